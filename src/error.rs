@@ -0,0 +1,463 @@
+/*!
+ * The error type returned by validated setters, and the functions that implement the
+ * built-in field constraints.
+ *
+ * The built-in constraints are used by the core module's [`set`](crate::set) and
+ * [`with`](crate::with) macros, where they generate `try_set_name` and `try_with_name`
+ * methods returning `Result<_, ValidationError>`.
+ *
+ * For checks that are not covered by the built-in constraints the same macros accept a
+ * `validate = V` clause, where `V` is either a function declared with the
+ * [`validator`](crate::validator) macro or a type implementing the [`Validator`] trait; the error type of the generated
+ * methods is then taken from the function's signature, or the validator's implementation.
+ *
+ * | Constraint       | field types                  | check                                   |
+ * |------------------|------------------------------|-----------------------------------------|
+ * | `range R`        | any `PartialOrd` type        | `R.contains(&value)`                    |
+ * | `non_empty`      | strings and collections      | `value.length() > 0`                    |
+ * | `max_len N`      | strings and collections      | `value.length() <= N`                   |
+ * | `matches f`      | any type                     | `f(&value)`, where `f` accepts `&T`     |
+ *
+ * ```rust
+ * use jemmy::*;
+ * use jemmy::error::{Constraint, ValidationError};
+ *
+ * #[derive(Default)]
+ * pub struct Person {
+ *     age: u8,
+ * }
+ *
+ * impl Person {
+ *     set!(pub age => u8, range 0..=150);
+ * }
+ *
+ * let mut person = Person::default();
+ * assert!(person.try_set_age(42).is_ok());
+ *
+ * let error: ValidationError = person.try_set_age(200).unwrap_err();
+ * assert_eq!(error.field(), "age");
+ * assert_eq!(error.constraint(), Constraint::Range);
+ * ```
+ */
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::{Debug, Display};
+use std::ops::RangeBounds;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The error returned when a value is rejected by a field's constraint.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    field: &'static str,
+    constraint: Constraint,
+    message: String,
+}
+
+///
+/// The set of built-in constraints that may be applied to a validated setter.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Constraint {
+    /// The value must be contained within a range; `range R`.
+    Range,
+    /// The value must not be empty; `non_empty`.
+    NonEmpty,
+    /// The value's length must be no greater than a maximum; `max_len N`.
+    MaxLength,
+    /// The value must be accepted by a predicate function; `matches f`.
+    Matches,
+}
+
+///
+/// Implemented by types that have a length, for the `non_empty` and `max_len` constraints.
+///
+/// Note that the length of string types is the number of `char`s and not the number of
+/// bytes.
+///
+pub trait Length {
+    /// Returns the length of this value.
+    fn length(&self) -> usize;
+
+    /// Returns `true` if this value has a length of zero.
+    fn is_length_zero(&self) -> bool {
+        self.length() == 0
+    }
+}
+
+///
+/// Implemented by the validator types used in the `validate = V` clause of the [`set`](crate::set)
+/// and [`with`](crate::with) macros. The error type of the generated `try_set_name` and
+/// `try_with_name` methods is the associated type `Error`, so that it need not be repeated
+/// in each macro. A validator function may instead be declared with the
+/// [`validator`](crate::validator) macro, which implements this trait for it.
+///
+/// ```rust
+/// use jemmy::*;
+/// use jemmy::error::Validator;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct QuantityError;
+///
+/// pub struct PositiveQuantity;
+///
+/// impl Validator<u32> for PositiveQuantity {
+///     type Error = QuantityError;
+///
+///     fn validate(value: &u32) -> Result<(), Self::Error> {
+///         if *value > 0 { Ok(()) } else { Err(QuantityError) }
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// pub struct Order {
+///     quantity: u32,
+/// }
+///
+/// impl Order {
+///     set!(pub quantity => u32, validate = PositiveQuantity);
+/// }
+///
+/// let mut order = Order::default();
+/// assert!(order.try_set_quantity(5).is_ok());
+/// assert_eq!(order.try_set_quantity(0), Err(QuantityError));
+/// ```
+///
+pub trait Validator<T: ?Sized> {
+    /// The error returned when a value is rejected.
+    type Error;
+
+    /// Returns `Ok(())` if `value` is acceptable, else the validator's error.
+    fn validate(value: &T) -> Result<(), Self::Error>;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Check the `range` constraint; `value` must be contained within `range`.
+///
+pub fn check_range<T, R>(field: &'static str, value: &T, range: R) -> Result<(), ValidationError>
+where
+    T: PartialOrd + Debug,
+    R: RangeBounds<T> + Debug,
+{
+    if range.contains(value) {
+        Ok(())
+    } else {
+        Err(ValidationError::new(
+            field,
+            Constraint::Range,
+            format!("value {value:?} is not within the range {range:?}"),
+        ))
+    }
+}
+
+///
+/// Check the `non_empty` constraint; `value` must have a non-zero length.
+///
+pub fn check_non_empty<T>(field: &'static str, value: &T) -> Result<(), ValidationError>
+where
+    T: Length + ?Sized,
+{
+    if value.is_length_zero() {
+        Err(ValidationError::new(
+            field,
+            Constraint::NonEmpty,
+            "value must not be empty".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+///
+/// Check the `max_len` constraint; `value` must have a length no greater than `max`.
+///
+pub fn check_max_len<T>(field: &'static str, value: &T, max: usize) -> Result<(), ValidationError>
+where
+    T: Length + ?Sized,
+{
+    let length = value.length();
+    if length <= max {
+        Ok(())
+    } else {
+        Err(ValidationError::new(
+            field,
+            Constraint::MaxLength,
+            format!("value has length {length}, greater than the maximum {max}"),
+        ))
+    }
+}
+
+///
+/// Check the `matches` constraint; `value` must be accepted by the function `predicate`.
+///
+pub fn check_matches<T, F>(
+    field: &'static str,
+    value: &T,
+    predicate_name: &'static str,
+    predicate: F,
+) -> Result<(), ValidationError>
+where
+    T: ?Sized,
+    F: FnOnce(&T) -> bool,
+{
+    if predicate(value) {
+        Ok(())
+    } else {
+        Err(ValidationError::new(
+            field,
+            Constraint::Matches,
+            format!("value is not accepted by `{predicate_name}`"),
+        ))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Constraint Macros
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Check a value against one of the built-in constraints, this is used by the validated forms
+/// of the [`set`](crate::set) and [`with`](crate::with) macros and is not intended to be used
+/// directly.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! check_constraint {
+    ($field:expr, $value:expr, range $range:expr) => {
+        $crate::error::check_range($field, $value, $range)
+    };
+    ($field:expr, $value:expr, non_empty) => {
+        $crate::error::check_non_empty($field, $value)
+    };
+    ($field:expr, $value:expr, max_len $max:expr) => {
+        $crate::error::check_max_len($field, $value, $max)
+    };
+    ($field:expr, $value:expr, matches $($predicate:ident)::+) => {
+        $crate::error::check_matches(
+            $field,
+            $value,
+            stringify!($($predicate)::+),
+            |value| $($predicate)::+(value),
+        )
+    };
+}
+
+///
+/// Declare one or more validator functions, for use in the `validate = validator` clause of
+/// the [`set`](crate::set) and [`with`](crate::with) macros, where the error type of the
+/// generated methods is taken from each function's signature.
+///
+/// ## Forms
+///
+/// ### `validator! { viz fn name(value: &Type) -> Result<(), Error> { ... } ... }`
+///
+/// * Each function is declared unchanged, and may be called as any other function.
+/// * Each function must take a single reference argument and return `Result<(), Error>`.
+/// * Alongside each function a type, with the same name, is declared that implements
+///   [`Validator<T>`](Validator), with the associated type `Error`, for any field type `T`
+///   that borrows as `Type`; so a `fn(&str)` validator may be used for a `String` field. As
+///   the type has no constructor it does not clash with the function.
+///
+/// The following — commented lines and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # #[derive(Debug)] pub struct QuantityError;
+/// use jemmy::error::Validator;
+/// use std::borrow::Borrow;
+///
+/// // validator! {
+/// //     fn check_quantity(value: &u32) -> Result<(), QuantityError> {
+/// //         if *value > 0 { Ok(()) } else { Err(QuantityError) }
+/// //     }
+/// // }
+///
+/// fn check_quantity(value: &u32) -> Result<(), QuantityError> {
+///     if *value > 0 { Ok(()) } else { Err(QuantityError) }
+/// }
+///
+/// #[doc(hidden)]
+/// #[allow(non_camel_case_types)]
+/// struct check_quantity {}
+///
+/// impl<T> Validator<T> for check_quantity
+/// where
+///     T: Borrow<u32> + ?Sized,
+/// {
+///     type Error = QuantityError;
+///
+///     fn validate(value: &T) -> Result<(), Self::Error> {
+///         check_quantity(value.borrow())
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! validator {
+    ($(
+        $(#[$meta:meta])*
+        $fn_vis:vis fn $fn_name:ident($value:ident: &$value_type:ty) -> Result<(), $error_type:ty> $body:block
+    )+) => {
+        $(
+            $(#[$meta])*
+            $fn_vis fn $fn_name($value: &$value_type) -> Result<(), $error_type> $body
+
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            $fn_vis struct $fn_name {}
+
+            impl<T> $crate::error::Validator<T> for $fn_name
+            where
+                T: ::core::borrow::Borrow<$value_type> + ?Sized,
+            {
+                type Error = $error_type;
+
+                fn validate(value: &T) -> Result<(), Self::Error> {
+                    $fn_name(value.borrow())
+                }
+            }
+        )+
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ ValidationError
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid value for field `{}`, constraint {}: {}",
+            self.field, self.constraint, self.message
+        )
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl ValidationError {
+    ///
+    /// Construct a new validation error for the named field.
+    ///
+    pub fn new<S: Into<String>>(field: &'static str, constraint: Constraint, message: S) -> Self {
+        Self {
+            field,
+            constraint,
+            message: message.into(),
+        }
+    }
+
+    ///
+    /// Returns the name of the field whose value was rejected.
+    ///
+    pub const fn field(&self) -> &'static str {
+        self.field
+    }
+
+    ///
+    /// Returns the constraint that rejected the value.
+    ///
+    pub const fn constraint(&self) -> Constraint {
+        self.constraint
+    }
+
+    ///
+    /// Returns a description of why the value was rejected.
+    ///
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ Constraint
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Range => "range",
+                Self::NonEmpty => "non_empty",
+                Self::MaxLength => "max_len",
+                Self::Matches => "matches",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ Length
+// ------------------------------------------------------------------------------------------------
+
+impl Length for str {
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+
+    fn is_length_zero(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl Length for String {
+    fn length(&self) -> usize {
+        self.as_str().length()
+    }
+
+    fn is_length_zero(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T> Length for [T] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Length for Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Length for VecDeque<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V, S> Length for HashMap<K, V, S> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> Length for BTreeMap<K, V> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, S> Length for HashSet<T, S> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Length for BTreeSet<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
@@ -0,0 +1,424 @@
+/*!
+ * The set of macros for struct fields of the atomic types in `std::sync::atomic`.
+ *
+ * In all of these macros the `Type` given is the primitive type of the atomic's value, for
+ * example `u64` for an `AtomicU64` field, and all of the generated methods take a shared
+ * reference to self, `&self`.
+ *
+ * The memory ordering for each generated method is given as a keyword so that call sites do
+ * not have to repeat it. As not every [`Ordering`](std::sync::atomic::Ordering) is valid for
+ * every operation, each keyword is mapped to the strongest valid ordering for the operation:
+ *
+ * | Keyword   | load      | store     | read-modify-write | compare-exchange failure |
+ * |-----------|-----------|-----------|-------------------|--------------------------|
+ * | `relaxed` | `Relaxed` | `Relaxed` | `Relaxed`         | `Relaxed`                |
+ * | `acq_rel` | `Acquire` | `Release` | `AcqRel`          | `Acquire`                |
+ * | `seq_cst` | `SeqCst`  | `SeqCst`  | `SeqCst`          | `SeqCst`                 |
+ *
+ * The read-modify-write operations are those generated by [`atomic_fetch_add`],
+ * [`atomic_fetch_sub`], and [`atomic_swap`], as well as the success ordering of
+ * [`atomic_compare_exchange`].
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * use std::sync::atomic::{AtomicBool, AtomicU64};
+ *
+ * pub struct Metrics {
+ *     requests: AtomicU64,
+ *     shutdown: AtomicBool,
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro                      | field name | keywords | type | generated signature                                                                   |
+ * |----------------------------|------------|----------|------|---------------------------------------------------------------------------------------|
+ * | `atomic_load!`             | requests   | relaxed  | u64  | `fn requests(&self) -> u64`                                                           |
+ * | `atomic_store!`            | shutdown   | seq_cst  | bool | `fn set_shutdown(&self, value: bool)`                                                 |
+ * | `atomic_fetch_add!`        | requests   | relaxed  | u64  | `fn fetch_add_requests(&self, value: u64) -> u64`                                     |
+ * | `atomic_fetch_sub!`        | requests   | relaxed  | u64  | `fn fetch_sub_requests(&self, value: u64) -> u64`                                     |
+ * | `atomic_swap!`             | shutdown   | acq_rel  | bool | `fn swap_shutdown(&self, value: bool) -> bool`                                        |
+ * | `atomic_compare_exchange!` | shutdown   | acq_rel  | bool | `fn compare_exchange_shutdown(&self, current: bool, new: bool) -> Result<bool, bool>` |
+ *
+ */
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Atomic <name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a getter method that loads the value of an atomic field.
+///
+/// ## Forms
+///
+/// ### `atomic_load!(viz name => [field_name,] ordering Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * `ordering` is one of the keywords `relaxed`, `acq_rel`, or `seq_cst`;
+///   for a load `acq_rel` is `Acquire`.
+/// * `Type` is the primitive type of the atomic's value, for example `u64` for `AtomicU64`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::atomic::AtomicU64;
+/// # pub struct Metrics { requests: AtomicU64 }
+/// use std::sync::atomic::Ordering;
+/// impl Metrics {
+///     // atomic_load!(pub requests => relaxed u64);
+///
+///     /// Loads the value of the atomic field `requests`.
+///     pub fn requests(&self) -> u64 {
+///         self.requests.load(Ordering::Relaxed)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! atomic_load {
+    // Base case: `viz name => field_name, ordering Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $ordering:ident $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Loads the value of the atomic field `" $fn_name "`."]
+            $fn_vis fn $fn_name(&self) -> $value_type {
+                self.$field_name.load($crate::atomic_ordering!(load $ordering))
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => ordering Type`
+    ($fn_vis:vis $name:ident => $ordering:ident $value_type:ty) => {
+        $crate::atomic_load!($fn_vis $name => $name, $ordering $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Atomic set_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a setter method that stores a value into an atomic field.
+///
+/// ## Forms
+///
+/// ### `atomic_store!(viz name => [field_name,] ordering Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * `ordering` is one of the keywords `relaxed`, `acq_rel`, or `seq_cst`;
+///   for a store `acq_rel` is `Release`.
+/// * `Type` is the primitive type of the atomic's value, for example `u64` for `AtomicU64`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::atomic::AtomicU64;
+/// # pub struct Metrics { requests: AtomicU64 }
+/// use std::sync::atomic::Ordering;
+/// impl Metrics {
+///     // atomic_store!(pub requests => acq_rel u64);
+///
+///     /// Stores `value` into the atomic field `requests`.
+///     pub fn set_requests(&self, value: u64) {
+///         self.requests.store(value, Ordering::Release);
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! atomic_store {
+    // Base case: `viz name => field_name, ordering Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $ordering:ident $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Stores `value` into the atomic field `" $fn_name "`."]
+            $fn_vis fn [<set_ $fn_name>](&self, value: $value_type) {
+                self.$field_name.store(value, $crate::atomic_ordering!(store $ordering));
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => ordering Type`
+    ($fn_vis:vis $name:ident => $ordering:ident $value_type:ty) => {
+        $crate::atomic_store!($fn_vis $name => $name, $ordering $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Atomic fetch_add_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that adds a value to an integer atomic field, returning the previous
+/// value.
+///
+/// ## Forms
+///
+/// ### `atomic_fetch_add!(viz name => [field_name,] ordering Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * `ordering` is one of the keywords `relaxed`, `acq_rel`, or `seq_cst`;
+///   for a read-modify-write `acq_rel` is `AcqRel`.
+/// * `Type` is the primitive type of the atomic's value, for example `u64` for `AtomicU64`.
+/// * The operation wraps around on overflow.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::atomic::AtomicU64;
+/// # pub struct Metrics { requests: AtomicU64 }
+/// use std::sync::atomic::Ordering;
+/// impl Metrics {
+///     // atomic_fetch_add!(pub requests => relaxed u64);
+///
+///     /// Adds `value` to the atomic field `requests`, returning the previous value.
+///     pub fn fetch_add_requests(&self, value: u64) -> u64 {
+///         self.requests.fetch_add(value, Ordering::Relaxed)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! atomic_fetch_add {
+    // Base case: `viz name => field_name, ordering Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $ordering:ident $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Adds `value` to the atomic field `" $fn_name
+                    "`, returning the previous value."]
+            $fn_vis fn [<fetch_add_ $fn_name>](&self, value: $value_type) -> $value_type {
+                self.$field_name.fetch_add(value, $crate::atomic_ordering!(rmw $ordering))
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => ordering Type`
+    ($fn_vis:vis $name:ident => $ordering:ident $value_type:ty) => {
+        $crate::atomic_fetch_add!($fn_vis $name => $name, $ordering $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Atomic fetch_sub_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that subtracts a value from an integer atomic field, returning the previous
+/// value.
+///
+/// ## Forms
+///
+/// ### `atomic_fetch_sub!(viz name => [field_name,] ordering Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * `ordering` is one of the keywords `relaxed`, `acq_rel`, or `seq_cst`;
+///   for a read-modify-write `acq_rel` is `AcqRel`.
+/// * `Type` is the primitive type of the atomic's value, for example `u64` for `AtomicU64`.
+/// * The operation wraps around on overflow.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::atomic::AtomicU64;
+/// # pub struct Metrics { requests: AtomicU64 }
+/// use std::sync::atomic::Ordering;
+/// impl Metrics {
+///     // atomic_fetch_sub!(pub requests => relaxed u64);
+///
+///     /// Subtracts `value` from the atomic field `requests`, returning the previous value.
+///     pub fn fetch_sub_requests(&self, value: u64) -> u64 {
+///         self.requests.fetch_sub(value, Ordering::Relaxed)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! atomic_fetch_sub {
+    // Base case: `viz name => field_name, ordering Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $ordering:ident $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Subtracts `value` from the atomic field `" $fn_name
+                    "`, returning the previous value."]
+            $fn_vis fn [<fetch_sub_ $fn_name>](&self, value: $value_type) -> $value_type {
+                self.$field_name.fetch_sub(value, $crate::atomic_ordering!(rmw $ordering))
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => ordering Type`
+    ($fn_vis:vis $name:ident => $ordering:ident $value_type:ty) => {
+        $crate::atomic_fetch_sub!($fn_vis $name => $name, $ordering $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Atomic swap_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that stores a value into an atomic field, returning the previous value.
+///
+/// ## Forms
+///
+/// ### `atomic_swap!(viz name => [field_name,] ordering Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * `ordering` is one of the keywords `relaxed`, `acq_rel`, or `seq_cst`;
+///   for a read-modify-write `acq_rel` is `AcqRel`.
+/// * `Type` is the primitive type of the atomic's value, for example `u64` for `AtomicU64`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::atomic::AtomicU64;
+/// # pub struct Metrics { requests: AtomicU64 }
+/// use std::sync::atomic::Ordering;
+/// impl Metrics {
+///     // atomic_swap!(pub requests => seq_cst u64);
+///
+///     /// Stores `value` into the atomic field `requests`, returning the previous value.
+///     pub fn swap_requests(&self, value: u64) -> u64 {
+///         self.requests.swap(value, Ordering::SeqCst)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! atomic_swap {
+    // Base case: `viz name => field_name, ordering Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $ordering:ident $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Stores `value` into the atomic field `" $fn_name
+                    "`, returning the previous value."]
+            $fn_vis fn [<swap_ $fn_name>](&self, value: $value_type) -> $value_type {
+                self.$field_name.swap(value, $crate::atomic_ordering!(rmw $ordering))
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => ordering Type`
+    ($fn_vis:vis $name:ident => $ordering:ident $value_type:ty) => {
+        $crate::atomic_swap!($fn_vis $name => $name, $ordering $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Atomic compare_exchange_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that stores a value into an atomic field if its current value is the same
+/// as an expected value.
+///
+/// ## Forms
+///
+/// ### `atomic_compare_exchange!(viz name => [field_name,] ordering Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * `ordering` is one of the keywords `relaxed`, `acq_rel`, or `seq_cst`;
+///   for a compare-exchange `acq_rel` is `AcqRel` on success and `Acquire` on failure.
+/// * `Type` is the primitive type of the atomic's value, for example `u64` for `AtomicU64`.
+/// * The generated function returns `Ok` with the previous value if the value was stored, or
+///   `Err` with the actual current value if it was not.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::atomic::AtomicU64;
+/// # pub struct Metrics { requests: AtomicU64 }
+/// use std::sync::atomic::Ordering;
+/// impl Metrics {
+///     // atomic_compare_exchange!(pub requests => acq_rel u64);
+///
+///     /// Stores `new` into the atomic field `requests` if its value is the same as `current`,
+///     /// returning the previous value as `Ok` on success, or as `Err` on failure.
+///     pub fn compare_exchange_requests(&self, current: u64, new: u64) -> Result<u64, u64> {
+///         self.requests
+///             .compare_exchange(current, new, Ordering::AcqRel, Ordering::Acquire)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! atomic_compare_exchange {
+    // Base case: `viz name => field_name, ordering Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $ordering:ident $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Stores `new` into the atomic field `" $fn_name "` if its value is the same "
+                    "as `current`, returning the previous value as `Ok` on success, or as `Err` on "
+                    "failure."]
+            $fn_vis fn [<compare_exchange_ $fn_name>](
+                &self,
+                current: $value_type,
+                new: $value_type,
+            ) -> Result<$value_type, $value_type> {
+                self.$field_name.compare_exchange(
+                    current,
+                    new,
+                    $crate::atomic_ordering!(rmw $ordering),
+                    $crate::atomic_ordering!(load $ordering),
+                )
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => ordering Type`
+    ($fn_vis:vis $name:ident => $ordering:ident $value_type:ty) => {
+        $crate::atomic_compare_exchange!($fn_vis $name => $name, $ordering $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Ordering Macros
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Map an ordering keyword to the `Ordering` valid for the kind of operation, this is used by
+/// the atomic macros and is not intended to be used directly.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! atomic_ordering {
+    (load relaxed) => {
+        ::core::sync::atomic::Ordering::Relaxed
+    };
+    (load acq_rel) => {
+        ::core::sync::atomic::Ordering::Acquire
+    };
+    (load seq_cst) => {
+        ::core::sync::atomic::Ordering::SeqCst
+    };
+    (store relaxed) => {
+        ::core::sync::atomic::Ordering::Relaxed
+    };
+    (store acq_rel) => {
+        ::core::sync::atomic::Ordering::Release
+    };
+    (store seq_cst) => {
+        ::core::sync::atomic::Ordering::SeqCst
+    };
+    (rmw relaxed) => {
+        ::core::sync::atomic::Ordering::Relaxed
+    };
+    (rmw acq_rel) => {
+        ::core::sync::atomic::Ordering::AcqRel
+    };
+    (rmw seq_cst) => {
+        ::core::sync::atomic::Ordering::SeqCst
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::{
+    atomic_compare_exchange, atomic_fetch_add, atomic_fetch_sub, atomic_load, atomic_store,
+    atomic_swap,
+};
@@ -0,0 +1,300 @@
+/*!
+ * The [`bits`] macro, for accessors over a range of bits packed within an
+ * unsigned integer field.
+ *
+ * This is commonly used to model hardware registers and wire-format headers as a structure
+ * wrapping a single integer, where each accessor masks and shifts within that integer.
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * pub struct Header {
+ *     raw: u32,
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro   | name  | field name | bits | type | generated signature                            |
+ * |---------|-------|------------|------|------|------------------------------------------------|
+ * | `bits!` | mode  | raw        | 4..7 | u8   | `const fn mode(&self) -> u8`                   |
+ * | `bits!` | mode  | raw        | 4..7 | u8   | `fn set_mode(&mut self, value: u8)`            |
+ * | `bits!` | mode  | raw        | 4..7 | u8   | `fn with_mode(mut self, value: u8) -> Self`    |
+ * | `bits!` | ready | raw        | 0    | flag | `const fn is_ready(&self) -> bool`             |
+ * | `bits!` | ready | raw        | 0    | flag | `fn set_ready(&mut self, value: bool)`         |
+ * | `bits!` | ready | raw        | 0    | flag | `fn with_ready(mut self, value: bool) -> Self` |
+ *
+ */
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implemented by the unsigned integer types that may be used as the packed field for the
+/// [`bits`] macro; this allows the generated setters to mask and shift without
+/// knowing the field's type.
+///
+pub trait PackedBits: Copy {
+    /// The number of bits in this type.
+    const BITS: u32;
+
+    /// Widen this value to a `u128`.
+    fn into_bits(self) -> u128;
+
+    /// Narrow a `u128` to this type, discarding any bits that do not fit.
+    fn from_bits(bits: u128) -> Self;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the number of bits in the type of the field selected by `_field`; this is used by
+/// the [`bits`] macro to check, at compile time, that a range of bits fits within the packed
+/// field, as the macro is not given the field's type.
+///
+#[doc(hidden)]
+pub const fn packed_bits_of<S: ?Sized, T: PackedBits>(_field: fn(&S) -> &T) -> u32 {
+    T::BITS
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Bits
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate getter, setter, and initializer methods for a range of bits within an unsigned
+/// integer struct field.
+///
+/// ## Forms
+///
+/// ### `bits!(viz name => field_name, start..end, Type)`
+///
+/// * In this form `name` is used to name the generated functions, while `field_name` is the
+///   name of the structure's packed integer field.
+/// * The bits are the half-open range `start..end`, where bit `0` is the least significant;
+///   so `4..7` is the three bits `4`, `5`, and `6`.
+/// * `Type` is an unsigned integer type, at least as wide as the range of bits; this is
+///   checked at compile time.
+/// * The range of bits must also fit within the packed field's type; this is checked at
+///   compile time.
+/// * The getter function is `const`.
+/// * The setter function requires mutability in the form of a mutable reference to self;
+///   `&mut self`.
+/// * The setter and initializer functions will panic if the value does not fit within the
+///   range of bits.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use jemmy::field::bits::PackedBits;
+/// # pub struct Header { raw: u32 }
+/// impl Header {
+///     // bits!(pub mode => raw, 4..7, u8);
+///
+///     /// Returns the value of bits `4..7` within the field `raw`.
+///     pub const fn mode(&self) -> u8 {
+///         (((self.raw >> 4) as u128) & (u128::MAX >> (128 - (7 - 4)))) as u8
+///     }
+///
+///     /// Set the value of bits `4..7` within the field `raw`.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If `value` does not fit within the range of bits.
+///     pub fn set_mode(&mut self, value: u8) {
+///         assert!(
+///             (value as u128).checked_shr(7 - 4).unwrap_or(0) == 0,
+///             "value {} overflows the bits 4..7 of field `mode`",
+///             value
+///         );
+///         let mask: u128 = (u128::MAX >> (128 - (7 - 4))) << 4;
+///         let bits = PackedBits::into_bits(self.raw);
+///         self.raw = PackedBits::from_bits((bits & !mask) | ((value as u128) << 4));
+///     }
+///
+///     /// An initializer that sets the value of bits `4..7` within the field `raw`.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If `value` does not fit within the range of bits.
+///     pub fn with_mode(mut self, value: u8) -> Self {
+///         self.set_mode(value);
+///         self
+///     }
+/// }
+/// ```
+///
+/// The range of bits is checked against the field's type at compile time, so the following
+/// fails to compile as the bits `4..12` are outside the `u8` field `small`.
+///
+/// ```rust,compile_fail
+/// use jemmy::*;
+/// pub struct Register { small: u8 }
+/// impl Register {
+///     bits!(pub wide => small, 4..12, u16);
+/// }
+/// ```
+///
+/// ### `bits!(viz name => field_name, bit, flag)`
+///
+/// * In this form `name` is used to name the generated functions, while `field_name` is the
+///   name of the structure's packed integer field.
+/// * The keyword `flag` denotes that the single bit `bit` is accessed as a `bool`, with a
+///   predicate `is_name` rather than a getter.
+/// * The bit must be within the packed field's type; this is checked at compile time, so the
+///   following fails to compile as bit `20` is outside the `u8` field `small`.
+///
+/// ```rust,compile_fail
+/// use jemmy::*;
+/// pub struct Register { small: u8 }
+/// impl Register {
+///     bits!(pub enabled => small, 20, flag);
+/// }
+/// ```
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use jemmy::field::bits::PackedBits;
+/// # pub struct Header { raw: u32 }
+/// impl Header {
+///     // bits!(pub ready => raw, 0, flag);
+///
+///     /// Returns `true` if bit `0` within the field `raw` is set.
+///     pub const fn is_ready(&self) -> bool {
+///         (self.raw >> 0) & 1 == 1
+///     }
+///
+///     /// Set, or clear, bit `0` within the field `raw`.
+///     pub fn set_ready(&mut self, value: bool) {
+///         let mask: u128 = 1 << 0;
+///         let bits = PackedBits::into_bits(self.raw);
+///         self.raw = PackedBits::from_bits(if value { bits | mask } else { bits & !mask });
+///     }
+///
+///     /// An initializer that sets, or clears, bit `0` within the field `raw`.
+///     pub fn with_ready(mut self, value: bool) -> Self {
+///         self.set_ready(value);
+///         self
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! bits {
+    // Base case: `viz name => field_name, start..end, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $start:literal..$end:literal, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns the value of bits `" $start ".." $end "` within the field `"
+                    $field_name "`."]
+            $fn_vis const fn $fn_name(&self) -> $value_type {
+                const {
+                    assert!($start < $end, "the range of bits must not be empty");
+                    assert!(
+                        $end - $start <= <$value_type>::BITS,
+                        "the range of bits is wider than the value type"
+                    );
+                    assert!(
+                        $end <= $crate::field::bits::packed_bits_of(|this: &Self| &this.$field_name),
+                        "the range of bits is wider than the packed field"
+                    );
+                }
+                (((self.$field_name >> $start) as u128) & (u128::MAX >> (128 - ($end - $start))))
+                    as $value_type
+            }
+
+            #[doc = "Set the value of bits `" $start ".." $end "` within the field `"
+                    $field_name "`.\n\n# Panics\n\nIf `value` does not fit within the range of bits."]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: $value_type) {
+                assert!(
+                    (value as u128).checked_shr($end - $start).unwrap_or(0) == 0,
+                    concat!("value {} overflows the bits ", stringify!($start), "..",
+                            stringify!($end), " of field `", stringify!($fn_name), "`"),
+                    value
+                );
+                let mask: u128 = (u128::MAX >> (128 - ($end - $start))) << $start;
+                let bits = $crate::field::bits::PackedBits::into_bits(self.$field_name);
+                self.$field_name = $crate::field::bits::PackedBits::from_bits(
+                    (bits & !mask) | ((value as u128) << $start)
+                );
+            }
+
+            #[doc = "An initializer that sets the value of bits `" $start ".." $end
+                    "` within the field `" $field_name "`.\n\n# Panics\n\nIf `value` does not fit "
+                    "within the range of bits."]
+            $fn_vis fn [<with_ $fn_name>](mut self, value: $value_type) -> Self {
+                self.[<set_ $fn_name>](value);
+                self
+            }
+        }
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *flag*: `viz name => field_name, bit, flag`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $bit:literal, flag) => {
+        paste::paste! {
+            #[doc = "Returns `true` if bit `" $bit "` within the field `" $field_name "` is set."]
+            $fn_vis const fn [<is_ $fn_name>](&self) -> bool {
+                const {
+                    assert!(
+                        $bit < $crate::field::bits::packed_bits_of(|this: &Self| &this.$field_name),
+                        "the bit is outside the packed field"
+                    );
+                }
+                (self.$field_name >> $bit) & 1 == 1
+            }
+
+            #[doc = "Set, or clear, bit `" $bit "` within the field `" $field_name "`."]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: bool) {
+                let mask: u128 = 1 << $bit;
+                let bits = $crate::field::bits::PackedBits::into_bits(self.$field_name);
+                self.$field_name = $crate::field::bits::PackedBits::from_bits(
+                    if value { bits | mask } else { bits & !mask }
+                );
+            }
+
+            #[doc = "An initializer that sets, or clears, bit `" $bit "` within the field `"
+                    $field_name "`."]
+            $fn_vis fn [<with_ $fn_name>](mut self, value: bool) -> Self {
+                self.[<set_ $fn_name>](value);
+                self
+            }
+        }
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ PackedBits
+// ------------------------------------------------------------------------------------------------
+
+macro_rules! impl_packed_bits {
+    ($($int_type:ty),+) => {
+        $(
+            impl PackedBits for $int_type {
+                const BITS: u32 = <$int_type>::BITS;
+
+                #[inline(always)]
+                fn into_bits(self) -> u128 {
+                    self as u128
+                }
+
+                #[inline(always)]
+                fn from_bits(bits: u128) -> Self {
+                    bits as $int_type
+                }
+            }
+        )+
+    };
+}
+
+impl_packed_bits!(u8, u16, u32, u64, u128, usize);
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::bits;
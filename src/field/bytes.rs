@@ -0,0 +1,627 @@
+/*!
+ * The set of macros for typed accessors over a byte buffer field, at a fixed offset and with
+ * an explicit byte order.
+ *
+ * The buffer field may be either a fixed size array, `[u8; N]`, or a `Vec<u8>`; any type that
+ * implements [`ByteBuffer`]. For arrays the bounds of each accessor are checked at compile
+ * time, wherever the accessor is used, for vectors the generated methods will panic if the
+ * buffer is too short.
+ *
+ * The general macros [`be_bytes`] and [`le_bytes`] take the value type as a parameter, while
+ * the shorthand macros such as [`be_u16`] and [`le_f64`] name the type and byte order
+ * together. Shorthand macros are provided for `u8`, `u16`, `u32`, `u64`, `u128`, `i8`, `i16`,
+ * `i32`, `i64`, `i128`, `f32`, and `f64`. There are intentionally no shorthand macros for
+ * `usize` and `isize`, as their size, and so the layout of the buffer, would depend on the
+ * target platform; use [`be_bytes`] or [`le_bytes`] if this is required.
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * pub struct Packet {
+ *     buf: [u8; 16],
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro       | name     | field name | offset | type | generated signature                      |
+ * |-------------|----------|------------|--------|------|------------------------------------------|
+ * | `be_bytes!` | length   | buf        | 2      | u16  | `fn length(&self) -> u16`                |
+ * | `be_bytes!` | length   | buf        | 2      | u16  | `fn set_length(&mut self, value: u16)`   |
+ * | `le_bytes!` | checksum | buf        | 4      | u32  | `fn checksum(&self) -> u32`              |
+ * | `le_bytes!` | checksum | buf        | 4      | u32  | `fn set_checksum(&mut self, value: u32)` |
+ * | `be_u16!`   | length   | buf        | 2      |      | `fn length(&self) -> u16`                |
+ * | `be_u16!`   | length   | buf        | 2      |      | `fn set_length(&mut self, value: u16)`   |
+ *
+ */
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implemented by the types that may be used as the buffer field for the byte accessor
+/// macros in this module.
+///
+pub trait ByteBuffer {
+    ///
+    /// The fixed length of this buffer type, if known at compile time; this is used to check
+    /// the bounds of accessors at compile time.
+    ///
+    const LENGTH: Option<usize>;
+
+    /// Returns the content of this buffer as a slice.
+    fn as_byte_slice(&self) -> &[u8];
+
+    /// Returns the content of this buffer as a mutable slice.
+    fn as_byte_slice_mut(&mut self) -> &mut [u8];
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Copy the `SIZE` bytes at `OFFSET` out of `buffer`; this is used by the generated getters
+/// and is not intended to be used directly.
+///
+/// # Panics
+///
+/// If `buffer` has no fixed length and is too short.
+///
+#[doc(hidden)]
+pub fn read_bytes<B, const OFFSET: usize, const SIZE: usize>(buffer: &B) -> [u8; SIZE]
+where
+    B: ByteBuffer + ?Sized,
+{
+    const { assert_in_bounds::<B>(OFFSET, SIZE) };
+    let mut bytes = [0; SIZE];
+    bytes.copy_from_slice(&buffer.as_byte_slice()[OFFSET..OFFSET + SIZE]);
+    bytes
+}
+
+///
+/// Copy `bytes` into `buffer` at `OFFSET`; this is used by the generated setters and is not
+/// intended to be used directly.
+///
+/// # Panics
+///
+/// If `buffer` has no fixed length and is too short.
+///
+#[doc(hidden)]
+pub fn write_bytes<B, const OFFSET: usize, const SIZE: usize>(buffer: &mut B, bytes: [u8; SIZE])
+where
+    B: ByteBuffer + ?Sized,
+{
+    const { assert_in_bounds::<B>(OFFSET, SIZE) };
+    buffer.as_byte_slice_mut()[OFFSET..OFFSET + SIZE].copy_from_slice(&bytes);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Bytes
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate getter and setter methods for a big-endian value at a fixed offset within a
+/// byte buffer struct field.
+///
+/// ## Forms
+///
+/// ### `be_bytes!(viz name => field_name, offset, Type)`
+///
+/// * In this form `name` is used to name the generated functions, while `field_name` is the
+///   name of the structure's buffer field.
+/// * `offset` is a constant expression, the index of the value's first byte in the buffer.
+/// * `Type` is any type with the associated functions `from_be_bytes` and `to_be_bytes`;
+///   this includes all of the primitive integer and floating point types.
+/// * The setter function requires mutability in the form of a mutable reference to self;
+///   `&mut self`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use jemmy::field::bytes::{read_bytes, write_bytes};
+/// # pub struct Packet { buf: [u8; 16] }
+/// impl Packet {
+///     // be_bytes!(pub length => buf, 2, u16);
+///
+///     /// Returns the big-endian `u16` value at offset `2` within the field `buf`.
+///     pub fn length(&self) -> u16 {
+///         u16::from_be_bytes(read_bytes::<_, 2, { size_of::<u16>() }>(&self.buf))
+///     }
+///
+///     /// Set the big-endian `u16` value at offset `2` within the field `buf`.
+///     pub fn set_length(&mut self, value: u16) {
+///         write_bytes::<_, 2, { size_of::<u16>() }>(&mut self.buf, value.to_be_bytes());
+///     }
+/// }
+/// ```
+///
+/// When the field is an array the bounds are checked at compile time, wherever the generated
+/// methods are used, so the following fails to compile as the value's bytes `15..17` are
+/// outside the bounds of the buffer.
+///
+/// ```rust,compile_fail
+/// use jemmy::*;
+/// pub struct Packet { buf: [u8; 16] }
+/// impl Packet {
+///     be_bytes!(pub trailer => buf, 15, u16);
+/// }
+/// let packet = Packet { buf: [0; 16] };
+/// let _ = packet.trailer();
+/// ```
+///
+#[macro_export]
+macro_rules! be_bytes {
+    // Base case: `viz name => field_name, offset, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr, $value_type:ty) => {
+        paste::paste! {
+            #[doc = concat!("Returns the big-endian `", stringify!($value_type), "` value at offset `",
+                            stringify!($offset), "` within the field `", stringify!($field_name), "`.")]
+            $fn_vis fn $fn_name(&self) -> $value_type {
+                <$value_type>::from_be_bytes(
+                    $crate::field::bytes::read_bytes::<_, { $offset }, { ::core::mem::size_of::<$value_type>() }>(
+                        &self.$field_name,
+                    ),
+                )
+            }
+
+            #[doc = concat!("Set the big-endian `", stringify!($value_type), "` value at offset `",
+                            stringify!($offset), "` within the field `", stringify!($field_name), "`.")]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: $value_type) {
+                $crate::field::bytes::write_bytes::<_, { $offset }, { ::core::mem::size_of::<$value_type>() }>(
+                    &mut self.$field_name,
+                    value.to_be_bytes(),
+                );
+            }
+        }
+    };
+}
+
+///
+/// Generate getter and setter methods for a little-endian value at a fixed offset within a
+/// byte buffer struct field.
+///
+/// ## Forms
+///
+/// ### `le_bytes!(viz name => field_name, offset, Type)`
+///
+/// * In this form `name` is used to name the generated functions, while `field_name` is the
+///   name of the structure's buffer field.
+/// * `offset` is a constant expression, the index of the value's first byte in the buffer.
+/// * `Type` is any type with the associated functions `from_le_bytes` and `to_le_bytes`;
+///   this includes all of the primitive integer and floating point types.
+/// * The setter function requires mutability in the form of a mutable reference to self;
+///   `&mut self`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use jemmy::field::bytes::{read_bytes, write_bytes};
+/// # pub struct Packet { buf: Vec<u8> }
+/// impl Packet {
+///     // le_bytes!(pub checksum => buf, 4, u32);
+///
+///     /// Returns the little-endian `u32` value at offset `4` within the field `buf`.
+///     pub fn checksum(&self) -> u32 {
+///         u32::from_le_bytes(read_bytes::<_, 4, { size_of::<u32>() }>(&self.buf))
+///     }
+///
+///     /// Set the little-endian `u32` value at offset `4` within the field `buf`.
+///     pub fn set_checksum(&mut self, value: u32) {
+///         write_bytes::<_, 4, { size_of::<u32>() }>(&mut self.buf, value.to_le_bytes());
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! le_bytes {
+    // Base case: `viz name => field_name, offset, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr, $value_type:ty) => {
+        paste::paste! {
+            #[doc = concat!("Returns the little-endian `", stringify!($value_type), "` value at offset `",
+                            stringify!($offset), "` within the field `", stringify!($field_name), "`.")]
+            $fn_vis fn $fn_name(&self) -> $value_type {
+                <$value_type>::from_le_bytes(
+                    $crate::field::bytes::read_bytes::<_, { $offset }, { ::core::mem::size_of::<$value_type>() }>(
+                        &self.$field_name,
+                    ),
+                )
+            }
+
+            #[doc = concat!("Set the little-endian `", stringify!($value_type), "` value at offset `",
+                            stringify!($offset), "` within the field `", stringify!($field_name), "`.")]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: $value_type) {
+                $crate::field::bytes::write_bytes::<_, { $offset }, { ::core::mem::size_of::<$value_type>() }>(
+                    &mut self.$field_name,
+                    value.to_le_bytes(),
+                );
+            }
+        }
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Bytes ❱ Big-Endian Shorthand
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate getter and setter methods for a big-endian `u8` value within a byte buffer
+/// struct field; `be_u8!(viz name => field_name, offset)` is shorthand for
+/// `be_bytes!(viz name => field_name, offset, u8)`, see [`be_bytes`].
+///
+#[macro_export]
+macro_rules! be_u8 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::be_bytes!($fn_vis $fn_name => $field_name, $offset, u8);
+    };
+}
+
+///
+/// Generate getter and setter methods for a big-endian `u16` value within a byte buffer
+/// struct field; `be_u16!(viz name => field_name, offset)` is shorthand for
+/// `be_bytes!(viz name => field_name, offset, u16)`, see [`be_bytes`].
+///
+#[macro_export]
+macro_rules! be_u16 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::be_bytes!($fn_vis $fn_name => $field_name, $offset, u16);
+    };
+}
+
+///
+/// Generate getter and setter methods for a big-endian `u32` value within a byte buffer
+/// struct field; `be_u32!(viz name => field_name, offset)` is shorthand for
+/// `be_bytes!(viz name => field_name, offset, u32)`, see [`be_bytes`].
+///
+#[macro_export]
+macro_rules! be_u32 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::be_bytes!($fn_vis $fn_name => $field_name, $offset, u32);
+    };
+}
+
+///
+/// Generate getter and setter methods for a big-endian `u64` value within a byte buffer
+/// struct field; `be_u64!(viz name => field_name, offset)` is shorthand for
+/// `be_bytes!(viz name => field_name, offset, u64)`, see [`be_bytes`].
+///
+#[macro_export]
+macro_rules! be_u64 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::be_bytes!($fn_vis $fn_name => $field_name, $offset, u64);
+    };
+}
+
+///
+/// Generate getter and setter methods for a big-endian `u128` value within a byte buffer
+/// struct field; `be_u128!(viz name => field_name, offset)` is shorthand for
+/// `be_bytes!(viz name => field_name, offset, u128)`, see [`be_bytes`].
+///
+#[macro_export]
+macro_rules! be_u128 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::be_bytes!($fn_vis $fn_name => $field_name, $offset, u128);
+    };
+}
+
+///
+/// Generate getter and setter methods for a big-endian `i8` value within a byte buffer
+/// struct field; `be_i8!(viz name => field_name, offset)` is shorthand for
+/// `be_bytes!(viz name => field_name, offset, i8)`, see [`be_bytes`].
+///
+#[macro_export]
+macro_rules! be_i8 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::be_bytes!($fn_vis $fn_name => $field_name, $offset, i8);
+    };
+}
+
+///
+/// Generate getter and setter methods for a big-endian `i16` value within a byte buffer
+/// struct field; `be_i16!(viz name => field_name, offset)` is shorthand for
+/// `be_bytes!(viz name => field_name, offset, i16)`, see [`be_bytes`].
+///
+#[macro_export]
+macro_rules! be_i16 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::be_bytes!($fn_vis $fn_name => $field_name, $offset, i16);
+    };
+}
+
+///
+/// Generate getter and setter methods for a big-endian `i32` value within a byte buffer
+/// struct field; `be_i32!(viz name => field_name, offset)` is shorthand for
+/// `be_bytes!(viz name => field_name, offset, i32)`, see [`be_bytes`].
+///
+#[macro_export]
+macro_rules! be_i32 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::be_bytes!($fn_vis $fn_name => $field_name, $offset, i32);
+    };
+}
+
+///
+/// Generate getter and setter methods for a big-endian `i64` value within a byte buffer
+/// struct field; `be_i64!(viz name => field_name, offset)` is shorthand for
+/// `be_bytes!(viz name => field_name, offset, i64)`, see [`be_bytes`].
+///
+#[macro_export]
+macro_rules! be_i64 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::be_bytes!($fn_vis $fn_name => $field_name, $offset, i64);
+    };
+}
+
+///
+/// Generate getter and setter methods for a big-endian `i128` value within a byte buffer
+/// struct field; `be_i128!(viz name => field_name, offset)` is shorthand for
+/// `be_bytes!(viz name => field_name, offset, i128)`, see [`be_bytes`].
+///
+#[macro_export]
+macro_rules! be_i128 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::be_bytes!($fn_vis $fn_name => $field_name, $offset, i128);
+    };
+}
+
+///
+/// Generate getter and setter methods for a big-endian `f32` value within a byte buffer
+/// struct field; `be_f32!(viz name => field_name, offset)` is shorthand for
+/// `be_bytes!(viz name => field_name, offset, f32)`, see [`be_bytes`].
+///
+#[macro_export]
+macro_rules! be_f32 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::be_bytes!($fn_vis $fn_name => $field_name, $offset, f32);
+    };
+}
+
+///
+/// Generate getter and setter methods for a big-endian `f64` value within a byte buffer
+/// struct field; `be_f64!(viz name => field_name, offset)` is shorthand for
+/// `be_bytes!(viz name => field_name, offset, f64)`, see [`be_bytes`].
+///
+#[macro_export]
+macro_rules! be_f64 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::be_bytes!($fn_vis $fn_name => $field_name, $offset, f64);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Bytes ❱ Little-Endian Shorthand
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate getter and setter methods for a little-endian `u8` value within a byte buffer
+/// struct field; `le_u8!(viz name => field_name, offset)` is shorthand for
+/// `le_bytes!(viz name => field_name, offset, u8)`, see [`le_bytes`].
+///
+#[macro_export]
+macro_rules! le_u8 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::le_bytes!($fn_vis $fn_name => $field_name, $offset, u8);
+    };
+}
+
+///
+/// Generate getter and setter methods for a little-endian `u16` value within a byte buffer
+/// struct field; `le_u16!(viz name => field_name, offset)` is shorthand for
+/// `le_bytes!(viz name => field_name, offset, u16)`, see [`le_bytes`].
+///
+#[macro_export]
+macro_rules! le_u16 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::le_bytes!($fn_vis $fn_name => $field_name, $offset, u16);
+    };
+}
+
+///
+/// Generate getter and setter methods for a little-endian `u32` value within a byte buffer
+/// struct field; `le_u32!(viz name => field_name, offset)` is shorthand for
+/// `le_bytes!(viz name => field_name, offset, u32)`, see [`le_bytes`].
+///
+#[macro_export]
+macro_rules! le_u32 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::le_bytes!($fn_vis $fn_name => $field_name, $offset, u32);
+    };
+}
+
+///
+/// Generate getter and setter methods for a little-endian `u64` value within a byte buffer
+/// struct field; `le_u64!(viz name => field_name, offset)` is shorthand for
+/// `le_bytes!(viz name => field_name, offset, u64)`, see [`le_bytes`].
+///
+#[macro_export]
+macro_rules! le_u64 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::le_bytes!($fn_vis $fn_name => $field_name, $offset, u64);
+    };
+}
+
+///
+/// Generate getter and setter methods for a little-endian `u128` value within a byte buffer
+/// struct field; `le_u128!(viz name => field_name, offset)` is shorthand for
+/// `le_bytes!(viz name => field_name, offset, u128)`, see [`le_bytes`].
+///
+#[macro_export]
+macro_rules! le_u128 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::le_bytes!($fn_vis $fn_name => $field_name, $offset, u128);
+    };
+}
+
+///
+/// Generate getter and setter methods for a little-endian `i8` value within a byte buffer
+/// struct field; `le_i8!(viz name => field_name, offset)` is shorthand for
+/// `le_bytes!(viz name => field_name, offset, i8)`, see [`le_bytes`].
+///
+#[macro_export]
+macro_rules! le_i8 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::le_bytes!($fn_vis $fn_name => $field_name, $offset, i8);
+    };
+}
+
+///
+/// Generate getter and setter methods for a little-endian `i16` value within a byte buffer
+/// struct field; `le_i16!(viz name => field_name, offset)` is shorthand for
+/// `le_bytes!(viz name => field_name, offset, i16)`, see [`le_bytes`].
+///
+#[macro_export]
+macro_rules! le_i16 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::le_bytes!($fn_vis $fn_name => $field_name, $offset, i16);
+    };
+}
+
+///
+/// Generate getter and setter methods for a little-endian `i32` value within a byte buffer
+/// struct field; `le_i32!(viz name => field_name, offset)` is shorthand for
+/// `le_bytes!(viz name => field_name, offset, i32)`, see [`le_bytes`].
+///
+#[macro_export]
+macro_rules! le_i32 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::le_bytes!($fn_vis $fn_name => $field_name, $offset, i32);
+    };
+}
+
+///
+/// Generate getter and setter methods for a little-endian `i64` value within a byte buffer
+/// struct field; `le_i64!(viz name => field_name, offset)` is shorthand for
+/// `le_bytes!(viz name => field_name, offset, i64)`, see [`le_bytes`].
+///
+#[macro_export]
+macro_rules! le_i64 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::le_bytes!($fn_vis $fn_name => $field_name, $offset, i64);
+    };
+}
+
+///
+/// Generate getter and setter methods for a little-endian `i128` value within a byte buffer
+/// struct field; `le_i128!(viz name => field_name, offset)` is shorthand for
+/// `le_bytes!(viz name => field_name, offset, i128)`, see [`le_bytes`].
+///
+#[macro_export]
+macro_rules! le_i128 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::le_bytes!($fn_vis $fn_name => $field_name, $offset, i128);
+    };
+}
+
+///
+/// Generate getter and setter methods for a little-endian `f32` value within a byte buffer
+/// struct field; `le_f32!(viz name => field_name, offset)` is shorthand for
+/// `le_bytes!(viz name => field_name, offset, f32)`, see [`le_bytes`].
+///
+#[macro_export]
+macro_rules! le_f32 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::le_bytes!($fn_vis $fn_name => $field_name, $offset, f32);
+    };
+}
+
+///
+/// Generate getter and setter methods for a little-endian `f64` value within a byte buffer
+/// struct field; `le_f64!(viz name => field_name, offset)` is shorthand for
+/// `le_bytes!(viz name => field_name, offset, f64)`, see [`le_bytes`].
+///
+#[macro_export]
+macro_rules! le_f64 {
+    // Base case: `viz name => field_name, offset`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $offset:expr) => {
+        $crate::le_bytes!($fn_vis $fn_name => $field_name, $offset, f64);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const fn assert_in_bounds<B: ByteBuffer + ?Sized>(offset: usize, size: usize) {
+    if let Some(length) = B::LENGTH {
+        assert!(
+            offset + size <= length,
+            "the value's bytes are outside the bounds of the buffer"
+        );
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ ByteBuffer
+// ------------------------------------------------------------------------------------------------
+
+impl<const N: usize> ByteBuffer for [u8; N] {
+    const LENGTH: Option<usize> = Some(N);
+
+    fn as_byte_slice(&self) -> &[u8] {
+        self
+    }
+
+    fn as_byte_slice_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+impl ByteBuffer for [u8] {
+    const LENGTH: Option<usize> = None;
+
+    fn as_byte_slice(&self) -> &[u8] {
+        self
+    }
+
+    fn as_byte_slice_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+impl ByteBuffer for Vec<u8> {
+    const LENGTH: Option<usize> = None;
+
+    fn as_byte_slice(&self) -> &[u8] {
+        self
+    }
+
+    fn as_byte_slice_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::{
+    be_bytes, be_f32, be_f64, be_i128, be_i16, be_i32, be_i64, be_i8, be_u128, be_u16, be_u32,
+    be_u64, be_u8, le_bytes, le_f32, le_f64, le_i128, le_i16, le_i32, le_i64, le_i8, le_u128,
+    le_u16, le_u32, le_u64, le_u8,
+};
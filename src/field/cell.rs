@@ -0,0 +1,418 @@
+/*!
+ * The set of macros for struct fields with interior mutability, backed by `Cell<T>` or
+ * `RefCell<T>`.
+ *
+ * In all of these macros the `Type` given is the type of the cell's content, `T`, and *not*
+ * the type of the field itself. All of the generated methods, including setters, take a shared
+ * reference to self, `&self`.
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * use std::cell::{Cell, RefCell};
+ *
+ * pub struct Node {
+ *     visits: Cell<u32>,
+ *     children: RefCell<Vec<String>>,
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro                  | field name | keywords | type          | generated signature                                                             |
+ * |------------------------|------------|----------|---------------|---------------------------------------------------------------------------------|
+ * | `cell_get!`            | visits     |          | u32           | `fn visits(&self) -> u32`                                                       |
+ * | `cell_set!`            | visits     | into     | u32           | `fn set_visits<T: Into<u32>>(&self, value: T)`                                  |
+ * | `cell_replace!`        | visits     |          | u32           | `fn replace_visits(&self, value: u32) -> u32`                                   |
+ * | `refcell_get!`         | children   |          | `Vec<String>` | `fn children(&self) -> Ref<'_, Vec<String>>`                                    |
+ * | `refcell_get_mut!`     | children   |          | `Vec<String>` | `fn children_mut(&self) -> RefMut<'_, Vec<String>>`                             |
+ * | `refcell_try_get!`     | children   |          | `Vec<String>` | `fn try_children(&self) -> Result<Ref<'_, Vec<String>>, BorrowError>`           |
+ * | `refcell_try_get_mut!` | children   |          | `Vec<String>` | `fn try_children_mut(&self) -> Result<RefMut<'_, Vec<String>>, BorrowMutError>` |
+ *
+ */
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Cell <name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a getter method that returns a copy of the content of a `Cell<T>` field.
+///
+/// ## Forms
+///
+/// ### `cell_get!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * `Type` must implement `Copy`, as the value is returned using `Cell::get`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::cell::Cell;
+/// # pub struct Node { visits: Cell<u32> }
+/// impl Node {
+///     // cell_get!(pub visits => u32);
+///
+///     /// Returns a copy of the content of the cell field `visits`.
+///     pub fn visits(&self) -> u32 {
+///         self.visits.get()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! cell_get {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a copy of the content of the cell field `" $fn_name "`."]
+            $fn_vis fn $fn_name(&self) -> $value_type {
+                self.$field_name.get()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::cell_get!($fn_vis $name => $name, $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Cell set_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a setter method that sets the content of a `Cell<T>` field.
+///
+/// ## Forms
+///
+/// ### `cell_set!(viz name => [field_name,] [into] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * If the keyword `into` is specified the type of the value parameter is the trait-bound
+///   type `T: Into<Type>` rather than `Type` for flexibility.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::cell::Cell;
+/// # pub struct Node { visits: Cell<u32> }
+/// impl Node {
+///     // cell_set!(pub visits => u32);
+///
+///     /// Set the content of the cell field `visits`.
+///     pub fn set_visits(&self, value: u32) {
+///         self.visits.set(value);
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! cell_set {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the content of the cell field `" $fn_name "`."]
+            $fn_vis fn [<set_ $fn_name>](&self, value: $value_type) {
+                self.$field_name.set(value);
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::cell_set!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the content of the cell field `" $fn_name "`."]
+            $fn_vis fn [<set_ $fn_name>]<T: Into<$value_type>>(&self, value: T) {
+                self.$field_name.set(value.into());
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::cell_set!($fn_vis $name => $name, into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Cell replace_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that replaces the content of a `Cell<T>` field, returning the previous
+/// content.
+///
+/// ## Forms
+///
+/// ### `cell_replace!(viz name => [field_name,] [into] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * If the keyword `into` is specified the type of the value parameter is the trait-bound
+///   type `T: Into<Type>` rather than `Type` for flexibility.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::cell::Cell;
+/// # pub struct Node { visits: Cell<u32> }
+/// impl Node {
+///     // cell_replace!(pub visits => u32);
+///
+///     /// Replace the content of the cell field `visits`, returning the previous content.
+///     pub fn replace_visits(&self, value: u32) -> u32 {
+///         self.visits.replace(value)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! cell_replace {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Replace the content of the cell field `" $fn_name "`, returning the previous content."]
+            $fn_vis fn [<replace_ $fn_name>](&self, value: $value_type) -> $value_type {
+                self.$field_name.replace(value)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::cell_replace!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Replace the content of the cell field `" $fn_name "`, returning the previous content."]
+            $fn_vis fn [<replace_ $fn_name>]<T: Into<$value_type>>(&self, value: T) -> $value_type {
+                self.$field_name.replace(value.into())
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::cell_replace!($fn_vis $name => $name, into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ RefCell <name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a getter method that immutably borrows the content of a `RefCell<T>` field.
+///
+/// ## Forms
+///
+/// ### `refcell_get!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * The generated function will panic if the content is currently mutably borrowed.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::cell::RefCell;
+/// # pub struct Node { children: RefCell<Vec<String>> }
+/// impl Node {
+///     // refcell_get!(pub children => Vec<String>);
+///
+///     /// Immutably borrows the content of the cell field `children`.
+///     pub fn children(&self) -> std::cell::Ref<'_, Vec<String>> {
+///         self.children.borrow()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! refcell_get {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Immutably borrows the content of the cell field `" $fn_name "`."]
+            $fn_vis fn $fn_name(&self) -> ::std::cell::Ref<'_, $value_type> {
+                self.$field_name.borrow()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::refcell_get!($fn_vis $name => $name, $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ RefCell <name>_mut
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a getter method that mutably borrows the content of a `RefCell<T>` field.
+///
+/// ## Forms
+///
+/// ### `refcell_get_mut!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * The generated function will panic if the content is currently borrowed.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::cell::RefCell;
+/// # pub struct Node { children: RefCell<Vec<String>> }
+/// impl Node {
+///     // refcell_get_mut!(pub children => Vec<String>);
+///
+///     /// Mutably borrows the content of the cell field `children`.
+///     pub fn children_mut(&self) -> std::cell::RefMut<'_, Vec<String>> {
+///         self.children.borrow_mut()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! refcell_get_mut {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Mutably borrows the content of the cell field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _mut>](&self) -> ::std::cell::RefMut<'_, $value_type> {
+                self.$field_name.borrow_mut()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::refcell_get_mut!($fn_vis $name => $name, $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ RefCell try_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a getter method that immutably borrows the content of a `RefCell<T>` field,
+/// returning an error rather than panicking if it is currently mutably borrowed.
+///
+/// ## Forms
+///
+/// ### `refcell_try_get!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::cell::RefCell;
+/// # pub struct Node { children: RefCell<Vec<String>> }
+/// impl Node {
+///     // refcell_try_get!(pub children => Vec<String>);
+///
+///     /// Immutably borrows the content of the cell field `children`, returning an error if
+///     /// it is currently mutably borrowed.
+///     pub fn try_children(
+///         &self,
+///     ) -> Result<std::cell::Ref<'_, Vec<String>>, std::cell::BorrowError> {
+///         self.children.try_borrow()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! refcell_try_get {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Immutably borrows the content of the cell field `" $fn_name "`, returning an error if it "
+                    "is currently mutably borrowed."]
+            $fn_vis fn [<try_ $fn_name>](&self) -> Result<::std::cell::Ref<'_, $value_type>, ::std::cell::BorrowError> {
+                self.$field_name.try_borrow()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::refcell_try_get!($fn_vis $name => $name, $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ RefCell try_<name>_mut
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a getter method that mutably borrows the content of a `RefCell<T>` field,
+/// returning an error rather than panicking if it is currently borrowed.
+///
+/// ## Forms
+///
+/// ### `refcell_try_get_mut!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::cell::RefCell;
+/// # pub struct Node { children: RefCell<Vec<String>> }
+/// impl Node {
+///     // refcell_try_get_mut!(pub children => Vec<String>);
+///
+///     /// Mutably borrows the content of the cell field `children`, returning an error if it
+///     /// is currently borrowed.
+///     pub fn try_children_mut(
+///         &self,
+///     ) -> Result<std::cell::RefMut<'_, Vec<String>>, std::cell::BorrowMutError> {
+///         self.children.try_borrow_mut()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! refcell_try_get_mut {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Mutably borrows the content of the cell field `" $fn_name "`, returning an error if it "
+                    "is currently borrowed."]
+            $fn_vis fn [<try_ $fn_name _mut>](&self) -> Result<::std::cell::RefMut<'_, $value_type>, ::std::cell::BorrowMutError> {
+                self.$field_name.try_borrow_mut()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::refcell_try_get_mut!($fn_vis $name => $name, $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::{
+    cell_get, cell_replace, cell_set, refcell_get, refcell_get_mut, refcell_try_get,
+    refcell_try_get_mut,
+};
@@ -0,0 +1,573 @@
+/*!
+ * The set of macros for struct fields backed by `HashSet<T>` or `BTreeSet<T>`.
+ *
+ * This module, and each of its macros, is named with the prefix `collection_set` to avoid
+ * confusion with the [`set`] macro; `set_len!(pub tags)` would read as a setter for a field
+ * named `len`.
+ * In all of these macros the `Type` given is the type of the set's members, `T`, and *not* the
+ * type of the field itself. As the generated methods only use the operations common to both set
+ * types the same macros are used for either.
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * use std::collections::{BTreeSet, HashSet};
+ *
+ * pub struct Address {
+ *     tags: HashSet<String>,       // demonstrates keyword 'into'
+ *     unit_numbers: BTreeSet<u32>, // demonstrates keyword 'copy'
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro                         | field name   | keywords | type   | generated signature                                                                                             |
+ * |-------------------------------|--------------|----------|--------|-----------------------------------------------------------------------------------------------------------------|
+ * | `collection_set_contains!`    | tags         |          | String | `fn tags_contains(&self, value: &String) -> bool`                                                               |
+ * | `collection_set_insert!`      | tags         |          | String | `fn tags_insert(&mut self, value: String) -> bool`                                                              |
+ * | `collection_set_insert!`      | tags         | into     | String | `fn tags_insert<T: Into<String>>(&mut self, value: T) -> bool`                                                  |
+ * | `collection_set_remove!`      | tags         |          | String | `fn tags_remove(&mut self, value: &String) -> bool`                                                             |
+ * | `collection_set_iter!`        | tags         |          | String | `fn tags_iter(&self) -> impl Iterator<Item = &String>`                                                          |
+ * | `collection_set_iter!`        | unit_numbers | copy     | u32    | `fn unit_numbers_iter(&self) -> impl Iterator<Item = u32> + '_`                                                 |
+ * | `collection_set_len!`         | tags         |          |        | `fn tags_len(&self) -> usize`                                                                                   |
+ * | `collection_set_extend!`      | tags         |          | String | `fn tags_extend<I: IntoIterator<Item = String>>(&mut self, values: I)`                                          |
+ * | `collection_set_extend!`      | tags         | into     | String | `fn tags_extend<T: Into<String>, I: IntoIterator<Item = T>>(&mut self, values: I)`                              |
+ * | `collection_set_union!`       | tags         |          | String | `fn tags_union<'a, I: IntoIterator<Item = &'a String>>(&'a self, other: I) -> impl Iterator<Item = &'a String>` |
+ * | `collection_set_with_member!` | tags         |          | String | `fn with_tags_member(mut self, value: String) -> Self`                                                          |
+ * | `collection_set_with_member!` | tags         | into     | String | `fn with_tags_member<T: Into<String>>(mut self, value: T) -> Self`                                              |
+ *
+ */
+
+use std::collections::{BTreeSet, HashSet};
+use std::hash::Hash;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implemented by the set types that may be used with the [`collection_set_union`] macro; this
+/// allows the generated method to return each value only once without knowing whether the
+/// field is a `HashSet` or a `BTreeSet`.
+///
+pub trait SetMembers<T> {
+    /// Returns a predicate that is `true` only the first time it is called with each value; the
+    /// values are recorded in a new set of the same kind as this one.
+    fn first_seen<'a>(&self) -> impl FnMut(&'a T) -> bool
+    where
+        T: 'a;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Set <name>_contains
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a predicate method that returns `true` if a set field contains a given value.
+///
+/// ## Forms
+///
+/// ### `collection_set_contains!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # pub struct Address { tags: HashSet<String> }
+/// impl Address {
+///     // collection_set_contains!(pub tags => String);
+///
+///     /// Returns `true` if the set field `tags` contains `value`, else `false`.
+///     pub fn tags_contains(&self, value: &String) -> bool {
+///         self.tags.contains(value)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! collection_set_contains {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns `true` if the set field `" $fn_name "` contains `value`, else `false`."]
+            $fn_vis fn [<$fn_name _contains>](&self, value: &$value_type) -> bool {
+                self.$field_name.contains(value)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::collection_set_contains!($fn_vis $name => $name, $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Set <name>_insert
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that adds a value to a set field.
+///
+/// ## Forms
+///
+/// ### `collection_set_insert!(viz name => [field_name,] [into] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * If the keyword `into` is specified the type of the new value parameter is the trait-bound
+///   type `T: Into<Type>` rather than `Type` for flexibility.
+/// * The generated function returns `true` if the value was newly inserted, or `false` if the
+///   set already contained it.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # pub struct Address { tags: HashSet<String> }
+/// impl Address {
+///     // collection_set_insert!(pub tags => into String);
+///
+///     /// Adds `value` to the set field `tags`, returning `true` if it was not already present.
+///     pub fn tags_insert<T: Into<String>>(&mut self, value: T) -> bool {
+///         self.tags.insert(value.into())
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! collection_set_insert {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Adds `value` to the set field `" $fn_name "`, returning `true` if it was not already present."]
+            $fn_vis fn [<$fn_name _insert>](&mut self, value: $value_type) -> bool {
+                self.$field_name.insert(value)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::collection_set_insert!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Adds `value` (Using `Into<" $value_type ">`) to the set field `" $fn_name
+                    "`, returning `true` if it was not already present."]
+            $fn_vis fn [<$fn_name _insert>]<T: Into<$value_type>>(&mut self, value: T) -> bool {
+                self.$field_name.insert(value.into())
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::collection_set_insert!($fn_vis $name => $name, into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Set <name>_remove
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that removes a value from a set field.
+///
+/// ## Forms
+///
+/// ### `collection_set_remove!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The generated function returns `true` if the value was present in the set.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # pub struct Address { tags: HashSet<String> }
+/// impl Address {
+///     // collection_set_remove!(pub tags => String);
+///
+///     /// Removes `value` from the set field `tags`, returning `true` if it was present.
+///     pub fn tags_remove(&mut self, value: &String) -> bool {
+///         self.tags.remove(value)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! collection_set_remove {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Removes `value` from the set field `" $fn_name "`, returning `true` if it was present."]
+            $fn_vis fn [<$fn_name _remove>](&mut self, value: &$value_type) -> bool {
+                self.$field_name.remove(value)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::collection_set_remove!($fn_vis $name => $name, $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Set <name>_iter
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that returns an iterator over the members of a set field.
+///
+/// ## Forms
+///
+/// ### `collection_set_iter!(viz name => [field_name,] [copy] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is an iterator over immutable references `&Type`,
+///   or if `copy` is specified, over copies `Type`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::BTreeSet;
+/// # pub struct Address { unit_numbers: BTreeSet<u32> }
+/// impl Address {
+///     // collection_set_iter!(pub unit_numbers => copy u32);
+///
+///     /// Returns an iterator over copies of the members of the set field `unit_numbers`.
+///     pub fn unit_numbers_iter(&self) -> impl Iterator<Item = u32> + '_ {
+///         self.unit_numbers.iter().copied()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! collection_set_iter {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns an iterator over references to the members of the set field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _iter>](&self) -> impl Iterator<Item = &$value_type> {
+                self.$field_name.iter()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::collection_set_iter!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *copy*: `viz name => field_name, copy Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns an iterator over copies of the members of the set field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _iter>](&self) -> impl Iterator<Item = $value_type> + '_ {
+                self.$field_name.iter().copied()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => copy Type`
+    ($fn_vis:vis $name:ident => copy $value_type:ty) => {
+        $crate::collection_set_iter!($fn_vis $name => $name, copy $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Set <name>_len
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that returns the number of members in a set field.
+///
+/// ## Forms
+///
+/// ### `collection_set_len!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # pub struct Address { tags: HashSet<String> }
+/// impl Address {
+///     // collection_set_len!(pub tags);
+///
+///     /// Returns the number of members in the set field `tags`.
+///     pub fn tags_len(&self) -> usize {
+///         self.tags.len()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! collection_set_len {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Returns the number of members in the set field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _len>](&self) -> usize {
+                self.$field_name.len()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::collection_set_len!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Set <name>_extend
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that adds all the values of an iterator to a set field.
+///
+/// ## Forms
+///
+/// ### `collection_set_extend!(viz name => [field_name,] [into] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the new values parameter is the trait-bound type `I: IntoIterator<Item = Type>`,
+///   or if `into` is specified `I: IntoIterator<Item = T>` where `T: Into<Type>`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # pub struct Address { tags: HashSet<String> }
+/// impl Address {
+///     // collection_set_extend!(pub tags => String);
+///
+///     /// Adds all the values of `values` to the set field `tags`.
+///     pub fn tags_extend<I: IntoIterator<Item = String>>(&mut self, values: I) {
+///         self.tags.extend(values);
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! collection_set_extend {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Adds all the values of `values` to the set field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _extend>]<I: IntoIterator<Item = $value_type>>(&mut self, values: I) {
+                self.$field_name.extend(values);
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::collection_set_extend!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Adds all the values of `values` to the set field `" $fn_name "` (Using `Into<"
+                    $value_type ">`)."]
+            $fn_vis fn [<$fn_name _extend>]<T: Into<$value_type>, I: IntoIterator<Item = T>>(&mut self, values: I) {
+                self.$field_name.extend(values.into_iter().map(Into::into));
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::collection_set_extend!($fn_vis $name => $name, into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Set <name>_union
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that returns an iterator over the union of a set field and another
+/// collection of values.
+///
+/// ## Forms
+///
+/// ### `collection_set_union!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The `other` parameter is any iterable of references, for example `&HashSet<Type>` or
+///   `&BTreeSet<Type>`, and does not need to be the same set type as the field.
+/// * The members of the field are returned first, followed by those members of `other` not
+///   already in the field; as with the members of a set each value is returned only once, even
+///   if it is repeated within `other`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # pub struct Address { tags: HashSet<String> }
+/// use jemmy::field::collection_set::SetMembers;
+///
+/// impl Address {
+///     // collection_set_union!(pub tags => String);
+///
+///     /// Returns an iterator over the members of the set field `tags` followed by the
+///     /// values in `other` that are not members of `tags`, each value is returned once.
+///     pub fn tags_union<'a, I: IntoIterator<Item = &'a String>>(
+///         &'a self,
+///         other: I,
+///     ) -> impl Iterator<Item = &'a String> {
+///         let mut first_seen = SetMembers::first_seen(&self.tags);
+///         self.tags.iter().chain(
+///             other
+///                 .into_iter()
+///                 .filter(move |value| !self.tags.contains(*value) && first_seen(*value)),
+///         )
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! collection_set_union {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns an iterator over the members of the set field `" $fn_name "` followed by "
+                    "the values in `other` that are not members of `" $fn_name "`, each value is "
+                    "returned once."]
+            $fn_vis fn [<$fn_name _union>]<'a, I: IntoIterator<Item = &'a $value_type>>(
+                &'a self,
+                other: I,
+            ) -> impl Iterator<Item = &'a $value_type> {
+                let mut first_seen = $crate::field::collection_set::SetMembers::first_seen(&self.$field_name);
+                self.$field_name.iter().chain(
+                    other
+                        .into_iter()
+                        .filter(move |value| !self.$field_name.contains(*value) && first_seen(*value)),
+                )
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::collection_set_union!($fn_vis $name => $name, $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Set with_<name>_member
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a builder style initializer method that adds a member to a set field.
+///
+/// ## Forms
+///
+/// ### `collection_set_with_member!(viz name => [field_name,] [into] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
+///   that initializers can be chained.
+/// * If the keyword `into` is specified the type of the new value parameter is the trait-bound
+///   type `T: Into<Type>` rather than `Type` for flexibility.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # pub struct Address { tags: HashSet<String> }
+/// impl Address {
+///     // collection_set_with_member!(pub tags => into String);
+///
+///     /// Add `value` to the set field `tags` within this structure, usually during
+///     /// construction. This function takes a mutable `self` parameter and returns
+///     /// `Self` allowing it to be chained during construction.
+///     pub fn with_tags_member<T: Into<String>>(mut self, value: T) -> Self {
+///         let _ = self.tags.insert(value.into());
+///         self
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! collection_set_with_member {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add `value` to the set field `" $fn_name "` within this structure, usually during "
+                    "construction. This function takes a mutable `self` parameter and returns `Self` "
+                    "allowing it to be chained during construction."]
+            $fn_vis fn [<with_ $fn_name _member>](mut self, value: $value_type) -> Self {
+                let _ = self.$field_name.insert(value);
+                self
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::collection_set_with_member!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add `value` (Using `Into<" $value_type ">`) to the set field `" $fn_name
+                    "` within this structure, usually during construction. This function takes a "
+                    "mutable `self` parameter and returns `Self` allowing it to be chained during "
+                    "construction."]
+            $fn_vis fn [<with_ $fn_name _member>]<T: Into<$value_type>>(mut self, value: T) -> Self {
+                let _ = self.$field_name.insert(value.into());
+                self
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::collection_set_with_member!($fn_vis $name => $name, into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ SetMembers
+// ------------------------------------------------------------------------------------------------
+
+impl<T: Hash + Eq, S> SetMembers<T> for HashSet<T, S> {
+    fn first_seen<'a>(&self) -> impl FnMut(&'a T) -> bool
+    where
+        T: 'a,
+    {
+        let mut seen = HashSet::new();
+        move |value| seen.insert(value)
+    }
+}
+
+impl<T: Ord> SetMembers<T> for BTreeSet<T> {
+    fn first_seen<'a>(&self) -> impl FnMut(&'a T) -> bool
+    where
+        T: 'a,
+    {
+        let mut seen = BTreeSet::new();
+        move |value| seen.insert(value)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::{
+    collection_set_contains, collection_set_extend, collection_set_insert, collection_set_iter,
+    collection_set_len, collection_set_remove, collection_set_union, collection_set_with_member,
+};
@@ -1,6 +1,7 @@
 /*!
  * Macros that produce combinations of methods using the core module's [`get`], [`get_mut`],
- * [`set`], [`unset`] and [`with`] macros.
+ * [`set`], [`unset`] and [`with`] macros, the vector module's `vec_*` macros, the flag
+ * module's `flag_*` macros, and the lock module's `mutex_*` and `rwlock_*` macros.
  */
 
 // ------------------------------------------------------------------------------------------------
@@ -136,8 +137,179 @@
 /// }
 /// ```
 ///
+/// ### `get_and_set!(viz name => [field_name,] [optional] deref [into] Type)`
+///
+/// This form generates simple getter and setter functions using the [`get`] and [`set`]  macros.
+///
+/// * The keyword `deref` is passed to the [`get`] macro only.
+/// * The keyword `optional` is passed to both both [`get`] and [`set`] macros.
+/// * The keyword `into` is passed to the [`set`] macro only.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// use jemmy::{get, set};
+/// impl Address {
+///     // get_and_set!(pub street_1 => deref into String);
+///     // get_and_set!(pub street_2 => optional deref String);
+///
+///     get!(pub street_1 => deref String);
+///     set!(pub street_1 => into String);
+///     get!(pub street_2 => optional deref String);
+///     set!(pub street_2 => optional String);
+/// }
+/// ```
+///
+/// ### `get_and_set!(viz name => [field_name,] [optional] as_ref Target, [into] Type)`
+///
+/// This form generates simple getter and setter functions using the [`get`] and [`set`]  macros.
+///
+/// * The keyword `as_ref` and the type `Target` are passed to the [`get`] macro only, while
+///   `Type`, the type of the field's value, is passed to the [`set`] macro.
+/// * The keyword `optional` is passed to both both [`get`] and [`set`] macros.
+/// * The keyword `into` is passed to the [`set`] macro only.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::path::{Path, PathBuf};
+/// # pub struct Address { map_file: PathBuf }
+/// use jemmy::{get, set};
+/// impl Address {
+///     // get_and_set!(pub map_file => as_ref Path, into PathBuf);
+///
+///     get!(pub map_file => as_ref Path);
+///     set!(pub map_file => into PathBuf);
+/// }
+/// ```
+///
+/// ### `get_and_set!(viz name => [field_name,] [optional] clone Type)`
+///
+/// This form generates simple getter and setter functions using the [`get`] and [`set`]  macros.
+///
+/// * The keyword `clone` is passed to the [`get`] macro only.
+/// * The keyword `optional` is passed to both both [`get`] and [`set`] macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// use jemmy::{get, set};
+/// impl Address {
+///     // get_and_set!(pub street_1 => clone String);
+///     // get_and_set!(pub street_2 => optional clone String);
+///
+///     get!(pub street_1 => clone String);
+///     set!(pub street_1 => String);
+///     get!(pub street_2 => optional clone String);
+///     set!(pub street_2 => optional String);
+/// }
+/// ```
+///
+/// ### `get_and_set!(viz name => [field_name,] [optional] boxed|shared Type)`
+///
+/// This form generates simple getter and setter functions using the [`get`] and [`set`]  macros.
+///
+/// * The keywords `boxed` and `shared` are passed to both the [`get`] and [`set`] macros.
+/// * The keyword `optional` is passed to both the [`get`] and [`set`] macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # pub struct Handler { path: String }
+/// # #[derive(Clone)] pub struct Config { port: u16 }
+/// # pub struct Server { handler: Box<Handler>, config: Arc<Config> }
+/// use jemmy::{get, set};
+/// impl Server {
+///     // get_and_set!(pub handler => boxed Handler);
+///     // get_and_set!(pub config => shared Arc<Config>);
+///
+///     get!(pub handler => boxed Handler);
+///     set!(pub handler => boxed Handler);
+///     get!(pub config => shared Arc<Config>);
+///     set!(pub config => shared Arc<Config>);
+/// }
+/// ```
+///
+/// ### `get_and_set!(viz name => [field_name,] cow 'a Type)`
+///
+/// This form generates simple getter and setter functions using the [`get`] and [`set`]  macros.
+///
+/// * The keyword `cow`, and its lifetime, are passed to both the [`get`] and [`set`] macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # pub struct Token<'a> { text: Cow<'a, str> }
+/// use jemmy::{get, set};
+/// impl<'a> Token<'a> {
+///     // get_and_set!(pub text => cow 'a str);
+///
+///     get!(pub text => cow 'a str);
+///     set!(pub text => cow 'a str);
+/// }
+/// ```
+///
+/// ### `get_and_set!(viz name => [field_name,] [optional] [copy|into] Type, validate = validator [-> Error])`
+///
+/// This form generates simple getter and *validated* setter functions using the [`get`] and
+/// [`set`]  macros.
+///
+/// * The keywords `optional`, `copy` and `into` are passed as in the forms above.
+/// * The validator clause is passed to the [`set`] macro only, which generates `try_set_name`
+///   in place of `set_name`.
+/// * The validator is either a function declared with the [`validator`](crate::validator)
+///   macro, or a type implementing [`Validator`](crate::error::Validator), in which case the
+///   error type is inferred; or any other function followed by `-> Error`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Order { quantity: u32 }
+/// # #[derive(Debug)] pub struct QuantityError;
+/// # jemmy::validator! { fn check_quantity(value: &u32) -> Result<(), QuantityError> { Ok(()) } }
+/// use jemmy::{get, set};
+/// impl Order {
+///     // get_and_set!(pub quantity => copy u32, validate = check_quantity);
+///
+///     get!(pub quantity => copy u32);
+///     set!(pub quantity => u32, validate = check_quantity);
+/// }
+/// ```
+///
+/// ### `get_and_set!(viz name => [field_name,] [optional] [copy|into] Type, constraint)`
+///
+/// This form generates simple getter and *validated* setter functions using the [`get`] and
+/// [`set`]  macros.
+///
+/// * The keywords `optional`, `copy` and `into` are passed as in the forms above.
+/// * The constraint is passed to the [`set`] macro only, which generates `try_set_name`
+///   in place of `set_name`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Person { age: u8 }
+/// use jemmy::{get, set};
+/// impl Person {
+///     // get_and_set!(pub age => copy u8, range 0..=150);
+///
+///     get!(pub age => copy u8);
+///     set!(pub age => u8, range 0..=150);
+/// }
+/// ```
+///
 #[macro_export]
 macro_rules! get_and_set {
+    // Case (23) without *field name* and with the constraint *non_empty* must precede the base
+    // case, which would otherwise match `String, non_empty` as a field name and a type.
+    ($fn_vis:vis $name:ident => $value_type:ty, non_empty) => {
+        $crate::get_and_set!($fn_vis $name => $name, $value_type, non_empty);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
     // Base case: `viz name => field_name, Type`
     ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
         $crate::get!($fn_vis $fn_name => $field_name, $value_type);
@@ -197,6 +369,279 @@ macro_rules! get_and_set {
     ($fn_vis:vis $name:ident => optional into $value_type:ty) => {
         $crate::get_and_set!($fn_vis $name => $name, optional into $value_type);
     };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (7) Base case with *deref*: `viz name => field_name, deref Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, deref $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, deref $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, $value_type);
+    };
+    // Case (7) without *field name*: `viz name => deref Type`
+    ($fn_vis:vis $name:ident => deref $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, deref $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (8) Case (7) with *into*: `viz name => field_name, deref into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, deref into $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, deref $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, into $value_type);
+    };
+    // Case (8) without *field name*: `viz name => deref into Type`
+    ($fn_vis:vis $name:ident => deref into $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, deref into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (9) Case (4) with *deref*: `viz name => field_name, optional deref Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional deref $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, optional deref $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, optional $value_type);
+    };
+    // Case (9) without *field name*: `viz name => optional deref Type`
+    ($fn_vis:vis $name:ident => optional deref $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, optional deref $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (10) Case (9) with *into*: `viz name => field_name, optional deref into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional deref into $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, optional deref $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, optional into $value_type);
+    };
+    // Case (10) without *field name*: `viz name => optional deref into Type`
+    ($fn_vis:vis $name:ident => optional deref into $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, optional deref into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (11) Base case with *as_ref*: `viz name => field_name, as_ref Target, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, as_ref $target_type:ty, $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, as_ref $target_type);
+        $crate::set!($fn_vis $fn_name => $field_name, $value_type);
+    };
+    // Case (11) without *field name*: `viz name => as_ref Target, Type`
+    ($fn_vis:vis $name:ident => as_ref $target_type:ty, $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, as_ref $target_type, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (12) Case (11) with *into*: `viz name => field_name, as_ref Target, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, as_ref $target_type:ty, into $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, as_ref $target_type);
+        $crate::set!($fn_vis $fn_name => $field_name, into $value_type);
+    };
+    // Case (12) without *field name*: `viz name => as_ref Target, into Type`
+    ($fn_vis:vis $name:ident => as_ref $target_type:ty, into $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, as_ref $target_type, into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (13) Case (4) with *as_ref*: `viz name => field_name, optional as_ref Target, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional as_ref $target_type:ty, $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, optional as_ref $target_type);
+        $crate::set!($fn_vis $fn_name => $field_name, optional $value_type);
+    };
+    // Case (13) without *field name*: `viz name => optional as_ref Target, Type`
+    ($fn_vis:vis $name:ident => optional as_ref $target_type:ty, $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, optional as_ref $target_type, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (14) Case (13) with *into*: `viz name => field_name, optional as_ref Target, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional as_ref $target_type:ty, into $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, optional as_ref $target_type);
+        $crate::set!($fn_vis $fn_name => $field_name, optional into $value_type);
+    };
+    // Case (14) without *field name*: `viz name => optional as_ref Target, into Type`
+    ($fn_vis:vis $name:ident => optional as_ref $target_type:ty, into $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, optional as_ref $target_type, into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (15) Base case with *clone*: `viz name => field_name, clone Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, clone $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, clone $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, $value_type);
+    };
+    // Case (15) without *field name*: `viz name => clone Type`
+    ($fn_vis:vis $name:ident => clone $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, clone $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (16) Case (4) with *clone*: `viz name => field_name, optional clone Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional clone $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, optional clone $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, optional $value_type);
+    };
+    // Case (16) without *field name*: `viz name => optional clone Type`
+    ($fn_vis:vis $name:ident => optional clone $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, optional clone $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (17) Base case with *validate*: `viz name => field_name, Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // Case (17) without *field name*: `viz name => Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (18) Case (17) with *copy*: `viz name => field_name, copy Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, copy $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // Case (18) without *field name*: `viz name => copy Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => copy $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, copy $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (19) Case (17) with *into*: `viz name => field_name, into Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, into $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // Case (19) without *field name*: `viz name => into Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, into $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (20) Case (17) with *optional*: `viz name => field_name, optional Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, optional $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, optional $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // Case (20) without *field name*: `viz name => optional Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => optional $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, optional $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (21) Case (20) with *copy*: `viz name => field_name, optional copy Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional copy $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, optional copy $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, optional $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // Case (21) without *field name*: `viz name => optional copy Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => optional copy $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, optional copy $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (22) Case (20) with *into*: `viz name => field_name, optional into Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, optional $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, optional into $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // Case (22) without *field name*: `viz name => optional into Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, optional into $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (23) Base case with *constraint*: `viz name => field_name, Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty, $($constraint:tt)+) => {
+        $crate::get!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, $value_type, $($constraint)+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (24) Case (23) with *copy*: `viz name => field_name, copy Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty, $($constraint:tt)+) => {
+        $crate::get!($fn_vis $fn_name => $field_name, copy $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, $value_type, $($constraint)+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (25) Case (23) with *into*: `viz name => field_name, into Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty, $($constraint:tt)+) => {
+        $crate::get!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, into $value_type, $($constraint)+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (26) Case (23) with *optional*: `viz name => field_name, optional Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty, $($constraint:tt)+) => {
+        $crate::get!($fn_vis $fn_name => $field_name, optional $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, optional $value_type, $($constraint)+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (27) Case (26) with *copy*: `viz name => field_name, optional copy Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional copy $value_type:ty, $($constraint:tt)+) => {
+        $crate::get!($fn_vis $fn_name => $field_name, optional copy $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, optional $value_type, $($constraint)+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (28) Case (26) with *into*: `viz name => field_name, optional into Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty, $($constraint:tt)+) => {
+        $crate::get!($fn_vis $fn_name => $field_name, optional $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, optional into $value_type, $($constraint)+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (29) Base case with *boxed*: `viz name => field_name, boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, boxed $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, boxed $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, boxed $value_type);
+    };
+    // Case (29) without *field name*: `viz name => boxed Type`
+    ($fn_vis:vis $name:ident => boxed $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, boxed $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (30) Case (4) with *boxed*: `viz name => field_name, optional boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional boxed $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, optional boxed $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, optional boxed $value_type);
+    };
+    // Case (30) without *field name*: `viz name => optional boxed Type`
+    ($fn_vis:vis $name:ident => optional boxed $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, optional boxed $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (31) Base case with *shared*: `viz name => field_name, shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, shared $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, shared $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, shared $value_type);
+    };
+    // Case (31) without *field name*: `viz name => shared Type`
+    ($fn_vis:vis $name:ident => shared $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, shared $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (32) Case (4) with *shared*: `viz name => field_name, optional shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional shared $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, optional shared $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, optional shared $value_type);
+    };
+    // Case (32) without *field name*: `viz name => optional shared Type`
+    ($fn_vis:vis $name:ident => optional shared $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, optional shared $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (33) Base case with *cow*: `viz name => field_name, cow 'a Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, cow $lifetime:lifetime $value_type:ty) => {
+        $crate::get!($fn_vis $fn_name => $field_name, cow $lifetime $value_type);
+        $crate::set!($fn_vis $fn_name => $field_name, cow $lifetime $value_type);
+    };
+    // Case (33) without *field name*: `viz name => cow 'a Type`
+    ($fn_vis:vis $name:ident => cow $lifetime:lifetime $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $name => $name, cow $lifetime $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // The constraint cases without *field name* must follow all those with *field name*, the
+    // trailing constraint tokens would otherwise match the delegated form's keywords.
+    // Case (23) without *field name*: `viz name => Type, constraint`
+    ($fn_vis:vis $name:ident => $value_type:ty, $($constraint:tt)+) => {
+        $crate::get_and_set!($fn_vis $name => $name, $value_type, $($constraint)+);
+    };
+    // Case (24) without *field name*: `viz name => copy Type, constraint`
+    ($fn_vis:vis $name:ident => copy $value_type:ty, $($constraint:tt)+) => {
+        $crate::get_and_set!($fn_vis $name => $name, copy $value_type, $($constraint)+);
+    };
+    // Case (25) without *field name*: `viz name => into Type, constraint`
+    ($fn_vis:vis $name:ident => into $value_type:ty, $($constraint:tt)+) => {
+        $crate::get_and_set!($fn_vis $name => $name, into $value_type, $($constraint)+);
+    };
+    // Case (26) without *field name*: `viz name => optional Type, constraint`
+    ($fn_vis:vis $name:ident => optional $value_type:ty, $($constraint:tt)+) => {
+        $crate::get_and_set!($fn_vis $name => $name, optional $value_type, $($constraint)+);
+    };
+    // Case (27) without *field name*: `viz name => optional copy Type, constraint`
+    ($fn_vis:vis $name:ident => optional copy $value_type:ty, $($constraint:tt)+) => {
+        $crate::get_and_set!($fn_vis $name => $name, optional copy $value_type, $($constraint)+);
+    };
+    // Case (28) without *field name*: `viz name => optional into Type, constraint`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty, $($constraint:tt)+) => {
+        $crate::get_and_set!($fn_vis $name => $name, optional into $value_type, $($constraint)+);
+    };
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -338,198 +783,1504 @@ macro_rules! get_and_set {
 /// }
 /// ```
 ///
-#[macro_export]
-macro_rules! with_get_and_set {
-    // Base case: `viz name => field_name, Type`
-    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
-        $crate::with!($fn_vis $fn_name => $field_name, $value_type);
-        $crate::get_and_set!($fn_vis $fn_name => $field_name, $value_type);
-    };
-    // Base case without *field name*: `viz name => Type`
-    ($fn_vis:vis $name:ident => $value_type:ty) => {
-        $crate::with_get_and_set!($fn_vis $name => $name, $value_type);
-    };
-    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
-    // (2) Base case with *copy*: `viz name => field_name, copy Type`
-    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty) => {
-        $crate::with!($fn_vis $fn_name => $field_name, $value_type);
-        $crate::get_and_set!($fn_vis $fn_name => $field_name, copy $value_type);
-    };
-    // Case (2) without *field name*: `viz name => copy Type`
-    ($fn_vis:vis $name:ident => copy $value_type:ty) => {
-        $crate::with_get_and_set!($fn_vis $name => $name, copy $value_type);
-    };
-    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
-    // (3) Base case with *into*: `viz name => field_name, into Type`
-    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
-        $crate::with!($fn_vis $fn_name => $field_name, into $value_type);
-        $crate::get_and_set!($fn_vis $fn_name => $field_name, into $value_type);
-    };
-    // Case (3) without *field name*: `viz name => into Type`
-    ($fn_vis:vis $name:ident => into $value_type:ty) => {
-        $crate::with_get_and_set!($fn_vis $name => $name, into $value_type);
-    };
-    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
-    // (4) Base case with *optional*: `viz name => field_name, optional Type`
-    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty) => {
-        $crate::with!($fn_vis $fn_name => $field_name, optional $value_type);
-        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional $value_type);
-    };
-    // Case (4) without *field name*: `viz name => optional Type`
-    ($fn_vis:vis $name:ident => optional $value_type:ty) => {
-        $crate::with_get_and_set!($fn_vis $name => $name, optional $value_type);
-    };
-    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
-    // (6) Case (4) with *copy*: `viz name => field_name, optional copy Type`
-    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional copy $value_type:ty) => {
-        $crate::with!($fn_vis $fn_name => $field_name, optional $value_type);
-        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional copy $value_type);
-    };
-    // Case (6) without *field name*: `viz name => optional copy Type`
-    ($fn_vis:vis $name:ident => optional copy $value_type:ty) => {
-        $crate::with_get_and_set!($fn_vis $name => $name, optional copy $value_type);
-    };
-    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
-    // (6) Case (4) with *into*: `viz name => field_name, optional into Type`
-    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty) => {
-        $crate::with!($fn_vis $fn_name => $field_name, optional into $value_type);
-        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional into $value_type);
-    };
+/// ### `with_get_and_set!(viz name => [field_name,] [optional] deref [into] Type)`
+///
+/// This form generates simple initializer, getter and setter functions using the [`with`],
+/// [`get`] and [`set`]  macros.
+///
+/// * The keyword `deref` is passed to the [`get`] macro only.
+/// * The keywords `optional` and `into` are passed to the [`with`] and [`set`] macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // with_get_and_set!(pub street_1 => deref into String);
+///
+///     with!(pub street_1 => into String);
+///     get_and_set!(pub street_1 => deref into String);
+/// }
+/// ```
+///
+/// ### `with_get_and_set!(viz name => [field_name,] [optional] as_ref Target, [into] Type)`
+///
+/// This form generates simple initializer, getter and setter functions using the [`with`],
+/// [`get`] and [`set`]  macros.
+///
+/// * The keyword `as_ref` and the type `Target` are passed to the [`get`] macro only.
+/// * The keywords `optional` and `into` are passed to the [`with`] and [`set`] macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # use std::path::{Path, PathBuf};
+/// # pub struct Address { map_file: PathBuf }
+/// impl Address {
+///     // with_get_and_set!(pub map_file => as_ref Path, into PathBuf);
+///
+///     with!(pub map_file => into PathBuf);
+///     get_and_set!(pub map_file => as_ref Path, into PathBuf);
+/// }
+/// ```
+///
+/// ### `with_get_and_set!(viz name => [field_name,] [optional] clone Type)`
+///
+/// This form generates simple initializer, getter and setter functions using the [`with`],
+/// [`get`] and [`set`]  macros.
+///
+/// * The keyword `clone` is passed to the [`get`] macro only.
+/// * The keyword `optional` is passed to the [`with`] and [`set`] macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // with_get_and_set!(pub street_2 => optional clone String);
+///
+///     with!(pub street_2 => optional String);
+///     get_and_set!(pub street_2 => optional clone String);
+/// }
+/// ```
+///
+/// ### `with_get_and_set!(viz name => [field_name,] [optional] boxed|shared Type)`
+///
+/// This form generates initializer, getter, and setter functions using the [`with`] and
+/// [`get_and_set`] macros.
+///
+/// * The keywords `boxed` and `shared` are passed to the [`with`], [`get`], and [`set`] macros.
+/// * The keyword `optional` is passed to the [`with`], [`get`], and [`set`] macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # pub struct Handler { path: String }
+/// # #[derive(Clone)] pub struct Config { port: u16 }
+/// # pub struct Server { handler: Box<Handler>, config: Arc<Config> }
+/// use jemmy::{get_and_set, with};
+/// impl Server {
+///     // with_get_and_set!(pub handler => boxed Handler);
+///     // with_get_and_set!(pub config => shared Arc<Config>);
+///
+///     with!(pub handler => boxed Handler);
+///     get_and_set!(pub handler => boxed Handler);
+///     with!(pub config => shared Arc<Config>);
+///     get_and_set!(pub config => shared Arc<Config>);
+/// }
+/// ```
+///
+/// ### `with_get_and_set!(viz name => [field_name,] cow 'a Type)`
+///
+/// This form generates initializer, getter, and setter functions using the [`with`] and
+/// [`get_and_set`] macros.
+///
+/// * The keyword `cow`, and its lifetime, are passed to the [`with`], [`get`], and [`set`] macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # pub struct Token<'a> { text: Cow<'a, str> }
+/// use jemmy::{get_and_set, with};
+/// impl<'a> Token<'a> {
+///     // with_get_and_set!(pub text => cow 'a str);
+///
+///     with!(pub text => cow 'a str);
+///     get_and_set!(pub text => cow 'a str);
+/// }
+/// ```
+///
+/// ### `with_get_and_set!(viz name => [field_name,] [optional] [copy|into] Type, validate = validator [-> Error])`
+///
+/// This form generates simple initializer, getter and setter functions using the [`with`],
+/// [`get`] and [`set`]  macros, where the initializer and setter are *validated*.
+///
+/// * The keywords `optional`, `copy` and `into` are passed as in the forms above.
+/// * The validator clause is passed to the [`with`] and [`set`] macros, which generate
+///   `try_with_name` and `try_set_name` in place of `with_name` and `set_name`.
+/// * The validator is either a function declared with the [`validator`](crate::validator)
+///   macro, or a type implementing [`Validator`](crate::error::Validator), in which case the
+///   error type is inferred; or any other function followed by `-> Error`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Order { quantity: u32 }
+/// # #[derive(Debug)] pub struct QuantityError;
+/// # jemmy::validator! { fn check_quantity(value: &u32) -> Result<(), QuantityError> { Ok(()) } }
+/// impl Order {
+///     // with_get_and_set!(pub quantity => copy u32, validate = check_quantity);
+///
+///     with!(pub quantity => u32, validate = check_quantity);
+///     get_and_set!(pub quantity => copy u32, validate = check_quantity);
+/// }
+/// ```
+///
+/// ### `with_get_and_set!(viz name => [field_name,] [optional] [copy|into] Type, constraint)`
+///
+/// This form generates simple initializer, getter and setter functions using the [`with`],
+/// [`get`] and [`set`]  macros, where the initializer and setter are *validated*.
+///
+/// * The keywords `optional`, `copy` and `into` are passed as in the forms above.
+/// * The constraint is passed to the [`with`] and [`set`] macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Person { name: String }
+/// impl Person {
+///     // with_get_and_set!(pub name => into String, max_len 64);
+///
+///     with!(pub name => into String, max_len 64);
+///     get_and_set!(pub name => into String, max_len 64);
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! with_get_and_set {
+    // Case (23) without *field name* and with the constraint *non_empty* must precede the base
+    // case, which would otherwise match `String, non_empty` as a field name and a type.
+    ($fn_vis:vis $name:ident => $value_type:ty, non_empty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, $value_type, non_empty);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, $value_type);
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *copy*: `viz name => field_name, copy Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, copy $value_type);
+    };
+    // Case (2) without *field name*: `viz name => copy Type`
+    ($fn_vis:vis $name:ident => copy $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, copy $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (3) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, into $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, into $value_type);
+    };
+    // Case (3) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (4) Base case with *optional*: `viz name => field_name, optional Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional $value_type);
+    };
+    // Case (4) without *field name*: `viz name => optional Type`
+    ($fn_vis:vis $name:ident => optional $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (6) Case (4) with *copy*: `viz name => field_name, optional copy Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional copy $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional copy $value_type);
+    };
+    // Case (6) without *field name*: `viz name => optional copy Type`
+    ($fn_vis:vis $name:ident => optional copy $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional copy $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (6) Case (4) with *into*: `viz name => field_name, optional into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional into $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional into $value_type);
+    };
     // Case (6) without *field name*: `viz name => optional into Type`
     ($fn_vis:vis $name:ident => optional into $value_type:ty) => {
         $crate::with_get_and_set!($fn_vis $name => $name, optional into $value_type);
     };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (7) Base case with *deref*: `viz name => field_name, deref Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, deref $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, deref $value_type);
+    };
+    // Case (7) without *field name*: `viz name => deref Type`
+    ($fn_vis:vis $name:ident => deref $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, deref $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (8) Case (7) with *into*: `viz name => field_name, deref into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, deref into $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, into $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, deref into $value_type);
+    };
+    // Case (8) without *field name*: `viz name => deref into Type`
+    ($fn_vis:vis $name:ident => deref into $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, deref into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (9) Case (4) with *deref*: `viz name => field_name, optional deref Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional deref $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional deref $value_type);
+    };
+    // Case (9) without *field name*: `viz name => optional deref Type`
+    ($fn_vis:vis $name:ident => optional deref $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional deref $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (10) Case (9) with *into*: `viz name => field_name, optional deref into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional deref into $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional into $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional deref into $value_type);
+    };
+    // Case (10) without *field name*: `viz name => optional deref into Type`
+    ($fn_vis:vis $name:ident => optional deref into $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional deref into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (11) Base case with *as_ref*: `viz name => field_name, as_ref Target, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, as_ref $target_type:ty, $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, as_ref $target_type, $value_type);
+    };
+    // Case (11) without *field name*: `viz name => as_ref Target, Type`
+    ($fn_vis:vis $name:ident => as_ref $target_type:ty, $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, as_ref $target_type, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (12) Case (11) with *into*: `viz name => field_name, as_ref Target, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, as_ref $target_type:ty, into $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, into $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, as_ref $target_type, into $value_type);
+    };
+    // Case (12) without *field name*: `viz name => as_ref Target, into Type`
+    ($fn_vis:vis $name:ident => as_ref $target_type:ty, into $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, as_ref $target_type, into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (13) Case (4) with *as_ref*: `viz name => field_name, optional as_ref Target, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional as_ref $target_type:ty, $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional as_ref $target_type, $value_type);
+    };
+    // Case (13) without *field name*: `viz name => optional as_ref Target, Type`
+    ($fn_vis:vis $name:ident => optional as_ref $target_type:ty, $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional as_ref $target_type, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (14) Case (13) with *into*: `viz name => field_name, optional as_ref Target, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional as_ref $target_type:ty, into $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional into $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional as_ref $target_type, into $value_type);
+    };
+    // Case (14) without *field name*: `viz name => optional as_ref Target, into Type`
+    ($fn_vis:vis $name:ident => optional as_ref $target_type:ty, into $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional as_ref $target_type, into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (15) Base case with *clone*: `viz name => field_name, clone Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, clone $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, clone $value_type);
+    };
+    // Case (15) without *field name*: `viz name => clone Type`
+    ($fn_vis:vis $name:ident => clone $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, clone $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (16) Case (4) with *clone*: `viz name => field_name, optional clone Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional clone $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional clone $value_type);
+    };
+    // Case (16) without *field name*: `viz name => optional clone Type`
+    ($fn_vis:vis $name:ident => optional clone $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional clone $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (17) Base case with *validate*: `viz name => field_name, Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, $value_type, validate = $($validator)::+ -> $error_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // Case (17) without *field name*: `viz name => Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (18) Case (17) with *copy*: `viz name => field_name, copy Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, $value_type, validate = $($validator)::+ -> $error_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, copy $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // Case (18) without *field name*: `viz name => copy Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => copy $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, copy $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (19) Case (17) with *into*: `viz name => field_name, into Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, into $value_type, validate = $($validator)::+ -> $error_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, into $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // Case (19) without *field name*: `viz name => into Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, into $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (20) Case (17) with *optional*: `viz name => field_name, optional Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional $value_type, validate = $($validator)::+ -> $error_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // Case (20) without *field name*: `viz name => optional Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => optional $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (21) Case (20) with *copy*: `viz name => field_name, optional copy Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional copy $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional $value_type, validate = $($validator)::+ -> $error_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional copy $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // Case (21) without *field name*: `viz name => optional copy Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => optional copy $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional copy $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (22) Case (20) with *into*: `viz name => field_name, optional into Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional into $value_type, validate = $($validator)::+ -> $error_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional into $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // Case (22) without *field name*: `viz name => optional into Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional into $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (23) Base case with *constraint*: `viz name => field_name, Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty, $($constraint:tt)+) => {
+        $crate::with!($fn_vis $fn_name => $field_name, $value_type, $($constraint)+);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, $value_type, $($constraint)+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (24) Case (23) with *copy*: `viz name => field_name, copy Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty, $($constraint:tt)+) => {
+        $crate::with!($fn_vis $fn_name => $field_name, $value_type, $($constraint)+);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, copy $value_type, $($constraint)+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (25) Case (23) with *into*: `viz name => field_name, into Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty, $($constraint:tt)+) => {
+        $crate::with!($fn_vis $fn_name => $field_name, into $value_type, $($constraint)+);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, into $value_type, $($constraint)+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (26) Case (23) with *optional*: `viz name => field_name, optional Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty, $($constraint:tt)+) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional $value_type, $($constraint)+);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional $value_type, $($constraint)+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (27) Case (26) with *copy*: `viz name => field_name, optional copy Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional copy $value_type:ty, $($constraint:tt)+) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional $value_type, $($constraint)+);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional copy $value_type, $($constraint)+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (28) Case (26) with *into*: `viz name => field_name, optional into Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty, $($constraint:tt)+) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional into $value_type, $($constraint)+);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional into $value_type, $($constraint)+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (29) Base case with *boxed*: `viz name => field_name, boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, boxed $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, boxed $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, boxed $value_type);
+    };
+    // Case (29) without *field name*: `viz name => boxed Type`
+    ($fn_vis:vis $name:ident => boxed $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, boxed $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (30) Case (4) with *boxed*: `viz name => field_name, optional boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional boxed $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional boxed $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional boxed $value_type);
+    };
+    // Case (30) without *field name*: `viz name => optional boxed Type`
+    ($fn_vis:vis $name:ident => optional boxed $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional boxed $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (31) Base case with *shared*: `viz name => field_name, shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, shared $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, shared $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, shared $value_type);
+    };
+    // Case (31) without *field name*: `viz name => shared Type`
+    ($fn_vis:vis $name:ident => shared $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, shared $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (32) Case (4) with *shared*: `viz name => field_name, optional shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional shared $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, optional shared $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional shared $value_type);
+    };
+    // Case (32) without *field name*: `viz name => optional shared Type`
+    ($fn_vis:vis $name:ident => optional shared $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional shared $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (33) Base case with *cow*: `viz name => field_name, cow 'a Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, cow $lifetime:lifetime $value_type:ty) => {
+        $crate::with!($fn_vis $fn_name => $field_name, cow $lifetime $value_type);
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, cow $lifetime $value_type);
+    };
+    // Case (33) without *field name*: `viz name => cow 'a Type`
+    ($fn_vis:vis $name:ident => cow $lifetime:lifetime $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, cow $lifetime $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // The constraint cases without *field name* must follow all those with *field name*, the
+    // trailing constraint tokens would otherwise match the delegated form's keywords.
+    // Case (23) without *field name*: `viz name => Type, constraint`
+    ($fn_vis:vis $name:ident => $value_type:ty, $($constraint:tt)+) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, $value_type, $($constraint)+);
+    };
+    // Case (24) without *field name*: `viz name => copy Type, constraint`
+    ($fn_vis:vis $name:ident => copy $value_type:ty, $($constraint:tt)+) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, copy $value_type, $($constraint)+);
+    };
+    // Case (25) without *field name*: `viz name => into Type, constraint`
+    ($fn_vis:vis $name:ident => into $value_type:ty, $($constraint:tt)+) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, into $value_type, $($constraint)+);
+    };
+    // Case (26) without *field name*: `viz name => optional Type, constraint`
+    ($fn_vis:vis $name:ident => optional $value_type:ty, $($constraint:tt)+) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional $value_type, $($constraint)+);
+    };
+    // Case (27) without *field name*: `viz name => optional copy Type, constraint`
+    ($fn_vis:vis $name:ident => optional copy $value_type:ty, $($constraint:tt)+) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional copy $value_type, $($constraint)+);
+    };
+    // Case (28) without *field name*: `viz name => optional into Type, constraint`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty, $($constraint:tt)+) => {
+        $crate::with_get_and_set!($fn_vis $name => $name, optional into $value_type, $($constraint)+);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Combinator Macros ❱ Get, Set, and Unset
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate [`get`], [`set`], and [`unset`] for an optional struct field.
+///
+/// ## Forms
+///
+/// ### `get_set_and_unset!(viz name => Type)`
+///
+/// This form generates simple getter, setter and un-setter functions using the [`get`],
+/// [`set`] and [`unset`] macros.
+///
+/// * In this form `name` is used in both the naming of the generated functions and the
+///   name of as the structure's field.
+/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // get_set_and_unset!(pub street_2 => String);
+///
+///     get_and_set!(pub street_2 => optional String);
+///     unset!(pub street_2);
+/// }
+/// ```
+///
+/// ### `get_set_and_unset!(viz name => field_name, Type)`
+///
+/// This form generates simple getter, setter and un-setter functions using the [`get`],
+/// [`set`] and [`unset`] macros.
+///
+/// * In this form `name` is used in naming the generated functions while `field_name` is the
+///   name of the structure's field.
+/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2_string: Option<String> }
+/// impl Address {
+///     // get_set_and_unset!(pub street_2 => street_2_string, String);
+///
+///     get_and_set!(pub street_2 => street_2_string, optional String);
+///     unset!(pub street_2 => street_2_string);
+/// }
+/// ```
+///
+/// ### `get_set_and_unset!(viz name => [field_name,] deref Type)`
+///
+/// This form generates simple getter, setter and un-setter functions using the [`get`],
+/// [`set`] and [`unset`] macros.
+///
+/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+/// * The keyword `deref` is passed to the [`get`] macro only.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // get_set_and_unset!(pub street_2 => deref String);
+///
+///     get_and_set!(pub street_2 => optional deref String);
+///     unset!(pub street_2);
+/// }
+/// ```
+///
+/// ### `get_set_and_unset!(viz name => [field_name,] as_ref Target, Type)`
+///
+/// This form generates simple getter, setter and un-setter functions using the [`get`],
+/// [`set`] and [`unset`] macros.
+///
+/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+/// * The keyword `as_ref` and the type `Target` are passed to the [`get`] macro only.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # use std::path::{Path, PathBuf};
+/// # pub struct Address { map_file: Option<PathBuf> }
+/// impl Address {
+///     // get_set_and_unset!(pub map_file => as_ref Path, PathBuf);
+///
+///     get_and_set!(pub map_file => optional as_ref Path, PathBuf);
+///     unset!(pub map_file);
+/// }
+/// ```
+///
+/// ### `get_set_and_unset!(viz name => [field_name,] clone Type)`
+///
+/// This form generates simple getter, setter and un-setter functions using the [`get`],
+/// [`set`] and [`unset`] macros.
+///
+/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+/// * The keyword `clone` is passed to the [`get`] macro only.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // get_set_and_unset!(pub street_2 => clone String);
+///
+///     get_and_set!(pub street_2 => optional clone String);
+///     unset!(pub street_2);
+/// }
+/// ```
+///
+/// ### `get_set_and_unset!(viz name => [field_name,] boxed|shared Type)`
+///
+/// This form generates simple getter, setter and un-setter functions for an optional boxed, or shared, field.
+///
+/// * Because unset is present, the `optional` keyword is used along with the keywords
+///   `boxed` and `shared`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # pub struct Handler { path: String }
+/// # #[derive(Clone)] pub struct Config { port: u16 }
+/// # pub struct Server { handler: Option<Box<Handler>>, config: Option<Arc<Config>> }
+/// use jemmy::{get_and_set, unset};
+/// impl Server {
+///     // get_set_and_unset!(pub handler => boxed Handler);
+///     // get_set_and_unset!(pub config => shared Arc<Config>);
+///
+///     get_and_set!(pub handler => optional boxed Handler);
+///     unset!(pub handler);
+///     get_and_set!(pub config => optional shared Arc<Config>);
+///     unset!(pub config);
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! get_set_and_unset {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional $value_type);
+        $crate::unset!($fn_vis $fn_name => $field_name);
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::get_set_and_unset!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *deref*: `viz name => field_name, deref Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, deref $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional deref $value_type);
+        $crate::unset!($fn_vis $fn_name => $field_name);
+    };
+    // Case (2) without *field name*: `viz name => deref Type`
+    ($fn_vis:vis $name:ident => deref $value_type:ty) => {
+        $crate::get_set_and_unset!($fn_vis $name => $name, deref $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (3) Base case with *as_ref*: `viz name => field_name, as_ref Target, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, as_ref $target_type:ty, $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional as_ref $target_type, $value_type);
+        $crate::unset!($fn_vis $fn_name => $field_name);
+    };
+    // Case (3) without *field name*: `viz name => as_ref Target, Type`
+    ($fn_vis:vis $name:ident => as_ref $target_type:ty, $value_type:ty) => {
+        $crate::get_set_and_unset!($fn_vis $name => $name, as_ref $target_type, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (4) Base case with *clone*: `viz name => field_name, clone Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, clone $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional clone $value_type);
+        $crate::unset!($fn_vis $fn_name => $field_name);
+    };
+    // Case (4) without *field name*: `viz name => clone Type`
+    ($fn_vis:vis $name:ident => clone $value_type:ty) => {
+        $crate::get_set_and_unset!($fn_vis $name => $name, clone $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (5) Base case with *boxed*: `viz name => field_name, boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, boxed $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional boxed $value_type);
+        $crate::unset!($fn_vis $fn_name => $field_name);
+    };
+    // Case (5) without *field name*: `viz name => boxed Type`
+    ($fn_vis:vis $name:ident => boxed $value_type:ty) => {
+        $crate::get_set_and_unset!($fn_vis $name => $name, boxed $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (6) Base case with *shared*: `viz name => field_name, shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, shared $value_type:ty) => {
+        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional shared $value_type);
+        $crate::unset!($fn_vis $fn_name => $field_name);
+    };
+    // Case (6) without *field name*: `viz name => shared Type`
+    ($fn_vis:vis $name:ident => shared $value_type:ty) => {
+        $crate::get_set_and_unset!($fn_vis $name => $name, shared $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Combinator Macros ❱ Get, Get Mutable, Set, and Unset
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate [`get`], [`get_mut`], [`set`], and [`unset`] for an optional struct field.
+///
+/// ## Forms
+///
+/// ### `get_mut_set_and_unset!(viz name => [field_name,] Type)`
+///
+/// This form generates simple getter, mutable getter, setter and un-setter functions using
+/// the [`get_set_and_unset`] and [`get_mut`] macros.
+///
+/// * In this form `name` is used to name the generated functions, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * Because unset is present, the `optional` keyword is used on the get, get_mut, and set
+///   macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // get_mut_set_and_unset!(pub street_2 => String);
+///
+///     get_set_and_unset!(pub street_2 => String);
+///     get_mut!(pub street_2 => optional String);
+/// }
+/// ```
+///
+/// ### `get_mut_set_and_unset!(viz name => [field_name,] or_default Type)`
+///
+/// This form generates simple getter, mutable getter, setter and un-setter functions using
+/// the [`get_set_and_unset`] and [`get_mut`] macros.
+///
+/// * Because unset is present, the `optional` keyword is used on the get, get_mut, and set
+///   macros.
+/// * The keyword `or_default` is passed to the [`get_mut`] macro only.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // get_mut_set_and_unset!(pub street_2 => or_default String);
+///
+///     get_set_and_unset!(pub street_2 => String);
+///     get_mut!(pub street_2 => optional or_default String);
+/// }
+/// ```
+///
+/// ### `get_mut_set_and_unset!(viz name => [field_name,] boxed|shared Type)`
+///
+/// This form generates simple getter, mutable getter, setter and un-setter functions for an optional boxed, or shared, field.
+///
+/// * Because unset is present, the `optional` keyword is used along with the keywords
+///   `boxed` and `shared`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # pub struct Handler { path: String }
+/// # #[derive(Clone)] pub struct Config { port: u16 }
+/// # pub struct Server { handler: Option<Box<Handler>>, config: Option<Arc<Config>> }
+/// use jemmy::{get_mut, get_set_and_unset};
+/// impl Server {
+///     // get_mut_set_and_unset!(pub handler => boxed Handler);
+///     // get_mut_set_and_unset!(pub config => shared Arc<Config>);
+///
+///     get_set_and_unset!(pub handler => boxed Handler);
+///     get_mut!(pub handler => optional boxed Handler);
+///     get_set_and_unset!(pub config => shared Arc<Config>);
+///     get_mut!(pub config => optional shared Arc<Config>);
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! get_mut_set_and_unset {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        $crate::get_set_and_unset!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::get_mut!($fn_vis $fn_name => $field_name, optional $value_type);
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::get_mut_set_and_unset!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *or_default*: `viz name => field_name, or_default Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, or_default $value_type:ty) => {
+        $crate::get_set_and_unset!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::get_mut!($fn_vis $fn_name => $field_name, optional or_default $value_type);
+    };
+    // Case (2) without *field name*: `viz name => or_default Type`
+    ($fn_vis:vis $name:ident => or_default $value_type:ty) => {
+        $crate::get_mut_set_and_unset!($fn_vis $name => $name, or_default $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (3) Base case with *boxed*: `viz name => field_name, boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, boxed $value_type:ty) => {
+        $crate::get_set_and_unset!($fn_vis $fn_name => $field_name, boxed $value_type);
+        $crate::get_mut!($fn_vis $fn_name => $field_name, optional boxed $value_type);
+    };
+    // Case (3) without *field name*: `viz name => boxed Type`
+    ($fn_vis:vis $name:ident => boxed $value_type:ty) => {
+        $crate::get_mut_set_and_unset!($fn_vis $name => $name, boxed $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (4) Base case with *shared*: `viz name => field_name, shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, shared $value_type:ty) => {
+        $crate::get_set_and_unset!($fn_vis $fn_name => $field_name, shared $value_type);
+        $crate::get_mut!($fn_vis $fn_name => $field_name, optional shared $value_type);
+    };
+    // Case (4) without *field name*: `viz name => shared Type`
+    ($fn_vis:vis $name:ident => shared $value_type:ty) => {
+        $crate::get_mut_set_and_unset!($fn_vis $name => $name, shared $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Combinator Macros ❱ With, Get, Set, and Unset
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate [`with`], [`get`], [`set`], and [`unset`] for an optional struct field.
+///
+/// ## Forms
+///
+/// ### `with_get_set_and_unset!(viz name => Type)`
+///
+/// This form generates simple initializer, getter, setter and un-setter functions using the
+/// [`with`], [`get`], [`set`] and [`unset`] macros.
+///
+/// * In this form `name` is used in both the naming of the generated functions and the
+///   name of as the structure's field.
+/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // with_get_set_and_unset!(pub street_2 => String);
+///
+///     with_get_and_set!(pub street_2 => optional String);
+///     unset!(pub street_2);
+/// }
+/// ```
+///
+/// ### `with_get_set_and_unset!(viz name => field_name, Type)`
+///
+/// This form generates simple initializer, getter, setter and un-setter functions using the
+/// [`with`], [`get`], [`set`] and [`unset`] macros.
+///
+/// * In this form `name` is used in naming the generated functions while `field_name` is the
+///   name of the structure's field.
+/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2_string: Option<String> }
+/// impl Address {
+///     // with_get_set_and_unset!(pub street_2 => street_2_string, String);
+///
+///     with_get_and_set!(pub street_2 => street_2_string, optional String);
+///     unset!(pub street_2 => street_2_string);
+/// }
+/// ```
+///
+/// ### `with_get_set_and_unset!(viz name => [field_name,] deref Type)`
+///
+/// This form generates simple initializer, getter, setter and un-setter functions using the
+/// [`with`], [`get`], [`set`] and [`unset`] macros.
+///
+/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+/// * The keyword `deref` is passed to the [`get`] macro only.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // with_get_set_and_unset!(pub street_2 => deref String);
+///
+///     with_get_and_set!(pub street_2 => optional deref String);
+///     unset!(pub street_2);
+/// }
+/// ```
+///
+/// ### `with_get_set_and_unset!(viz name => [field_name,] as_ref Target, Type)`
+///
+/// This form generates simple initializer, getter, setter and un-setter functions using the
+/// [`with`], [`get`], [`set`] and [`unset`] macros.
+///
+/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+/// * The keyword `as_ref` and the type `Target` are passed to the [`get`] macro only.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # use std::path::{Path, PathBuf};
+/// # pub struct Address { map_file: Option<PathBuf> }
+/// impl Address {
+///     // with_get_set_and_unset!(pub map_file => as_ref Path, PathBuf);
+///
+///     with_get_and_set!(pub map_file => optional as_ref Path, PathBuf);
+///     unset!(pub map_file);
+/// }
+/// ```
+///
+/// ### `with_get_set_and_unset!(viz name => [field_name,] clone Type)`
+///
+/// This form generates simple initializer, getter, setter and un-setter functions using the
+/// [`with`], [`get`], [`set`] and [`unset`] macros.
+///
+/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+/// * The keyword `clone` is passed to the [`get`] macro only.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // with_get_set_and_unset!(pub street_2 => clone String);
+///
+///     with_get_and_set!(pub street_2 => optional clone String);
+///     unset!(pub street_2);
+/// }
+/// ```
+///
+/// ### `with_get_set_and_unset!(viz name => [field_name,] boxed|shared Type)`
+///
+/// This form generates initializer, getter, setter and un-setter functions for an optional boxed, or shared, field.
+///
+/// * Because unset is present, the `optional` keyword is used along with the keywords
+///   `boxed` and `shared`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # pub struct Handler { path: String }
+/// # #[derive(Clone)] pub struct Config { port: u16 }
+/// # pub struct Server { handler: Option<Box<Handler>>, config: Option<Arc<Config>> }
+/// use jemmy::{unset, with_get_and_set};
+/// impl Server {
+///     // with_get_set_and_unset!(pub handler => boxed Handler);
+///     // with_get_set_and_unset!(pub config => shared Arc<Config>);
+///
+///     with_get_and_set!(pub handler => optional boxed Handler);
+///     unset!(pub handler);
+///     with_get_and_set!(pub config => optional shared Arc<Config>);
+///     unset!(pub config);
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! with_get_set_and_unset {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $fn_name => $field_name, optional $value_type);
+        $crate::unset!($fn_vis $fn_name => $field_name);
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::with_get_set_and_unset!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *deref*: `viz name => field_name, deref Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, deref $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $fn_name => $field_name, optional deref $value_type);
+        $crate::unset!($fn_vis $fn_name => $field_name);
+    };
+    // Case (2) without *field name*: `viz name => deref Type`
+    ($fn_vis:vis $name:ident => deref $value_type:ty) => {
+        $crate::with_get_set_and_unset!($fn_vis $name => $name, deref $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (3) Base case with *as_ref*: `viz name => field_name, as_ref Target, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, as_ref $target_type:ty, $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $fn_name => $field_name, optional as_ref $target_type, $value_type);
+        $crate::unset!($fn_vis $fn_name => $field_name);
+    };
+    // Case (3) without *field name*: `viz name => as_ref Target, Type`
+    ($fn_vis:vis $name:ident => as_ref $target_type:ty, $value_type:ty) => {
+        $crate::with_get_set_and_unset!($fn_vis $name => $name, as_ref $target_type, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (4) Base case with *clone*: `viz name => field_name, clone Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, clone $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $fn_name => $field_name, optional clone $value_type);
+        $crate::unset!($fn_vis $fn_name => $field_name);
+    };
+    // Case (4) without *field name*: `viz name => clone Type`
+    ($fn_vis:vis $name:ident => clone $value_type:ty) => {
+        $crate::with_get_set_and_unset!($fn_vis $name => $name, clone $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (5) Base case with *boxed*: `viz name => field_name, boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, boxed $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $fn_name => $field_name, optional boxed $value_type);
+        $crate::unset!($fn_vis $fn_name => $field_name);
+    };
+    // Case (5) without *field name*: `viz name => boxed Type`
+    ($fn_vis:vis $name:ident => boxed $value_type:ty) => {
+        $crate::with_get_set_and_unset!($fn_vis $name => $name, boxed $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (6) Base case with *shared*: `viz name => field_name, shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, shared $value_type:ty) => {
+        $crate::with_get_and_set!($fn_vis $fn_name => $field_name, optional shared $value_type);
+        $crate::unset!($fn_vis $fn_name => $field_name);
+    };
+    // Case (6) without *field name*: `viz name => shared Type`
+    ($fn_vis:vis $name:ident => shared $value_type:ty) => {
+        $crate::with_get_set_and_unset!($fn_vis $name => $name, shared $value_type);
+    };
 }
 
 // ------------------------------------------------------------------------------------------------
-// Combinator Macros ❱ Get, Set, and Unset
+// Combinator Macros ❱ Vector Accessors
 // ------------------------------------------------------------------------------------------------
 
 ///
-/// Generate [`get`], [`set`], and [`unset`] for an optional struct field.
+/// Generate the read-only vector accessors [`vec_length`], [`vec_is_empty`], [`vec_iter`],
+/// [`vec_get`], and [`vec_contains`] for a `Vec<T>` struct field.
 ///
 /// ## Forms
 ///
-/// ### `get_set_and_unset!(viz name => Type)`
+/// ### `vec_accessors!(viz name => Type)`
 ///
-/// This form generates simple getter, setter and un-setter functions using the [`get`],
-/// [`set`] and [`unset`] macros.
+/// This form generates read-only accessor functions using the vector macros.
 ///
 /// * In this form `name` is used in both the naming of the generated functions and the
 ///   name of as the structure's field.
-/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+/// * `Type` is the type of the vector's elements.
 ///
 /// The following — commented line and following implementation — are therefore equivalent:
 ///
 /// ```rust
 /// use jemmy::*;
-/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// # pub struct Address { street_lines: Vec<String> }
 /// impl Address {
-///     // get_set_and_unset!(pub street_2 => String);
+///     // vec_accessors!(pub street_lines => String);
 ///
-///     get_and_set!(pub street_2 => optional String);
-///     unset!(pub street_2);
+///     vec_length!(pub street_lines);
+///     vec_is_empty!(pub street_lines);
+///     vec_iter!(pub street_lines => String);
+///     vec_get!(pub street_lines => String);
+///     vec_contains!(pub street_lines => String);
 /// }
 /// ```
 ///
-/// ### `get_set_and_unset!(viz name => field_name, Type)`
+/// ### `vec_accessors!(viz name => field_name, Type)`
 ///
-/// This form generates simple getter, setter and un-setter functions using the [`get`],
-/// [`set`] and [`unset`] macros.
+/// This form generates read-only accessor functions using the vector macros.
 ///
 /// * In this form `name` is used in naming the generated functions while `field_name` is the
 ///   name of the structure's field.
-/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+/// * `Type` is the type of the vector's elements.
 ///
 /// The following — commented line and following implementation — are therefore equivalent:
 ///
 /// ```rust
 /// use jemmy::*;
-/// # pub struct Address { number_on_street: u32, street_1: String, street_2_string: Option<String> }
+/// # pub struct Address { lines: Vec<String> }
 /// impl Address {
-///     // get_set_and_unset!(pub street_2 => street_2_string, String);
+///     // vec_accessors!(pub street_lines => lines, String);
 ///
-///     get_and_set!(pub street_2 => street_2_string, optional String);
-///     unset!(pub street_2 => street_2_string);
+///     vec_length!(pub street_lines => lines);
+///     vec_is_empty!(pub street_lines => lines);
+///     vec_iter!(pub street_lines => lines, String);
+///     vec_get!(pub street_lines => lines, String);
+///     vec_contains!(pub street_lines => lines, String);
+/// }
+/// ```
+///
+/// ### `vec_accessors!(viz name => [field_name,] copy Type)`
+///
+/// This form generates read-only accessor functions using the vector macros.
+///
+/// * The keyword `copy` is passed to the [`vec_iter`] and [`vec_get`] macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { unit_numbers: Vec<u32> }
+/// impl Address {
+///     // vec_accessors!(pub unit_numbers => copy u32);
+///
+///     vec_length!(pub unit_numbers);
+///     vec_is_empty!(pub unit_numbers);
+///     vec_iter!(pub unit_numbers => copy u32);
+///     vec_get!(pub unit_numbers => copy u32);
+///     vec_contains!(pub unit_numbers => u32);
 /// }
 /// ```
 ///
 #[macro_export]
-macro_rules! get_set_and_unset {
+macro_rules! vec_accessors {
     // Base case: `viz name => field_name, Type`
     ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
-        $crate::get_and_set!($fn_vis $fn_name => $field_name, optional $value_type);
-        $crate::unset!($fn_vis $fn_name => $field_name);
+        $crate::vec_length!($fn_vis $fn_name => $field_name);
+        $crate::vec_is_empty!($fn_vis $fn_name => $field_name);
+        $crate::vec_iter!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_get!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_contains!($fn_vis $fn_name => $field_name, $value_type);
     };
     // Base case without *field name*: `viz name => Type`
     ($fn_vis:vis $name:ident => $value_type:ty) => {
-        $crate::get_set_and_unset!($fn_vis $name => $name, $value_type);
+        $crate::vec_accessors!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *copy*: `viz name => field_name, copy Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty) => {
+        $crate::vec_length!($fn_vis $fn_name => $field_name);
+        $crate::vec_is_empty!($fn_vis $fn_name => $field_name);
+        $crate::vec_iter!($fn_vis $fn_name => $field_name, copy $value_type);
+        $crate::vec_get!($fn_vis $fn_name => $field_name, copy $value_type);
+        $crate::vec_contains!($fn_vis $fn_name => $field_name, $value_type);
+    };
+    // Case (2) without *field name*: `viz name => copy Type`
+    ($fn_vis:vis $name:ident => copy $value_type:ty) => {
+        $crate::vec_accessors!($fn_vis $name => $name, copy $value_type);
     };
 }
 
 // ------------------------------------------------------------------------------------------------
-// Combinator Macros ❱ With, Get, Set, and Unset
+// Combinator Macros ❱ Vector Accessors, Mutable
 // ------------------------------------------------------------------------------------------------
 
 ///
-/// Generate [`with`], [`get`], [`set`], and [`unset`] for an optional struct field.
+/// Generate [`vec_accessors`] as well as the mutating vector accessors [`vec_push`],
+/// [`vec_pop`], [`vec_remove`], [`vec_extend`], [`vec_clear`], and [`vec_iter_mut`] for a
+/// `Vec<T>` struct field.
 ///
 /// ## Forms
 ///
-/// ### `with_get_set_and_unset!(viz name => Type)`
+/// ### `vec_accessors_mut!(viz name => Type)`
 ///
-/// This form generates simple initializer, getter, setter and un-setter functions using the
-/// [`with`], [`get`], [`set`] and [`unset`] macros.
+/// This form generates read and write accessor functions using the vector macros.
 ///
 /// * In this form `name` is used in both the naming of the generated functions and the
 ///   name of as the structure's field.
-/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+/// * `Type` is the type of the vector's elements.
 ///
 /// The following — commented line and following implementation — are therefore equivalent:
 ///
 /// ```rust
 /// use jemmy::*;
-/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// # pub struct Address { street_lines: Vec<String> }
 /// impl Address {
-///     // with_get_set_and_unset!(pub street_2 => String);
-///
-///     with_get_and_set!(pub street_2 => optional String);
-///     unset!(pub street_2);
+///     // vec_accessors_mut!(pub street_lines => String);
+///
+///     vec_accessors!(pub street_lines => String);
+///     vec_push!(pub street_lines => String);
+///     vec_pop!(pub street_lines => String);
+///     vec_remove!(pub street_lines => String);
+///     vec_extend!(pub street_lines => String);
+///     vec_clear!(pub street_lines);
+///     vec_iter_mut!(pub street_lines => String);
 /// }
 /// ```
 ///
-/// ### `with_get_set_and_unset!(viz name => field_name, Type)`
+/// ### `vec_accessors_mut!(viz name => field_name, Type)`
 ///
-/// This form generates simple initializer, getter, setter and un-setter functions using the
-/// [`with`], [`get`], [`set`] and [`unset`] macros.
+/// This form generates read and write accessor functions using the vector macros.
 ///
 /// * In this form `name` is used in naming the generated functions while `field_name` is the
 ///   name of the structure's field.
-/// * Because unset is present, the `optional` keyword is used on the get and set macros.
+/// * `Type` is the type of the vector's elements.
 ///
 /// The following — commented line and following implementation — are therefore equivalent:
 ///
 /// ```rust
 /// use jemmy::*;
-/// # pub struct Address { number_on_street: u32, street_1: String, street_2_string: Option<String> }
+/// # pub struct Address { lines: Vec<String> }
 /// impl Address {
-///     // with_get_set_and_unset!(pub street_2 => street_2_string, String);
+///     // vec_accessors_mut!(pub street_lines => lines, String);
+///
+///     vec_accessors!(pub street_lines => lines, String);
+///     vec_push!(pub street_lines => lines, String);
+///     vec_pop!(pub street_lines => lines, String);
+///     vec_remove!(pub street_lines => lines, String);
+///     vec_extend!(pub street_lines => lines, String);
+///     vec_clear!(pub street_lines => lines);
+///     vec_iter_mut!(pub street_lines => lines, String);
+/// }
+/// ```
 ///
-///     with_get_and_set!(pub street_2 => street_2_string, optional String);
-///     unset!(pub street_2 => street_2_string);
+/// ### `vec_accessors_mut!(viz name => [field_name,] copy Type)`
+///
+/// This form generates read and write accessor functions using the vector macros.
+///
+/// * The keyword `copy` is passed to the [`vec_accessors`] macro.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { unit_numbers: Vec<u32> }
+/// impl Address {
+///     // vec_accessors_mut!(pub unit_numbers => copy u32);
+///
+///     vec_accessors!(pub unit_numbers => copy u32);
+///     vec_push!(pub unit_numbers => u32);
+///     vec_pop!(pub unit_numbers => u32);
+///     vec_remove!(pub unit_numbers => u32);
+///     vec_extend!(pub unit_numbers => u32);
+///     vec_clear!(pub unit_numbers);
+///     vec_iter_mut!(pub unit_numbers => u32);
+/// }
+/// ```
+///
+/// ### `vec_accessors_mut!(viz name => [field_name,] into Type)`
+///
+/// This form generates read and write accessor functions using the vector macros.
+///
+/// * The keyword `into` is passed to the [`vec_push`] and [`vec_extend`] macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_accessors_mut!(pub street_lines => into String);
+///
+///     vec_accessors!(pub street_lines => String);
+///     vec_push!(pub street_lines => into String);
+///     vec_pop!(pub street_lines => String);
+///     vec_remove!(pub street_lines => String);
+///     vec_extend!(pub street_lines => into String);
+///     vec_clear!(pub street_lines);
+///     vec_iter_mut!(pub street_lines => String);
 /// }
 /// ```
 ///
 #[macro_export]
-macro_rules! with_get_set_and_unset {
+macro_rules! vec_accessors_mut {
     // Base case: `viz name => field_name, Type`
     ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
-        $crate::with_get_and_set!($fn_vis $fn_name => $field_name, optional $value_type);
-        $crate::unset!($fn_vis $fn_name => $field_name);
+        $crate::vec_accessors!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_push!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_pop!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_remove!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_extend!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_clear!($fn_vis $fn_name => $field_name);
+        $crate::vec_iter_mut!($fn_vis $fn_name => $field_name, $value_type);
     };
     // Base case without *field name*: `viz name => Type`
     ($fn_vis:vis $name:ident => $value_type:ty) => {
-        $crate::with_get_set_and_unset!($fn_vis $name => $name, $value_type);
+        $crate::vec_accessors_mut!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *copy*: `viz name => field_name, copy Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty) => {
+        $crate::vec_accessors!($fn_vis $fn_name => $field_name, copy $value_type);
+        $crate::vec_push!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_pop!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_remove!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_extend!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_clear!($fn_vis $fn_name => $field_name);
+        $crate::vec_iter_mut!($fn_vis $fn_name => $field_name, $value_type);
+    };
+    // Case (2) without *field name*: `viz name => copy Type`
+    ($fn_vis:vis $name:ident => copy $value_type:ty) => {
+        $crate::vec_accessors_mut!($fn_vis $name => $name, copy $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (3) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        $crate::vec_accessors!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_push!($fn_vis $fn_name => $field_name, into $value_type);
+        $crate::vec_pop!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_remove!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::vec_extend!($fn_vis $fn_name => $field_name, into $value_type);
+        $crate::vec_clear!($fn_vis $fn_name => $field_name);
+        $crate::vec_iter_mut!($fn_vis $fn_name => $field_name, $value_type);
+    };
+    // Case (3) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::vec_accessors_mut!($fn_vis $name => $name, into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Combinator Macros ❱ Flag
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate the flag accessors [`flag_is`], [`flag_set`], [`flag_enable`], [`flag_disable`],
+/// [`flag_toggle`], and [`flag_with`] for a `bool` struct field.
+///
+/// ## Forms
+///
+/// ### `flag!(viz name)`
+///
+/// This form generates predicate, setter, and initializer functions using the flag macros.
+///
+/// * In this form `name` is used in both the naming of the generated functions and the
+///   name of as the structure's field.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Account { active: bool }
+/// impl Account {
+///     // flag!(pub active);
+///
+///     flag_is!(pub active);
+///     flag_set!(pub active);
+///     flag_enable!(pub active);
+///     flag_disable!(pub active);
+///     flag_toggle!(pub active);
+///     flag_with!(pub active);
+/// }
+/// ```
+///
+/// ### `flag!(viz name => field_name)`
+///
+/// This form generates predicate, setter, and initializer functions using the flag macros.
+///
+/// * In this form `name` is used in naming the generated functions while `field_name` is the
+///   name of the structure's field.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # pub struct Account { is_active: bool }
+/// impl Account {
+///     // flag!(pub active => is_active);
+///
+///     flag_is!(pub active => is_active);
+///     flag_set!(pub active => is_active);
+///     flag_enable!(pub active => is_active);
+///     flag_disable!(pub active => is_active);
+///     flag_toggle!(pub active => is_active);
+///     flag_with!(pub active => is_active);
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! flag {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        $crate::flag_is!($fn_vis $fn_name => $field_name);
+        $crate::flag_set!($fn_vis $fn_name => $field_name);
+        $crate::flag_enable!($fn_vis $fn_name => $field_name);
+        $crate::flag_disable!($fn_vis $fn_name => $field_name);
+        $crate::flag_toggle!($fn_vis $fn_name => $field_name);
+        $crate::flag_with!($fn_vis $fn_name => $field_name);
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::flag!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Combinator Macros ❱ Mutex Accessors
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate the mutex accessors [`mutex_lock`], [`mutex_try_lock`], and [`mutex_with_locked`]
+/// for a `Mutex<T>` struct field.
+///
+/// ## Forms
+///
+/// ### `mutex_accessors!(viz name => [field_name,] [poison] Type)`
+///
+/// This form generates locking accessor functions using the lock macros.
+///
+/// * In this form `name` is used to name the generated functions, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * `Type` is the type of the mutex's content.
+/// * The keyword `poison` is passed to all of the lock macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # use std::sync::Mutex;
+/// # pub struct Inner { sessions: Mutex<Vec<u32>> }
+/// impl Inner {
+///     // mutex_accessors!(pub sessions => Vec<u32>);
+///
+///     mutex_lock!(pub sessions => Vec<u32>);
+///     mutex_try_lock!(pub sessions => Vec<u32>);
+///     mutex_with_locked!(pub sessions => Vec<u32>);
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! mutex_accessors {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        $crate::mutex_lock!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::mutex_try_lock!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::mutex_with_locked!($fn_vis $fn_name => $field_name, $value_type);
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::mutex_accessors!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *poison*: `viz name => field_name, poison Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, poison $value_type:ty) => {
+        $crate::mutex_lock!($fn_vis $fn_name => $field_name, poison $value_type);
+        $crate::mutex_try_lock!($fn_vis $fn_name => $field_name, poison $value_type);
+        $crate::mutex_with_locked!($fn_vis $fn_name => $field_name, poison $value_type);
+    };
+    // Case (2) without *field name*: `viz name => poison Type`
+    ($fn_vis:vis $name:ident => poison $value_type:ty) => {
+        $crate::mutex_accessors!($fn_vis $name => $name, poison $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Combinator Macros ❱ RwLock Accessors
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate the lock accessors [`rwlock_read`], [`rwlock_write`], [`rwlock_with_read`], and
+/// [`rwlock_with_write`] for a `RwLock<T>` struct field.
+///
+/// ## Forms
+///
+/// ### `rwlock_accessors!(viz name => [field_name,] [poison] Type)`
+///
+/// This form generates locking accessor functions using the lock macros.
+///
+/// * In this form `name` is used to name the generated functions, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * `Type` is the type of the lock's content.
+/// * The keyword `poison` is passed to all of the lock macros.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// use jemmy::*;
+/// # use std::sync::RwLock;
+/// # pub struct Inner { config: RwLock<String> }
+/// impl Inner {
+///     // rwlock_accessors!(pub config => poison String);
+///
+///     rwlock_read!(pub config => poison String);
+///     rwlock_write!(pub config => poison String);
+///     rwlock_with_read!(pub config => poison String);
+///     rwlock_with_write!(pub config => poison String);
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! rwlock_accessors {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        $crate::rwlock_read!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::rwlock_write!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::rwlock_with_read!($fn_vis $fn_name => $field_name, $value_type);
+        $crate::rwlock_with_write!($fn_vis $fn_name => $field_name, $value_type);
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::rwlock_accessors!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *poison*: `viz name => field_name, poison Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, poison $value_type:ty) => {
+        $crate::rwlock_read!($fn_vis $fn_name => $field_name, poison $value_type);
+        $crate::rwlock_write!($fn_vis $fn_name => $field_name, poison $value_type);
+        $crate::rwlock_with_read!($fn_vis $fn_name => $field_name, poison $value_type);
+        $crate::rwlock_with_write!($fn_vis $fn_name => $field_name, poison $value_type);
+    };
+    // Case (2) without *field name*: `viz name => poison Type`
+    ($fn_vis:vis $name:ident => poison $value_type:ty) => {
+        $crate::rwlock_accessors!($fn_vis $name => $name, poison $value_type);
     };
 }
 
@@ -537,4 +2288,7 @@ macro_rules! with_get_set_and_unset {
 // Re-export macros
 // ------------------------------------------------------------------------------------------------
 
-pub use crate::{get_and_set, get_set_and_unset, with_get_and_set, with_get_set_and_unset};
+pub use crate::{
+    flag, get_and_set, get_mut_set_and_unset, get_set_and_unset, mutex_accessors, rwlock_accessors,
+    vec_accessors, vec_accessors_mut, with_get_and_set, with_get_set_and_unset,
+};
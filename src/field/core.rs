@@ -1,6 +1,6 @@
 /*!
- * The core set of struct field access macros: [`get`], [`get_mut`], [`set`], [`unset`]
- * and [`with`].
+ * The core set of struct field access macros: [`get`], [`get_mut`], [`set`], [`unset`],
+ * [`with`], [`take`], [`replace`], [`swap`] and [`update`].
  */
 
 // ------------------------------------------------------------------------------------------------
@@ -196,6 +196,274 @@
 /// }
 /// ```
 ///
+/// ### `get!(viz name => [field_name,] deref Type)`
+///
+/// This form generates an immutable getter method for a field within a structure.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is the reference `&<Type as Deref>::Target`, so that
+///   `String` returns `&str`, `Vec<T>` returns `&[T]`, and `PathBuf` returns `&Path`.
+/// * Unlike the other forms the generated function is not `const` as `Deref::deref` is not.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_1: String, }
+/// impl Address {
+///     // get!(pub street_1 => deref String);
+///
+///     /// Returns a reference to the field `street_1` within this structure.
+///     /// The returned value is an immutable reference to the field's `Deref::Target`.
+///     pub fn street_1(&self) -> &str {
+///         &self.street_1
+///     }
+/// }
+/// ```
+///
+/// ### `get!(viz name => [field_name,] optional deref Type)`
+///
+/// This form generates an immutable getter method for a field within a structure.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is the reference `Option<&<Type as Deref>::Target>`.
+/// * Unlike the other forms the generated function is not `const` as `Option::as_deref` is not.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_2: Option<String>, }
+/// impl Address {
+///     // get!(pub street_2 => optional deref String);
+///
+///     /// Returns a reference to the optional field `street_2` within this structure.
+///     /// The returned value is an optional immutable reference to the field's `Deref::Target`.
+///     pub fn street_2(&self) -> Option<&str> {
+///         self.street_2.as_deref()
+///     }
+/// }
+/// ```
+///
+/// ### `get!(viz name => [field_name,] as_ref Target)`
+///
+/// This form generates an immutable getter method for a field within a structure.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * `Target` is *not* the type of the field, but the type borrowed from it; the field's type
+///   must implement `AsRef<Target>`.
+/// * The type of the generated function is the reference `&Target`.
+/// * Unlike the other forms the generated function is not `const` as `AsRef::as_ref` is not.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::path::{Path, PathBuf};
+/// # pub struct Address { map_file: PathBuf, }
+/// impl Address {
+///     // get!(pub map_file => as_ref Path);
+///
+///     /// Returns a reference to the field `map_file` within this structure.
+///     /// The returned value is an immutable reference to the field's `AsRef` target.
+///     pub fn map_file(&self) -> &Path {
+///         AsRef::<Path>::as_ref(&self.map_file)
+///     }
+/// }
+/// ```
+///
+/// ### `get!(viz name => [field_name,] optional as_ref Target)`
+///
+/// This form generates an immutable getter method for a field within a structure.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * `Target` is *not* the type of the field, but the type borrowed from the field's value;
+///   the value's type must implement `AsRef<Target>`.
+/// * The type of the generated function is the reference `Option<&Target>`.
+/// * Unlike the other forms the generated function is not `const` as `AsRef::as_ref` is not.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::ffi::OsStr;
+/// # use std::path::PathBuf;
+/// # pub struct Address { map_file: Option<PathBuf>, }
+/// impl Address {
+///     // get!(pub map_file => optional as_ref OsStr);
+///
+///     /// Returns a reference to the optional field `map_file` within this structure.
+///     /// The returned value is an optional immutable reference to the field's `AsRef` target.
+///     pub fn map_file(&self) -> Option<&OsStr> {
+///         self.map_file.as_ref().map(AsRef::<OsStr>::as_ref)
+///     }
+/// }
+/// ```
+///
+/// ### `get!(viz name => [field_name,] clone Type)`
+///
+/// This form generates an immutable getter method for a field within a structure.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is the value type `Type`, assuming `Type` implements
+///   `Clone`; this is useful for cheap clones such as `Rc<T>` or `Arc<T>`.
+/// * Unlike the other forms the generated function is not `const` as `Clone::clone` is not.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::rc::Rc;
+/// # pub struct Address { street_1: Rc<str>, }
+/// impl Address {
+///     // get!(pub street_1 => clone Rc<str>);
+///
+///     /// Returns the value of the field `street_1` within this structure.
+///     /// The returned value is an immutable clone of the field's value.
+///     pub fn street_1(&self) -> Rc<str> {
+///         self.street_1.clone()
+///     }
+/// }
+/// ```
+///
+/// ### `get!(viz name => [field_name,] optional clone Type)`
+///
+/// This form generates an immutable getter method for a field within a structure.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is the value type `Option<Type>`, assuming `Type`
+///   implements `Clone`.
+/// * Unlike the other forms the generated function is not `const` as `Clone::clone` is not.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_2: Option<String>, }
+/// impl Address {
+///     // get!(pub street_2 => optional clone String);
+///
+///     /// Returns the value of the optional field `street_2` within this structure.
+///     /// The returned value is an optional, immutable, clone of the field's value.
+///     pub fn street_2(&self) -> Option<String> {
+///         self.street_2.clone()
+///     }
+/// }
+/// ```
+///
+/// ### `get!(viz name => [field_name,] [optional] boxed Type)`
+///
+/// This form generates an immutable getter method for a boxed field within a structure.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * `Type` is *not* the type of the field, but the type of the boxed value; the field's type
+///   is `Box<Type>`, or `Option<Box<Type>>` with the keyword `optional`.
+/// * The type of the generated function is the reference `&Type`, or `Option<&Type>`.
+/// * Unlike the other forms the generated function is not `const` as `Deref::deref` is not.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Handler { path: String }
+/// # pub struct Server { handler: Box<Handler>, fallback: Option<Box<Handler>> }
+/// impl Server {
+///     // get!(pub handler => boxed Handler);
+///     // get!(pub fallback => optional boxed Handler);
+///
+///     /// Returns a reference to the boxed field `handler` within this structure.
+///     /// The returned value is an immutable reference to the boxed value.
+///     pub fn handler(&self) -> &Handler {
+///         &self.handler
+///     }
+///
+///     /// Returns a reference to the optional boxed field `fallback` within this structure.
+///     /// The returned value is an optional immutable reference to the boxed value.
+///     pub fn fallback(&self) -> Option<&Handler> {
+///         self.fallback.as_deref()
+///     }
+/// }
+/// ```
+///
+/// ### `get!(viz name => [field_name,] [optional] shared Type)`
+///
+/// This form generates immutable getter methods for a shared, reference-counted, field
+/// within a structure.
+///
+/// * In this form `name` is used to name the generated functions, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * `Type` is the type of the field, either `Rc<T>` or `Arc<T>`, or `Option<Type>` with
+///   the keyword `optional`.
+/// * The function `name` returns the reference `&T`, or `Option<&T>`.
+/// * The function `name_arc` returns a clone of the pointer `Type`, or `Option<Type>`,
+///   sharing the value rather than cloning it.
+/// * Unlike the other forms the generated functions are not `const` as `Deref::deref` and
+///   `Clone::clone` are not.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # pub struct Config { port: u16 }
+/// # pub struct Server { config: Arc<Config> }
+/// impl Server {
+///     // get!(pub config => shared Arc<Config>);
+///
+///     /// Returns a reference to the value of the shared field `config` within this structure.
+///     /// The returned value is an immutable reference to the shared value.
+///     pub fn config(&self) -> &Config {
+///         &self.config
+///     }
+///
+///     /// Returns a clone of the shared pointer in the field `config` within this structure.
+///     /// The returned value shares ownership of the value with the field.
+///     pub fn config_arc(&self) -> Arc<Config> {
+///         Arc::clone(&self.config)
+///     }
+/// }
+/// ```
+///
+/// ### `get!(viz name => [field_name,] cow 'a Type)`
+///
+/// This form generates an immutable getter method, and a consuming conversion method, for a
+/// clone-on-write field within a structure.
+///
+/// * In this form `name` is used to name the generated functions, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * `Type` is *not* the type of the field, but the borrowed type of the field's value; the
+///   field's type is `Cow<'a, Type>`, where `'a` is the lifetime given, for example
+///   `cow 'a str` or `cow 'a [u8]`.
+/// * The function `name` borrows through the `Cow`, returning the reference `&Type`
+///   whether the value is borrowed or owned.
+/// * The function `into_owned_name` consumes the structure, returning the owned value
+///   `<Type as ToOwned>::Owned`, cloning the value only if it is borrowed.
+/// * Unlike the other forms the generated functions are not `const` as `Deref::deref` and
+///   `Cow::into_owned` are not.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # pub struct Token<'a> { text: Cow<'a, str> }
+/// impl<'a> Token<'a> {
+///     // get!(pub text => cow 'a str);
+///
+///     /// Returns a reference to the value of the clone-on-write field `text` within this
+///     /// structure. The returned value is an immutable reference to the borrowed or owned
+///     /// value.
+///     pub fn text(&self) -> &str {
+///         &self.text
+///     }
+///
+///     /// Consumes this structure, returning the owned value of the clone-on-write field
+///     /// `text`. The returned value is the `ToOwned::Owned` form of the value, cloned only if
+///     /// it is borrowed.
+///     pub fn into_owned_text(self) -> String {
+///         self.text.into_owned()
+///     }
+/// }
+/// ```
+///
 #[macro_export]
 macro_rules! get {
     // Base case: `viz name => field_name, Type`
@@ -254,6 +522,180 @@ macro_rules! get {
     ($fn_vis:vis $name:ident => optional copy $value_type:ty) => {
         $crate::get!($fn_vis $name => $name, optional copy $value_type);
     };
+    // (5) Base case with *deref*: `viz name => field_name, deref Type`
+    // Note: the documentation for the *deref*, *as_ref*, and *clone* forms does not name the
+    // type, as `paste!` cannot include generic types such as `Vec<T>`, or trait objects.
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, deref $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a reference to the field `" $fn_name "` within this structure. "
+                    "The returned value is an immutable reference to the field's `Deref::Target`"]
+            $fn_vis fn $fn_name(&self) -> &<$value_type as ::core::ops::Deref>::Target {
+                ::core::ops::Deref::deref(&self.$field_name)
+            }
+        }
+    };
+    // Case (5) without *field name*: `viz name => deref Type`
+    ($fn_vis:vis $name:ident => deref $value_type:ty) => {
+        $crate::get!($fn_vis $name => $name, deref $value_type);
+    };
+    // (6) Case (3) with *deref*: `viz name => field_name, optional deref Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional deref $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a reference to the optional field `" $fn_name "` within this structure. "
+                    "The returned value is an optional immutable reference to the field's `Deref::Target`"]
+            $fn_vis fn $fn_name(&self) -> Option<&<$value_type as ::core::ops::Deref>::Target> {
+                self.$field_name.as_deref()
+            }
+        }
+    };
+    // Case (6) without *field name*: `viz name => optional deref Type`
+    ($fn_vis:vis $name:ident => optional deref $value_type:ty) => {
+        $crate::get!($fn_vis $name => $name, optional deref $value_type);
+    };
+    // (7) Base case with *as_ref*: `viz name => field_name, as_ref Target`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, as_ref $target_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a reference to the field `" $fn_name "` within this structure. "
+                    "The returned value is an immutable reference to the field's `AsRef` target"]
+            $fn_vis fn $fn_name(&self) -> &$target_type {
+                ::core::convert::AsRef::<$target_type>::as_ref(&self.$field_name)
+            }
+        }
+    };
+    // Case (7) without *field name*: `viz name => as_ref Target`
+    ($fn_vis:vis $name:ident => as_ref $target_type:ty) => {
+        $crate::get!($fn_vis $name => $name, as_ref $target_type);
+    };
+    // (8) Case (3) with *as_ref*: `viz name => field_name, optional as_ref Target`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional as_ref $target_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a reference to the optional field `" $fn_name "` within this structure. "
+                    "The returned value is an optional immutable reference to the field's `AsRef` target"]
+            $fn_vis fn $fn_name(&self) -> Option<&$target_type> {
+                self.$field_name.as_ref().map(::core::convert::AsRef::<$target_type>::as_ref)
+            }
+        }
+    };
+    // Case (8) without *field name*: `viz name => optional as_ref Target`
+    ($fn_vis:vis $name:ident => optional as_ref $target_type:ty) => {
+        $crate::get!($fn_vis $name => $name, optional as_ref $target_type);
+    };
+    // (9) Base case with *clone*: `viz name => field_name, clone Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, clone $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns the value of the field `" $fn_name "` within this structure. "
+                    "The returned value is an immutable clone of the field's value"]
+            $fn_vis fn $fn_name(&self) -> $value_type {
+                ::core::clone::Clone::clone(&self.$field_name)
+            }
+        }
+    };
+    // Case (9) without *field name*: `viz name => clone Type`
+    ($fn_vis:vis $name:ident => clone $value_type:ty) => {
+        $crate::get!($fn_vis $name => $name, clone $value_type);
+    };
+    // (10) Case (3) with *clone*: `viz name => field_name, optional clone Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional clone $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns the value of the optional field `" $fn_name "` within this structure. "
+                    "The returned value is an optional, immutable, clone of the field's value"]
+            $fn_vis fn $fn_name(&self) -> Option<$value_type> {
+                ::core::clone::Clone::clone(&self.$field_name)
+            }
+        }
+    };
+    // Case (10) without *field name*: `viz name => optional clone Type`
+    ($fn_vis:vis $name:ident => optional clone $value_type:ty) => {
+        $crate::get!($fn_vis $name => $name, optional clone $value_type);
+    };
+    // (11) Base case with *boxed*: `viz name => field_name, boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, boxed $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a reference to the boxed field `" $fn_name "` within this structure. "
+                    "The returned value is an immutable reference to the boxed value"]
+            $fn_vis fn $fn_name(&self) -> &$value_type {
+                &self.$field_name
+            }
+        }
+    };
+    // Case (11) without *field name*: `viz name => boxed Type`
+    ($fn_vis:vis $name:ident => boxed $value_type:ty) => {
+        $crate::get!($fn_vis $name => $name, boxed $value_type);
+    };
+    // (12) Case (3) with *boxed*: `viz name => field_name, optional boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional boxed $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a reference to the optional boxed field `" $fn_name "` within this structure. "
+                    "The returned value is an optional immutable reference to the boxed value"]
+            $fn_vis fn $fn_name(&self) -> Option<&$value_type> {
+                self.$field_name.as_deref()
+            }
+        }
+    };
+    // Case (12) without *field name*: `viz name => optional boxed Type`
+    ($fn_vis:vis $name:ident => optional boxed $value_type:ty) => {
+        $crate::get!($fn_vis $name => $name, optional boxed $value_type);
+    };
+    // (13) Base case with *shared*: `viz name => field_name, shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, shared $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a reference to the value of the shared field `" $fn_name "` within this structure. "
+                    "The returned value is an immutable reference to the shared value"]
+            $fn_vis fn $fn_name(&self) -> &<$value_type as ::core::ops::Deref>::Target {
+                ::core::ops::Deref::deref(&self.$field_name)
+            }
+
+            #[doc = "Returns a clone of the shared pointer in the field `" $fn_name "` within this structure. "
+                    "The returned value shares ownership of the value with the field"]
+            $fn_vis fn [<$fn_name _arc>](&self) -> $value_type {
+                ::core::clone::Clone::clone(&self.$field_name)
+            }
+        }
+    };
+    // Case (13) without *field name*: `viz name => shared Type`
+    ($fn_vis:vis $name:ident => shared $value_type:ty) => {
+        $crate::get!($fn_vis $name => $name, shared $value_type);
+    };
+    // (14) Case (3) with *shared*: `viz name => field_name, optional shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional shared $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a reference to the value of the optional shared field `" $fn_name "` within this structure. "
+                    "The returned value is an optional immutable reference to the shared value"]
+            $fn_vis fn $fn_name(&self) -> Option<&<$value_type as ::core::ops::Deref>::Target> {
+                self.$field_name.as_deref()
+            }
+
+            #[doc = "Returns a clone of the shared pointer in the optional field `" $fn_name "` within this structure. "
+                    "The returned value, if any, shares ownership of the value with the field"]
+            $fn_vis fn [<$fn_name _arc>](&self) -> Option<$value_type> {
+                ::core::clone::Clone::clone(&self.$field_name)
+            }
+        }
+    };
+    // Case (14) without *field name*: `viz name => optional shared Type`
+    ($fn_vis:vis $name:ident => optional shared $value_type:ty) => {
+        $crate::get!($fn_vis $name => $name, optional shared $value_type);
+    };
+    // (15) Base case with *cow*: `viz name => field_name, cow 'a Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, cow $lifetime:lifetime $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a reference to the value of the clone-on-write field `" $fn_name "` within this structure. "
+                    "The returned value is an immutable reference to the borrowed or owned value"]
+            $fn_vis fn $fn_name(&self) -> &$value_type {
+                ::core::ops::Deref::deref(&self.$field_name)
+            }
+
+            #[doc = "Consumes this structure, returning the owned value of the clone-on-write field `" $fn_name "`. "
+                    "The returned value is the `ToOwned::Owned` form of the value, cloned only if it is borrowed"]
+            $fn_vis fn [<into_owned_ $fn_name>](self) -> <$value_type as ::std::borrow::ToOwned>::Owned {
+                self.$field_name.into_owned()
+            }
+        }
+    };
+    // Case (15) without *field name*: `viz name => cow 'a Type`
+    ($fn_vis:vis $name:ident => cow $lifetime:lifetime $value_type:ty) => {
+        $crate::get!($fn_vis $name => $name, cow $lifetime $value_type);
+    };
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -314,103 +756,359 @@ macro_rules! get {
 /// }
 /// ```
 ///
+/// `get_mut!(viz name => [field_name,] optional Type)`
 ///
-#[macro_export]
-macro_rules! get_mut {
-    // Base case: `viz name => field_name, Type`
-    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
-        paste::paste! {
-            #[doc = "Returns a *mutable* reference to the field `" $fn_name "` within this structure. "
-                    "The returned value is a mutable reference `&mut " $value_type "`."]
-            $fn_vis const fn [< $fn_name _mut >](&mut self) -> &mut $value_type {
-                &mut self.$field_name
-            }
-        }
-    };
-    // Case (2) with *field name*: `viz name => Type`
-    ($fn_vis:vis $name:ident => $value_type:ty) => {
-        $crate::get_mut!($fn_vis $name => $name, $value_type);
-    };
-}
-
-// ------------------------------------------------------------------------------------------------
-// Accessor Macros ❱ Set
-// ------------------------------------------------------------------------------------------------
-
-///
-/// Generate a setter method for a field within a structure.
-///
-/// ## Forms
-///
-/// ### `set!(viz name => Type)`
-///
-/// This form generates a simple setter function.
+/// This form generates a mutable getter method for an optional field within a structure.
 ///
-/// * In this form `name` is both the name of the generated function and the name of the
-///   structure's field.
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
 /// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
-/// * The type of the new value parameter is the value type `Type`.
-/// * This function returns no value.
+/// * The type of the generated function is the reference `Option<&mut Type>`.
 ///
 /// The following — commented line and following implementation — are therefore equivalent:
 ///
 /// ```rust
 /// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
 /// impl Address {
-///     // set!(pub number_on_street => u32);
+///     // get_mut!(pub street_2 => optional String);
 ///
-///     /// Set the value of the field `number_on_street` within this structure.
-///     pub fn number_on_street(&mut self, number_on_street: u32) {
-///         self.number_on_street = number_on_street;
+///     /// Returns a *mutable* reference to the optional field `street_2` within this structure.
+///     /// The returned value is an optional mutable reference `Option<&mut String>`.
+///     pub const fn street_2_mut(&mut self) -> Option<&mut String> {
+///         self.street_2.as_mut()
 ///     }
 /// }
 /// ```
 ///
-/// ### `set!(viz setter_name => field_name, Type)`
+/// `get_mut!(viz name => [field_name,] optional or_default Type)`
 ///
-/// This form generates a simple setter function.
+/// This form generates a mutable getter method for an optional field within a structure,
+/// setting the field to the default value of `Type` if it has no value.
 ///
-/// * In this form `name` is the name of the generated function while `field_name` is the
-///   name of the structure's field.
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
 /// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
-/// * The type of the new value parameter is the value type `Type`.
-/// * This function returns no value.
-//
+/// * The type of the generated function is the reference `&mut Type`, assuming `Type`
+///   implements `Default`.
+/// * Unlike the other forms the generated function is not `const` as `Default::default` is not.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
 /// ```rust
-/// # pub struct Address { number: u32, street_1: String, street_2: Option<String> }
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
 /// impl Address {
-///     // set!(pub number_on_street => number, u32);
+///     // get_mut!(pub street_2 => optional or_default String);
 ///
-///     /// Set the value of the field `number_on_street` within this structure.
-///     pub fn number_on_street(&mut self, number_on_street: u32) {
-///         self.number = number_on_street;
+///     /// Returns a *mutable* reference to the optional field `street_2` within this structure,
+///     /// first setting it to the default value if it has no value.
+///     /// The returned value is a mutable reference `&mut String`.
+///     pub fn street_2_mut(&mut self) -> &mut String {
+///         self.street_2.get_or_insert_with(Default::default)
 ///     }
 /// }
 /// ```
 ///
-/// The following — commented line and following implementation — are therefore equivalent:
-///
-/// ### `set!(viz name => into Type)`
+/// `get_mut!(viz name => [field_name,] [optional] boxed Type)`
 ///
-/// This form generates a simple setter function.
+/// This form generates a mutable getter method for a boxed field within a structure.
 ///
-/// * In this form `name` is both the name of the generated function and the name of the
-///   structure's field.
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
 /// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
-/// * The type of the new value parameter is the trait-bound type `T: Into<Type>` rather
-///   than `Type` for flexibility.
-/// * This function returns no value.
+/// * `Type` is *not* the type of the field, but the type of the boxed value; the field's type
+///   is `Box<Type>`, or `Option<Box<Type>>` with the keyword `optional`.
+/// * The type of the generated function is the reference `&mut Type`, or `Option<&mut Type>`.
+/// * Unlike the other forms the generated function is not `const` as `DerefMut::deref_mut`
+///   is not.
 ///
 /// The following — commented line and following implementation — are therefore equivalent:
 ///
 /// ```rust
-/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
-/// impl Address {
-///     // set!(pub street_1 => into String);
-///
-///     /// Set the value of the field `street_1` within this structure.
-///     pub fn street_1<T: Into<String>>(&mut self, street_1: T) {
-///         self.street_1 = street_1.into();
+/// # pub struct Handler { path: String }
+/// # pub struct Server { handler: Box<Handler> }
+/// impl Server {
+///     // get_mut!(pub handler => boxed Handler);
+///
+///     /// Returns a *mutable* reference to the boxed field `handler` within this structure.
+///     /// The returned value is a mutable reference to the boxed value.
+///     pub fn handler_mut(&mut self) -> &mut Handler {
+///         &mut self.handler
+///     }
+/// }
+/// ```
+///
+/// `get_mut!(viz name => [field_name,] [optional] shared Type)`
+///
+/// This form generates a *copy-on-write* mutable getter method for a shared,
+/// reference-counted, field within a structure.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * `Type` is the type of the field, either `Rc<T>` or `Arc<T>`, or `Option<Type>` with
+///   the keyword `optional`.
+/// * The generated function is named `name_make_mut` rather than `name_mut`; if the value is
+///   shared with other pointers it is first cloned, so that changes are not visible through
+///   those other pointers. This assumes `T` implements `Clone`.
+/// * The type of the generated function is the reference `&mut T`, or `Option<&mut T>`.
+/// * Unlike the other forms the generated function is not `const` as `Arc::make_mut` is not.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # #[derive(Clone)] pub struct Config { port: u16 }
+/// # pub struct Server { config: Arc<Config> }
+/// impl Server {
+///     // get_mut!(pub config => shared Arc<Config>);
+///
+///     /// Returns a *mutable* reference to the value of the shared field `config` within this
+///     /// structure, first cloning the value if it is shared with other pointers.
+///     /// The returned value is a mutable reference to the shared value.
+///     pub fn config_make_mut(&mut self) -> &mut Config {
+///         Arc::make_mut(&mut self.config)
+///     }
+/// }
+/// ```
+///
+/// `get_mut!(viz name => [field_name,] cow 'a Type)`
+///
+/// This form generates a *clone-on-write* mutable getter method for a field within a
+/// structure.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * `Type` is *not* the type of the field, but the borrowed type of the field's value; the
+///   field's type is `Cow<'a, Type>`, where `'a` is the lifetime given, for example
+///   `cow 'a str` or `cow 'a [u8]`.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The generated function is named `name_to_mut` rather than `name_mut`; if the value is
+///   borrowed it is first cloned into an owned value, using `Cow::to_mut`.
+/// * The type of the generated function is the reference `&mut <Type as ToOwned>::Owned`.
+/// * Unlike the other forms the generated function is not `const` as `Cow::to_mut` is not.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # pub struct Token<'a> { text: Cow<'a, str> }
+/// impl<'a> Token<'a> {
+///     // get_mut!(pub text => cow 'a str);
+///
+///     /// Returns a *mutable* reference to the owned value of the clone-on-write field `text`
+///     /// within this structure, first cloning the value if it is borrowed.
+///     /// The returned value is a mutable reference to the `ToOwned::Owned` form of the value.
+///     pub fn text_to_mut(&mut self) -> &mut String {
+///         self.text.to_mut()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! get_mut {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a *mutable* reference to the field `" $fn_name "` within this structure. "
+                    "The returned value is a mutable reference `&mut " $value_type "`."]
+            $fn_vis const fn [< $fn_name _mut >](&mut self) -> &mut $value_type {
+                &mut self.$field_name
+            }
+        }
+    };
+    // Case (2) with *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::get_mut!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (3) Base case with *optional*: `viz name => field_name, optional Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a *mutable* reference to the optional field `" $fn_name "` within this structure. "
+                    "The returned value is an optional mutable reference `Option<&mut " $value_type ">`."]
+            $fn_vis const fn [< $fn_name _mut >](&mut self) -> Option<&mut $value_type> {
+                self.$field_name.as_mut()
+            }
+        }
+    };
+    // Case (3) without *field name*: `viz name => optional Type`
+    ($fn_vis:vis $name:ident => optional $value_type:ty) => {
+        $crate::get_mut!($fn_vis $name => $name, optional $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (4) Case (3) with *or_default*: `viz name => field_name, optional or_default Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional or_default $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a *mutable* reference to the optional field `" $fn_name "` within this structure, "
+                    "first setting it to the default value if it has no value. "
+                    "The returned value is a mutable reference `&mut " $value_type "`."]
+            $fn_vis fn [< $fn_name _mut >](&mut self) -> &mut $value_type {
+                self.$field_name.get_or_insert_with(::core::default::Default::default)
+            }
+        }
+    };
+    // Case (4) without *field name*: `viz name => optional or_default Type`
+    ($fn_vis:vis $name:ident => optional or_default $value_type:ty) => {
+        $crate::get_mut!($fn_vis $name => $name, optional or_default $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (5) Base case with *boxed*: `viz name => field_name, boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, boxed $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a *mutable* reference to the boxed field `" $fn_name "` within this structure. "
+                    "The returned value is a mutable reference to the boxed value."]
+            $fn_vis fn [< $fn_name _mut >](&mut self) -> &mut $value_type {
+                &mut self.$field_name
+            }
+        }
+    };
+    // Case (5) without *field name*: `viz name => boxed Type`
+    ($fn_vis:vis $name:ident => boxed $value_type:ty) => {
+        $crate::get_mut!($fn_vis $name => $name, boxed $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (6) Case (3) with *boxed*: `viz name => field_name, optional boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional boxed $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a *mutable* reference to the optional boxed field `" $fn_name "` within this structure. "
+                    "The returned value is an optional mutable reference to the boxed value."]
+            $fn_vis fn [< $fn_name _mut >](&mut self) -> Option<&mut $value_type> {
+                self.$field_name.as_deref_mut()
+            }
+        }
+    };
+    // Case (6) without *field name*: `viz name => optional boxed Type`
+    ($fn_vis:vis $name:ident => optional boxed $value_type:ty) => {
+        $crate::get_mut!($fn_vis $name => $name, optional boxed $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (7) Base case with *shared*: `viz name => field_name, shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, shared $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a *mutable* reference to the value of the shared field `" $fn_name "` within this structure, "
+                    "first cloning the value if it is shared with other pointers. "
+                    "The returned value is a mutable reference to the shared value."]
+            $fn_vis fn [< $fn_name _make_mut >](&mut self) -> &mut <$value_type as ::core::ops::Deref>::Target {
+                $crate::field::pointer::SharedPointer::make_mut(&mut self.$field_name)
+            }
+        }
+    };
+    // Case (7) without *field name*: `viz name => shared Type`
+    ($fn_vis:vis $name:ident => shared $value_type:ty) => {
+        $crate::get_mut!($fn_vis $name => $name, shared $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (8) Case (3) with *shared*: `viz name => field_name, optional shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional shared $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a *mutable* reference to the value of the optional shared field `" $fn_name "` within this structure, "
+                    "first cloning the value if it is shared with other pointers. "
+                    "The returned value is an optional mutable reference to the shared value."]
+            $fn_vis fn [< $fn_name _make_mut >](&mut self) -> Option<&mut <$value_type as ::core::ops::Deref>::Target> {
+                self.$field_name.as_mut().map($crate::field::pointer::SharedPointer::make_mut)
+            }
+        }
+    };
+    // Case (8) without *field name*: `viz name => optional shared Type`
+    ($fn_vis:vis $name:ident => optional shared $value_type:ty) => {
+        $crate::get_mut!($fn_vis $name => $name, optional shared $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (9) Base case with *cow*: `viz name => field_name, cow 'a Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, cow $lifetime:lifetime $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a *mutable* reference to the owned value of the clone-on-write field `" $fn_name "` within this structure, "
+                    "first cloning the value if it is borrowed. "
+                    "The returned value is a mutable reference to the `ToOwned::Owned` form of the value."]
+            $fn_vis fn [< $fn_name _to_mut >](&mut self) -> &mut <$value_type as ::std::borrow::ToOwned>::Owned {
+                self.$field_name.to_mut()
+            }
+        }
+    };
+    // Case (9) without *field name*: `viz name => cow 'a Type`
+    ($fn_vis:vis $name:ident => cow $lifetime:lifetime $value_type:ty) => {
+        $crate::get_mut!($fn_vis $name => $name, cow $lifetime $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Set
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a setter method for a field within a structure.
+///
+/// ## Forms
+///
+/// ### `set!(viz name => Type)`
+///
+/// This form generates a simple setter function.
+///
+/// * In this form `name` is both the name of the generated function and the name of the
+///   structure's field.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the new value parameter is the value type `Type`.
+/// * This function returns no value.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // set!(pub number_on_street => u32);
+///
+///     /// Set the value of the field `number_on_street` within this structure.
+///     pub fn number_on_street(&mut self, number_on_street: u32) {
+///         self.number_on_street = number_on_street;
+///     }
+/// }
+/// ```
+///
+/// ### `set!(viz setter_name => field_name, Type)`
+///
+/// This form generates a simple setter function.
+///
+/// * In this form `name` is the name of the generated function while `field_name` is the
+///   name of the structure's field.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the new value parameter is the value type `Type`.
+/// * This function returns no value.
+//
+/// ```rust
+/// # pub struct Address { number: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // set!(pub number_on_street => number, u32);
+///
+///     /// Set the value of the field `number_on_street` within this structure.
+///     pub fn number_on_street(&mut self, number_on_street: u32) {
+///         self.number = number_on_street;
+///     }
+/// }
+/// ```
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ### `set!(viz name => into Type)`
+///
+/// This form generates a simple setter function.
+///
+/// * In this form `name` is both the name of the generated function and the name of the
+///   structure's field.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the new value parameter is the trait-bound type `T: Into<Type>` rather
+///   than `Type` for flexibility.
+/// * This function returns no value.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // set!(pub street_1 => into String);
+///
+///     /// Set the value of the field `street_1` within this structure.
+///     pub fn street_1<T: Into<String>>(&mut self, street_1: T) {
+///         self.street_1 = street_1.into();
 ///     }
 /// }
 /// ```
@@ -552,405 +1250,2079 @@ macro_rules! get_mut {
 /// }
 /// ```
 ///
-#[macro_export]
-macro_rules! set {
-    // Base case: `viz name => field_name, Type`
-    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
-        paste::paste! {
-            #[doc = "Set the value of the field `" $fn_name "` within this structure."]
-            $fn_vis fn [<set_ $fn_name>](&mut self, value: $value_type) {
-                self.$field_name = value;
-            }
-        }
-    };
-    // Base Case without *field name*: `viz name => Type`
-    ($fn_vis:vis $name:ident => $value_type:ty) => {
-        $crate::set!($fn_vis $name => $name, $value_type);
-    };
-    // (2) Base case with *into*: `viz name => field_name, into Type`
-    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
-        paste::paste! {
-            #[doc = "Set the value of the field `" $fn_name "` within this structure (Using `Into<" $value_type ">`)."]
-            $fn_vis fn [<set_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) {
-                self.$field_name = value.into();
-            }
-        }
-    };
-    // Case (2) without *field name*: `viz name => into Type`
-    ($fn_vis:vis $name:ident => into $value_type:ty) => {
-        $crate::set!($fn_vis $name => $name, into $value_type);
-    };
-    // (3) Base case with *optional*: `viz name => field_name, optional Type`
-    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty) => {
-        paste::paste! {
-            #[doc = "Set the value of the field `" $fn_name "` within this structure. "
-                    "While the corresponding field is an `Option<" $value_type
-                    ">`, this function uses the wrapped type `" $value_type
-                    "`. To set the field value to `None` use the method [`unset_" $fn_name "`]."]
-            $fn_vis fn [<set_ $fn_name>](&mut self, value: $value_type) {
-                self.$field_name = Some(value);
-            }
-        }
-    };
-    // Case (3) with *field name*: `viz name => optional Type`
-    ($fn_vis:vis $name:ident => optional $value_type:ty) => {
-        $crate::set!($fn_vis $name => $name, optional $value_type);
-    };
-    // (4) Case (3) with *into*: `viz name => field_name, optional into Type`
-    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty) => {
-        paste::paste! {
-            #[doc = "Set the value of the field `" $fn_name "` within this structure. "
-                    "While the corresponding field is an `Option<" $value_type
-                    ">`, this function uses the type `Into<" $value_type
-                    ">`. To set the field value to `None` use the method [`unset_" $fn_name "`]."]
-            $fn_vis fn [<set_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) {
-                self.$field_name = Some(value.into());
-            }
-        }
-    };
-    // Case (4) without *field name*: `viz name => optional into Type`
-    ($fn_vis:vis $name:ident => optional into $value_type:ty) => {
-        $crate::set!($fn_vis $name => $name, optional into $value_type);
-    };
-}
-
-// ------------------------------------------------------------------------------------------------
-// Accessor Macros ❱ Unset
-// ------------------------------------------------------------------------------------------------
-
+/// ### `set!(viz name => [field_name,] [optional] boxed Type)`
 ///
-///  Generate an *un-setter* method for an optional struct field.
+/// This form generates a simple setter function for a boxed field.
 ///
-/// ## Forms
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * `Type` is *not* the type of the field, but the type of the boxed value; the field's type
+///   is `Box<Type>`, or `Option<Box<Type>>` with the keyword `optional`.
+/// * The type of the new value parameter is the value type `Type`, which is boxed before
+///   being stored in the field.
+/// * This function returns no value.
 ///
-/// ### `unset!(viz name)`
+/// The following — commented line and following implementation — are therefore equivalent:
 ///
-/// This form generates a simple setter function for optional fields that sets the value
-/// to `None`.
+/// ```rust
+/// # pub struct Handler { path: String }
+/// # pub struct Server { handler: Box<Handler> }
+/// impl Server {
+///     // set!(pub handler => boxed Handler);
+///
+///     /// Set the value of the boxed field `handler` within this structure.
+///     pub fn set_handler(&mut self, value: Handler) {
+///         self.handler = Box::new(value);
+///     }
+/// }
+/// ```
 ///
-/// * In this form `name` is both the name of the generated function and the name of the
-///   structure's field.
+/// ### `set!(viz name => [field_name,] [optional] shared Type)`
+///
+/// This form generates a simple setter function for a shared, reference-counted, field.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
 /// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
-/// * The use of this macro is intended to be paired with a [`set`] implementation using
-///   the `optional` keyword.
+/// * `Type` is the type of the field, either `Rc<T>` or `Arc<T>`, or `Option<Type>` with
+///   the keyword `optional`.
+/// * The type of the new value parameter is the value type `T`, which is stored in a new
+///   shared pointer; any other pointers to the field's previous value are unaffected.
 /// * This function returns no value.
 ///
 /// The following — commented line and following implementation — are therefore equivalent:
 ///
 /// ```rust
-/// pub struct Address {
-///     // number_on_street: u32, etc.
-///     street_2: Option<String>,
-/// }
-/// impl Address {
-///     // unset!(pub street_2);
-///
-///     /// Set the value of the field `street_2` within this structure to `None`. To set
-///     /// the value to `Some(value)`, use the method [`set_street_2`].
-///     #[inline(always)]
-///     pub fn street_2(&mut self) {
-///         self.street_2 = None;
+/// # use std::sync::Arc;
+/// # pub struct Config { port: u16 }
+/// # pub struct Server { config: Arc<Config> }
+/// impl Server {
+///     // set!(pub config => shared Arc<Config>);
+///
+///     /// Set the value of the shared field `config` within this structure, in a new shared
+///     /// pointer.
+///     pub fn set_config(&mut self, value: Config) {
+///         self.config = Arc::new(value);
 ///     }
 /// }
 /// ```
 ///
-/// ### `unset!(viz setter_name => field_name)`
+/// ### `set!(viz name => [field_name,] cow 'a Type)`
 ///
-/// This form generates a simple setter function for optional fields that sets the value
-/// to `None`.
+/// This form generates a simple setter function for a clone-on-write field.
 ///
-/// * In this form `name` is the name of the generated function while `field_name` is the
-///   name of the structure's field.
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * `Type` is *not* the type of the field, but the borrowed type of the field's value; the
+///   field's type is `Cow<'a, Type>`, where `'a` is the lifetime given, for example
+///   `cow 'a str` or `cow 'a [u8]`.
 /// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
-/// * The use of this macro is intended to be paired with a [`set`] implementation using
-///   the `optional` keyword.
-/// * This function returns no value.
+/// * The type of the new value parameter is the trait-bound type `T: Into<Cow<'a, Type>>`,
+///   so that either a borrowed value, such as `&'a str`, or an owned value, such as
+///   `String`, may be used.
 ///
 /// The following — commented line and following implementation — are therefore equivalent:
 ///
 /// ```rust
-/// pub struct Address {
-///     // number_on_street: u32, etc.
-///     street_2_string: Option<String>,
-/// }
-/// impl Address {
-///     // set!(pub street_2 => street_2_string);
-///
-///     /// Set the value of the field `street_2` within this structure to `None`. To set
-///     /// the value to `Some(value)`, use the method [`set_street_2`].
-///     #[inline(always)]
-///     pub fn street_2(&mut self) {
-///         self.street_2_string = None;
+/// # use std::borrow::Cow;
+/// # pub struct Token<'a> { text: Cow<'a, str> }
+/// impl<'a> Token<'a> {
+///     // set!(pub text => cow 'a str);
+///
+///     /// Set the value of the clone-on-write field `text` within this structure, from
+///     /// either a borrowed or an owned value (Using `Into<Cow>`).
+///     pub fn set_text<T: Into<Cow<'a, str>>>(&mut self, value: T) {
+///         self.text = value.into();
 ///     }
 /// }
 /// ```
 ///
-#[macro_export]
-macro_rules! unset {
-    // Base case: `viz name => field_name`
-    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
-        paste::paste! {
-            #[doc = "Set the value of the field `" $fn_name "` within this structure to `None`. "
-                    "To set the value to `Some(value)`, use the method [`set_" $fn_name "`]."]
-            #[inline(always)]
-            $fn_vis fn [<unset_ $fn_name>](&mut self) {
-                self.$field_name = None;
-            }
-        }
-    };
-    // Base case without *field name*: `viz name`
-    ($fn_vis:vis $name:ident) => {
-        $crate::unset!($fn_vis $name => $name);
-    };
-}
-
-// ------------------------------------------------------------------------------------------------
-// Accessor Macros ❱ With
-// ------------------------------------------------------------------------------------------------
-
-///
-///  Generate a builder style initializer method for a struct field.
-///
-/// ## Forms
-///
-/// ### `with!(viz name => Type)`
+/// ### `set!(viz name => [field_name,] [optional] [into] Type, validate = validator)`
 ///
-/// This form generates a builder-style function typically used to initialize a structure
-/// field during construction.
+/// This form generates a *validated* setter function, `try_set_name`, in place of the
+/// simple setter.
 ///
-/// * In this form `name` is both the name of the generated function and the name of the
-///   structure's field.
-/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
-///   that initializers can be chained.
-/// * The type of the new value parameter is the value type `Type`.
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The keywords `optional` and `into` have the same meaning as the forms above.
+/// * `validator` is the path to either a function declared with the
+///   [`validator`](crate::validator) macro, or a type that implements
+///   [`Validator<Type>`](crate::error::Validator); the value is only stored in the field if
+///   the validator returns `Ok(())`.
+/// * This function returns `Result<(), <validator as Validator<Type>>::Error>`, the error
+///   type is taken from the validator function's signature, or the validator type's
+///   implementation, and need not be repeated.
 ///
 /// The following — commented line and following implementation — are therefore equivalent:
 ///
 /// ```rust
-/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
-/// impl Address {
-///     // with!(pub number_on_street => u32);
+/// # pub struct Order { quantity: u32 }
+/// # #[derive(Debug)] pub struct QuantityError;
+/// use jemmy::error::Validator;
 ///
-///     /// Set the value of the field `number_on_street` within this structure, usually
-///     /// during construction. This function takes a mutable `self` parameter and returns
-///     /// `Self` allowing it to be chained during construction.
-///     pub fn with_number_on_street(mut self, number_on_street: u32) -> Self {
-///         self.number_on_street = number_on_street;
-///         self
+/// jemmy::validator! {
+///     fn check_quantity(value: &u32) -> Result<(), QuantityError> {
+///         if *value > 0 { Ok(()) } else { Err(QuantityError) }
 ///     }
 /// }
-/// ```
-///
-/// ### `with!(viz with_name => field_name, Type)`
-///
-/// This form generates a builder-style function typically used to initialize a structure
-/// field during construction.
-///
-/// * In this form `name` is the name of the generated function while `field_name` is the
-///   name of the structure's field.
-/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
-///   that initializers can be chained.
-/// * The type of the new value parameter is the value type `Type`.
 ///
-/// The following — commented line and following implementation — are therefore equivalent:
-///
-/// ```rust
-/// # pub struct Address { number: u32, street_1: String, street_2: Option<String> }
-/// impl Address {
-///     // with!(pub number_on_street => number, u32);
-///
-///     /// Set the value of the field `number_on_street` within this structure, usually
-///     /// during construction. This function takes a mutable `self` parameter and returns
-///     /// `Self` allowing it to be chained during construction.
-///     pub fn with_number_on_street(mut self, number_on_street: u32) -> Self {
-///         self.number = number_on_street;
-///         self
+/// impl Order {
+///     // set!(pub quantity => u32, validate = check_quantity);
+///
+///     /// Set the value of the field `quantity` within this structure, if the value is
+///     /// accepted by the field's validator; otherwise the validator's error is returned
+///     /// and the field is unchanged.
+///     pub fn try_set_quantity(
+///         &mut self,
+///         value: u32,
+///     ) -> Result<(), <check_quantity as Validator<u32>>::Error> {
+///         <check_quantity as Validator<u32>>::validate(&value)?;
+///         self.quantity = value;
+///         Ok(())
 ///     }
 /// }
 /// ```
 ///
-/// ### `with!(viz name => into Type)`
+/// ### `set!(viz name => [field_name,] [optional] [into] Type, validate = validator -> Error)`
 ///
-/// This form generates a builder-style function typically used to initialize a structure
-/// field during construction.
+/// This form generates a *validated* setter function, `try_set_name`, in place of the
+/// simple setter.
 ///
-/// * In this form `name` is both the name of the generated function and the name of the
-///   structure's field.
-/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
-///   that initializers can be chained.
-/// * The type of the new value parameter is the trait-bound type `T: Into<Type>` rather
-///   than `Type` for flexibility.
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The keywords `optional` and `into` have the same meaning as the forms above.
+/// * This form is used with a validator function that is not declared with the
+///   [`validator`](crate::validator) macro, such as one from another crate, in which case the
+///   error type cannot be read from the function's signature and must be given.
+/// * `validator` is the path to a function with the signature `fn(&Type) -> Result<(), E>`
+///   where `Error` implements `From<E>`, usually `E` is simply `Error`; the value is only
+///   stored in the field if the validator returns `Ok(())`. As the validator is called with
+///   `&value` deref coercion applies, so a `String` field may use a `fn(&str)` validator.
+/// * This function returns `Result<(), Error>`.
 ///
 /// The following — commented line and following implementation — are therefore equivalent:
 ///
 /// ```rust
-/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
-/// impl Address {
-///     // with!(pub street_1 => into String);
-///     pub fn with_street_1<T: Into<String>>(mut self, street_1: T) -> Self {
-///         self.street_1 = street_1.into();
-///         self
-///     }
+/// # pub struct Order { quantity: u32 }
+/// # #[derive(Debug)] pub struct QuantityError;
+/// fn check_quantity(value: &u32) -> Result<(), QuantityError> {
+///     if *value > 0 { Ok(()) } else { Err(QuantityError) }
 /// }
-/// ```
-///
-/// ### `with!(viz with_name => field_name, into Type)`
-///
-/// This form generates a builder-style function typically used to initialize a structure
-/// field during construction.
-///
-/// * In this form `name` is the name of the generated function while `field_name` is the
-///   name of the structure's field.
-/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
-///   that initializers can be chained.
-/// * The type of the new value parameter is the trait-bound type `T: Into<Type>` rather
-///   than `Type` for flexibility.
-///
-/// The following — commented line and following implementation — are therefore equivalent:
 ///
-/// ```rust
-/// # pub struct Address { number_on_street: u32, street_1_string: String, street_2: Option<String> }
-/// impl Address {
-///     // with!(pub street_1 => street_1_string, into String);
+/// impl Order {
+///     // set!(pub quantity => u32, validate = check_quantity -> QuantityError);
 ///
-///     /// Set the value of the field `number_on_street` within this structure, usually
-///     /// during construction. This function takes a mutable `self` parameter and returns
-///     /// `Self` allowing it to be chained during construction.
-///     pub fn with_street_1<T: Into<String>>(mut self, street_1: T) -> Self {
-///         self.street_1_string = street_1.into();
-///         self
+///     /// Set the value of the field `quantity` within this structure, if the value is
+///     /// accepted by the field's validator function; otherwise the validator's error is
+///     /// returned and the field is unchanged.
+///     pub fn try_set_quantity(&mut self, value: u32) -> Result<(), QuantityError> {
+///         check_quantity(&value)?;
+///         self.quantity = value;
+///         Ok(())
 ///     }
 /// }
 /// ```
 ///
-/// ### `with!(viz name => optional Type)`
+/// ### `set!(viz name => [field_name,] [optional] [into] Type, constraint)`
 ///
-/// This form generates a builder-style function, for optional fields, typically used to initialize a structure
-/// field during construction.
+/// This form generates a *validated* setter function, `try_set_name`, in place of the
+/// simple setter, where the value is checked by one of the built-in constraints in the
+/// [`error`](crate::error) module.
 ///
-/// * In this form `name` is both the name of the generated function and the name of the
-///   structure's field.
-/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
-///   that initializers can be chained.
-/// * While the type of the structure's field is `Option<Type>`, the type of the new value
-///   parameter is simply `Type`. The use of this macro is intended to be paired with
-///   an [`unset`] implementation.
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The keywords `optional` and `into` have the same meaning as the forms above.
+/// * `constraint` is one of `range R`, `non_empty`, `max_len N`, or `matches predicate`
+///   where `predicate` is the path to a function with the signature `fn(&Type) -> bool`.
+/// * This function returns `Result<(), ValidationError>`.
 ///
 /// The following — commented line and following implementation — are therefore equivalent:
 ///
 /// ```rust
-/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
-/// impl Address {
-///     // with!(pub street_2 => optional String);
-///
-///     /// Set the value of the field `number_on_street` within this structure, usually
-///     /// during construction. This function takes a mutable `self` parameter and returns
-///     /// `Self` allowing it to be chained during construction.
-///     pub fn with_street_2(mut self, street_2: String) -> Self {
-///         self.street_2 = Some(street_2);
-///         self
+/// # pub struct Person { age: u8 }
+/// impl Person {
+///     // set!(pub age => u8, range 0..=150);
+///
+///     /// Set the value of the field `age` within this structure, if the value satisfies
+///     /// the field's constraint; otherwise a `ValidationError` is returned and the field
+///     /// is unchanged.
+///     pub fn try_set_age(&mut self, value: u8) -> Result<(), jemmy::error::ValidationError> {
+///         jemmy::error::check_range("age", &value, 0..=150)?;
+///         self.age = value;
+///         Ok(())
 ///     }
 /// }
 /// ```
 ///
-/// ### `with!(viz with_name => field_name, optional Type)`
+/// ### `set!(viz name => [field_name,] [optional] [into] Type, invalidates lazy_name, ...)`
 ///
-/// This form generates a builder-style function, for optional fields, typically used to initialize a structure
-/// field during construction.
+/// This form generates a simple setter function that also invalidates one or more lazily
+/// computed fields, generated by the [`lazy_get`](crate::lazy_get) macro, which are derived
+/// from the value of this field.
 ///
-/// * In this form `name` is the name of the generated function while `field_name` is the
-///   name of the structure's field.
-/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
-///   that initializers can be chained.
-/// * While the type of the structure's field is `Option<Type>`, the type of the new value
-///   parameter is simply `Type`. The use of this macro is intended to be paired with
-///   an [`unset`] implementation.
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The keywords `optional` and `into` have the same meaning as the forms above.
+/// * Each `lazy_name` is the name given to a [`lazy_get`](crate::lazy_get) macro, whose
+///   `invalidate_lazy_name` function is called after the field is set.
 ///
 /// The following — commented line and following implementation — are therefore equivalent:
 ///
 /// ```rust
-/// # pub struct Address { number_on_street: u32, street_1: String, street_2_string: Option<String> }
-/// impl Address {
-///     // with!(pub street_2 => street_2_string, optional String);
-///
-///     /// Set the value of the field `number_on_street` within this structure, usually
-///     /// during construction. This function takes a mutable `self` parameter and returns
-///     /// `Self` allowing it to be chained during construction.
-///     pub fn with_street_2(mut self, street_2: String) -> Self {
-///         self.street_2_string = Some(street_2);
-///         self
+/// # use std::cell::OnceCell;
+/// # pub struct Packet { payload: Vec<u8>, checksum: OnceCell<u64> }
+/// # impl Packet { fn invalidate_checksum(&mut self) { let _ = self.checksum.take(); } }
+/// impl Packet {
+///     // set!(pub payload => Vec<u8>, invalidates checksum);
+///
+///     /// Set the value of the field `payload` within this structure, and invalidate the
+///     /// lazily computed fields that depend on it.
+///     pub fn set_payload(&mut self, value: Vec<u8>) {
+///         self.payload = value;
+///         self.invalidate_checksum();
 ///     }
 /// }
 /// ```
 ///
 #[macro_export]
-macro_rules! with {
+macro_rules! set {
+    // Case (17) without *field name* and with the constraint *non_empty* must precede the base
+    // case, which would otherwise match `String, non_empty` as a field name and a type.
+    ($fn_vis:vis $name:ident => $value_type:ty, non_empty) => {
+        $crate::set!($fn_vis $name => $name, $value_type, non_empty);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
     // Base case: `viz name => field_name, Type`
     ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
         paste::paste! {
-            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction. "
-                    "This function takes a mutable `self` parameter and returns `Self` allowing it to be "
-                    "chained during construction."]
-            $fn_vis fn [<with_ $fn_name>](mut self, value: $value_type) -> Self {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure."]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: $value_type) {
                 self.$field_name = value;
-                self
             }
         }
     };
-    // Base case without *field name*: `viz name => Type`
+    // Base Case without *field name*: `viz name => Type`
     ($fn_vis:vis $name:ident => $value_type:ty) => {
-        $crate::with!($fn_vis $name => $name, $value_type);
+        $crate::set!($fn_vis $name => $name, $value_type);
     };
-    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
     // (2) Base case with *into*: `viz name => field_name, into Type`
     ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
         paste::paste! {
-            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction. "
-                    "This function takes a mutable `self` parameter and returns `Self` allowing it to be "
-                    "chained during construction."]
-            $fn_vis fn [<with_ $fn_name>]<T: Into<$value_type>>(mut self, value: T) -> Self {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure (Using `Into<" $value_type ">`)."]
+            $fn_vis fn [<set_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) {
                 self.$field_name = value.into();
-                self
             }
         }
     };
     // Case (2) without *field name*: `viz name => into Type`
     ($fn_vis:vis $name:ident => into $value_type:ty) => {
-        $crate::with!($fn_vis $name => $name, into $value_type);
+        $crate::set!($fn_vis $name => $name, into $value_type);
     };
-    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
     // (3) Base case with *optional*: `viz name => field_name, optional Type`
     ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty) => {
         paste::paste! {
-            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction. "
-                    "This function takes a mutable `self` parameter and returns `Self` allowing it to be "
-                    "chained during construction."]
-            $fn_vis fn [<with_ $fn_name>](mut self, value: $value_type) -> Self {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure. "
+                    "While the corresponding field is an `Option<" $value_type
+                    ">`, this function uses the wrapped type `" $value_type
+                    "`. To set the field value to `None` use the method [`unset_" $fn_name "`]."]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: $value_type) {
                 self.$field_name = Some(value);
-                self
             }
         }
     };
-    // Case (3) without *field name*: `viz name => optional Type`
+    // Case (3) with *field name*: `viz name => optional Type`
     ($fn_vis:vis $name:ident => optional $value_type:ty) => {
-        $crate::with!($fn_vis $name => $name, optional $value_type);
+        $crate::set!($fn_vis $name => $name, optional $value_type);
     };
-    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
     // (4) Case (3) with *into*: `viz name => field_name, optional into Type`
     ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty) => {
         paste::paste! {
-            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction. "
-                    "This function takes a mutable `self` parameter and returns `Self` allowing it to be "
-                    "chained during construction."]
-            $fn_vis fn [<with_ $fn_name>]<T: Into<$value_type>>(mut self, value: T) -> Self {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure. "
+                    "While the corresponding field is an `Option<" $value_type
+                    ">`, this function uses the type `Into<" $value_type
+                    ">`. To set the field value to `None` use the method [`unset_" $fn_name "`]."]
+            $fn_vis fn [<set_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) {
                 self.$field_name = Some(value.into());
-                self
             }
         }
     };
     // Case (4) without *field name*: `viz name => optional into Type`
     ($fn_vis:vis $name:ident => optional into $value_type:ty) => {
-        $crate::with!($fn_vis $name => $name, optional into $value_type);
+        $crate::set!($fn_vis $name => $name, optional into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (5) Base case with *validate*: `viz name => field_name, Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, if the value "
+                    "is accepted by the field's validator function; otherwise the validator's error "
+                    "is returned and the field is unchanged."]
+            $fn_vis fn [<try_set_ $fn_name>](&mut self, value: $value_type) -> Result<(), $error_type> {
+                $($validator)::+(&value)?;
+                self.$field_name = value;
+                Ok(())
+            }
+        }
+    };
+    // Case (5) without *field name*: `viz name => Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::set!($fn_vis $name => $name, $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (6) Case (5) with *into*: `viz name => field_name, into Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, if the value "
+                    "is accepted by the field's validator function; otherwise the validator's error "
+                    "is returned and the field is unchanged."]
+            $fn_vis fn [<try_set_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) -> Result<(), $error_type> {
+                let value: $value_type = value.into();
+                $($validator)::+(&value)?;
+                self.$field_name = value;
+                Ok(())
+            }
+        }
+    };
+    // Case (6) without *field name*: `viz name => into Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::set!($fn_vis $name => $name, into $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (7) Case (5) with *optional*: `viz name => field_name, optional Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, if the value "
+                    "is accepted by the field's validator function; otherwise the validator's error "
+                    "is returned and the field is unchanged."]
+            $fn_vis fn [<try_set_ $fn_name>](&mut self, value: $value_type) -> Result<(), $error_type> {
+                $($validator)::+(&value)?;
+                self.$field_name = Some(value);
+                Ok(())
+            }
+        }
+    };
+    // Case (7) without *field name*: `viz name => optional Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => optional $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::set!($fn_vis $name => $name, optional $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (8) Case (7) with *into*: `viz name => field_name, optional into Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, if the value "
+                    "is accepted by the field's validator function; otherwise the validator's error "
+                    "is returned and the field is unchanged."]
+            $fn_vis fn [<try_set_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) -> Result<(), $error_type> {
+                let value: $value_type = value.into();
+                $($validator)::+(&value)?;
+                self.$field_name = Some(value);
+                Ok(())
+            }
+        }
+    };
+    // Case (8) without *field name*: `viz name => optional into Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::set!($fn_vis $name => $name, optional into $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (9) Case (5) with an inferred error: `viz name => field_name, Type, validate = validator`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty, validate = $($validator:ident)::+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, if the value "
+                    "is accepted by the field's validator; otherwise the validator's error "
+                    "is returned and the field is unchanged."]
+            $fn_vis fn [<try_set_ $fn_name>](&mut self, value: $value_type) -> Result<(), <$($validator)::+ as $crate::error::Validator<$value_type>>::Error> {
+                <$($validator)::+ as $crate::error::Validator<$value_type>>::validate(&value)?;
+                self.$field_name = value;
+                Ok(())
+            }
+        }
+    };
+    // Case (9) without *field name*: `viz name => Type, validate = validator`
+    ($fn_vis:vis $name:ident => $value_type:ty, validate = $($validator:ident)::+) => {
+        $crate::set!($fn_vis $name => $name, $value_type, validate = $($validator)::+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (10) Case (9) with *into*: `viz name => field_name, into Type, validate = validator`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty, validate = $($validator:ident)::+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, if the value "
+                    "is accepted by the field's validator; otherwise the validator's error "
+                    "is returned and the field is unchanged."]
+            $fn_vis fn [<try_set_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) -> Result<(), <$($validator)::+ as $crate::error::Validator<$value_type>>::Error> {
+                let value: $value_type = value.into();
+                <$($validator)::+ as $crate::error::Validator<$value_type>>::validate(&value)?;
+                self.$field_name = value;
+                Ok(())
+            }
+        }
+    };
+    // Case (10) without *field name*: `viz name => into Type, validate = validator`
+    ($fn_vis:vis $name:ident => into $value_type:ty, validate = $($validator:ident)::+) => {
+        $crate::set!($fn_vis $name => $name, into $value_type, validate = $($validator)::+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (11) Case (9) with *optional*: `viz name => field_name, optional Type, validate = validator`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty, validate = $($validator:ident)::+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, if the value "
+                    "is accepted by the field's validator; otherwise the validator's error "
+                    "is returned and the field is unchanged."]
+            $fn_vis fn [<try_set_ $fn_name>](&mut self, value: $value_type) -> Result<(), <$($validator)::+ as $crate::error::Validator<$value_type>>::Error> {
+                <$($validator)::+ as $crate::error::Validator<$value_type>>::validate(&value)?;
+                self.$field_name = Some(value);
+                Ok(())
+            }
+        }
+    };
+    // Case (11) without *field name*: `viz name => optional Type, validate = validator`
+    ($fn_vis:vis $name:ident => optional $value_type:ty, validate = $($validator:ident)::+) => {
+        $crate::set!($fn_vis $name => $name, optional $value_type, validate = $($validator)::+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (12) Case (11) with *into*: `viz name => field_name, optional into Type, validate = validator`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty, validate = $($validator:ident)::+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, if the value "
+                    "is accepted by the field's validator; otherwise the validator's error "
+                    "is returned and the field is unchanged."]
+            $fn_vis fn [<try_set_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) -> Result<(), <$($validator)::+ as $crate::error::Validator<$value_type>>::Error> {
+                let value: $value_type = value.into();
+                <$($validator)::+ as $crate::error::Validator<$value_type>>::validate(&value)?;
+                self.$field_name = Some(value);
+                Ok(())
+            }
+        }
+    };
+    // Case (12) without *field name*: `viz name => optional into Type, validate = validator`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty, validate = $($validator:ident)::+) => {
+        $crate::set!($fn_vis $name => $name, optional into $value_type, validate = $($validator)::+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (13) Base case with *invalidates*: `viz name => field_name, Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, and invalidate the "
+                    "lazily computed fields that depend on it."]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: $value_type) {
+                self.$field_name = value;
+                $(self.[<invalidate_ $lazy_name>]();)+
+            }
+        }
+    };
+    // Case (13) without *field name*: `viz name => Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $name:ident => $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        $crate::set!($fn_vis $name => $name, $value_type, invalidates $($lazy_name),+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (14) Case (13) with *into*: `viz name => field_name, into Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, and invalidate the "
+                    "lazily computed fields that depend on it."]
+            $fn_vis fn [<set_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) {
+                self.$field_name = value.into();
+                $(self.[<invalidate_ $lazy_name>]();)+
+            }
+        }
+    };
+    // Case (14) without *field name*: `viz name => into Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $name:ident => into $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        $crate::set!($fn_vis $name => $name, into $value_type, invalidates $($lazy_name),+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (15) Case (13) with *optional*: `viz name => field_name, optional Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, and invalidate the "
+                    "lazily computed fields that depend on it."]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: $value_type) {
+                self.$field_name = Some(value);
+                $(self.[<invalidate_ $lazy_name>]();)+
+            }
+        }
+    };
+    // Case (15) without *field name*: `viz name => optional Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $name:ident => optional $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        $crate::set!($fn_vis $name => $name, optional $value_type, invalidates $($lazy_name),+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (16) Case (15) with *into*: `viz name => field_name, optional into Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, and invalidate the "
+                    "lazily computed fields that depend on it."]
+            $fn_vis fn [<set_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) {
+                self.$field_name = Some(value.into());
+                $(self.[<invalidate_ $lazy_name>]();)+
+            }
+        }
+    };
+    // Case (16) without *field name*: `viz name => optional into Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        $crate::set!($fn_vis $name => $name, optional into $value_type, invalidates $($lazy_name),+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (17) Base case with *constraint*: `viz name => field_name, Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty, $($constraint:tt)+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, if the value "
+                    "satisfies the field's constraint; otherwise a `ValidationError` is returned and the "
+                    "field is unchanged."]
+            $fn_vis fn [<try_set_ $fn_name>](&mut self, value: $value_type) -> Result<(), $crate::error::ValidationError> {
+                $crate::check_constraint!(stringify!($fn_name), &value, $($constraint)+)?;
+                self.$field_name = value;
+                Ok(())
+            }
+        }
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (18) Case (17) with *into*: `viz name => field_name, into Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty, $($constraint:tt)+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, if the value "
+                    "satisfies the field's constraint; otherwise a `ValidationError` is returned and the "
+                    "field is unchanged."]
+            $fn_vis fn [<try_set_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) -> Result<(), $crate::error::ValidationError> {
+                let value: $value_type = value.into();
+                $crate::check_constraint!(stringify!($fn_name), &value, $($constraint)+)?;
+                self.$field_name = value;
+                Ok(())
+            }
+        }
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (19) Case (17) with *optional*: `viz name => field_name, optional Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty, $($constraint:tt)+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, if the value "
+                    "satisfies the field's constraint; otherwise a `ValidationError` is returned and the "
+                    "field is unchanged."]
+            $fn_vis fn [<try_set_ $fn_name>](&mut self, value: $value_type) -> Result<(), $crate::error::ValidationError> {
+                $crate::check_constraint!(stringify!($fn_name), &value, $($constraint)+)?;
+                self.$field_name = Some(value);
+                Ok(())
+            }
+        }
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (20) Case (19) with *into*: `viz name => field_name, optional into Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty, $($constraint:tt)+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, if the value "
+                    "satisfies the field's constraint; otherwise a `ValidationError` is returned and the "
+                    "field is unchanged."]
+            $fn_vis fn [<try_set_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) -> Result<(), $crate::error::ValidationError> {
+                let value: $value_type = value.into();
+                $crate::check_constraint!(stringify!($fn_name), &value, $($constraint)+)?;
+                self.$field_name = Some(value);
+                Ok(())
+            }
+        }
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (21) Base case with *boxed*: `viz name => field_name, boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, boxed $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the boxed field `" $fn_name "` within this structure."]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: $value_type) {
+                self.$field_name = ::std::boxed::Box::new(value);
+            }
+        }
+    };
+    // Case (21) without *field name*: `viz name => boxed Type`
+    ($fn_vis:vis $name:ident => boxed $value_type:ty) => {
+        $crate::set!($fn_vis $name => $name, boxed $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (22) Case (3) with *boxed*: `viz name => field_name, optional boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional boxed $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the boxed field `" $fn_name "` within this structure. "
+                    "To set the field value to `None` use the method [`unset_" $fn_name "`]."]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: $value_type) {
+                self.$field_name = Some(::std::boxed::Box::new(value));
+            }
+        }
+    };
+    // Case (22) without *field name*: `viz name => optional boxed Type`
+    ($fn_vis:vis $name:ident => optional boxed $value_type:ty) => {
+        $crate::set!($fn_vis $name => $name, optional boxed $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (23) Base case with *shared*: `viz name => field_name, shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, shared $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the shared field `" $fn_name "` within this structure, in a new shared pointer."]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: <$value_type as ::core::ops::Deref>::Target) {
+                self.$field_name = $crate::field::pointer::SharedPointer::new_shared(value);
+            }
+        }
+    };
+    // Case (23) without *field name*: `viz name => shared Type`
+    ($fn_vis:vis $name:ident => shared $value_type:ty) => {
+        $crate::set!($fn_vis $name => $name, shared $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (24) Case (3) with *shared*: `viz name => field_name, optional shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional shared $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the optional shared field `" $fn_name "` within this structure, in a new shared pointer. "
+                    "To set the field value to `None` use the method [`unset_" $fn_name "`]."]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: <$value_type as ::core::ops::Deref>::Target) {
+                self.$field_name = Some($crate::field::pointer::SharedPointer::new_shared(value));
+            }
+        }
+    };
+    // Case (24) without *field name*: `viz name => optional shared Type`
+    ($fn_vis:vis $name:ident => optional shared $value_type:ty) => {
+        $crate::set!($fn_vis $name => $name, optional shared $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (25) Base case with *cow*: `viz name => field_name, cow 'a Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, cow $lifetime:lifetime $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the clone-on-write field `" $fn_name "` within this structure, "
+                    "from either a borrowed or an owned value (Using `Into<Cow>`)."]
+            $fn_vis fn [<set_ $fn_name>]<T: Into<::std::borrow::Cow<$lifetime, $value_type>>>(&mut self, value: T) {
+                self.$field_name = value.into();
+            }
+        }
+    };
+    // Case (25) without *field name*: `viz name => cow 'a Type`
+    ($fn_vis:vis $name:ident => cow $lifetime:lifetime $value_type:ty) => {
+        $crate::set!($fn_vis $name => $name, cow $lifetime $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // The constraint cases without *field name* must follow all those with *field name*, the
+    // trailing constraint tokens would otherwise match the delegated form's keywords.
+    // Case (17) without *field name*: `viz name => Type, constraint`
+    ($fn_vis:vis $name:ident => $value_type:ty, $($constraint:tt)+) => {
+        $crate::set!($fn_vis $name => $name, $value_type, $($constraint)+);
+    };
+    // Case (18) without *field name*: `viz name => into Type, constraint`
+    ($fn_vis:vis $name:ident => into $value_type:ty, $($constraint:tt)+) => {
+        $crate::set!($fn_vis $name => $name, into $value_type, $($constraint)+);
+    };
+    // Case (19) without *field name*: `viz name => optional Type, constraint`
+    ($fn_vis:vis $name:ident => optional $value_type:ty, $($constraint:tt)+) => {
+        $crate::set!($fn_vis $name => $name, optional $value_type, $($constraint)+);
+    };
+    // Case (20) without *field name*: `viz name => optional into Type, constraint`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty, $($constraint:tt)+) => {
+        $crate::set!($fn_vis $name => $name, optional into $value_type, $($constraint)+);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Unset
+// ------------------------------------------------------------------------------------------------
+
+///
+///  Generate an *un-setter* method for an optional struct field.
+///
+/// ## Forms
+///
+/// ### `unset!(viz name)`
+///
+/// This form generates a simple setter function for optional fields that sets the value
+/// to `None`.
+///
+/// * In this form `name` is both the name of the generated function and the name of the
+///   structure's field.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The use of this macro is intended to be paired with a [`set`] implementation using
+///   the `optional` keyword.
+/// * This function returns no value.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// pub struct Address {
+///     // number_on_street: u32, etc.
+///     street_2: Option<String>,
+/// }
+/// impl Address {
+///     // unset!(pub street_2);
+///
+///     /// Set the value of the field `street_2` within this structure to `None`. To set
+///     /// the value to `Some(value)`, use the method [`set_street_2`].
+///     #[inline(always)]
+///     pub fn street_2(&mut self) {
+///         self.street_2 = None;
+///     }
+/// }
+/// ```
+///
+/// ### `unset!(viz setter_name => field_name)`
+///
+/// This form generates a simple setter function for optional fields that sets the value
+/// to `None`.
+///
+/// * In this form `name` is the name of the generated function while `field_name` is the
+///   name of the structure's field.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The use of this macro is intended to be paired with a [`set`] implementation using
+///   the `optional` keyword.
+/// * This function returns no value.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// pub struct Address {
+///     // number_on_street: u32, etc.
+///     street_2_string: Option<String>,
+/// }
+/// impl Address {
+///     // set!(pub street_2 => street_2_string);
+///
+///     /// Set the value of the field `street_2` within this structure to `None`. To set
+///     /// the value to `Some(value)`, use the method [`set_street_2`].
+///     #[inline(always)]
+///     pub fn street_2(&mut self) {
+///         self.street_2_string = None;
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! unset {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure to `None`. "
+                    "To set the value to `Some(value)`, use the method [`set_" $fn_name "`]."]
+            #[inline(always)]
+            $fn_vis fn [<unset_ $fn_name>](&mut self) {
+                self.$field_name = None;
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::unset!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ With
+// ------------------------------------------------------------------------------------------------
+
+///
+///  Generate a builder style initializer method for a struct field.
+///
+/// ## Forms
+///
+/// ### `with!(viz name => Type)`
+///
+/// This form generates a builder-style function typically used to initialize a structure
+/// field during construction.
+///
+/// * In this form `name` is both the name of the generated function and the name of the
+///   structure's field.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
+///   that initializers can be chained.
+/// * The type of the new value parameter is the value type `Type`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // with!(pub number_on_street => u32);
+///
+///     /// Set the value of the field `number_on_street` within this structure, usually
+///     /// during construction. This function takes a mutable `self` parameter and returns
+///     /// `Self` allowing it to be chained during construction.
+///     pub fn with_number_on_street(mut self, number_on_street: u32) -> Self {
+///         self.number_on_street = number_on_street;
+///         self
+///     }
+/// }
+/// ```
+///
+/// ### `with!(viz with_name => field_name, Type)`
+///
+/// This form generates a builder-style function typically used to initialize a structure
+/// field during construction.
+///
+/// * In this form `name` is the name of the generated function while `field_name` is the
+///   name of the structure's field.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
+///   that initializers can be chained.
+/// * The type of the new value parameter is the value type `Type`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { number: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // with!(pub number_on_street => number, u32);
+///
+///     /// Set the value of the field `number_on_street` within this structure, usually
+///     /// during construction. This function takes a mutable `self` parameter and returns
+///     /// `Self` allowing it to be chained during construction.
+///     pub fn with_number_on_street(mut self, number_on_street: u32) -> Self {
+///         self.number = number_on_street;
+///         self
+///     }
+/// }
+/// ```
+///
+/// ### `with!(viz name => into Type)`
+///
+/// This form generates a builder-style function typically used to initialize a structure
+/// field during construction.
+///
+/// * In this form `name` is both the name of the generated function and the name of the
+///   structure's field.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
+///   that initializers can be chained.
+/// * The type of the new value parameter is the trait-bound type `T: Into<Type>` rather
+///   than `Type` for flexibility.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // with!(pub street_1 => into String);
+///     pub fn with_street_1<T: Into<String>>(mut self, street_1: T) -> Self {
+///         self.street_1 = street_1.into();
+///         self
+///     }
+/// }
+/// ```
+///
+/// ### `with!(viz with_name => field_name, into Type)`
+///
+/// This form generates a builder-style function typically used to initialize a structure
+/// field during construction.
+///
+/// * In this form `name` is the name of the generated function while `field_name` is the
+///   name of the structure's field.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
+///   that initializers can be chained.
+/// * The type of the new value parameter is the trait-bound type `T: Into<Type>` rather
+///   than `Type` for flexibility.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { number_on_street: u32, street_1_string: String, street_2: Option<String> }
+/// impl Address {
+///     // with!(pub street_1 => street_1_string, into String);
+///
+///     /// Set the value of the field `number_on_street` within this structure, usually
+///     /// during construction. This function takes a mutable `self` parameter and returns
+///     /// `Self` allowing it to be chained during construction.
+///     pub fn with_street_1<T: Into<String>>(mut self, street_1: T) -> Self {
+///         self.street_1_string = street_1.into();
+///         self
+///     }
+/// }
+/// ```
+///
+/// ### `with!(viz name => optional Type)`
+///
+/// This form generates a builder-style function, for optional fields, typically used to initialize a structure
+/// field during construction.
+///
+/// * In this form `name` is both the name of the generated function and the name of the
+///   structure's field.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
+///   that initializers can be chained.
+/// * While the type of the structure's field is `Option<Type>`, the type of the new value
+///   parameter is simply `Type`. The use of this macro is intended to be paired with
+///   an [`unset`] implementation.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2: Option<String> }
+/// impl Address {
+///     // with!(pub street_2 => optional String);
+///
+///     /// Set the value of the field `number_on_street` within this structure, usually
+///     /// during construction. This function takes a mutable `self` parameter and returns
+///     /// `Self` allowing it to be chained during construction.
+///     pub fn with_street_2(mut self, street_2: String) -> Self {
+///         self.street_2 = Some(street_2);
+///         self
+///     }
+/// }
+/// ```
+///
+/// ### `with!(viz with_name => field_name, optional Type)`
+///
+/// This form generates a builder-style function, for optional fields, typically used to initialize a structure
+/// field during construction.
+///
+/// * In this form `name` is the name of the generated function while `field_name` is the
+///   name of the structure's field.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
+///   that initializers can be chained.
+/// * While the type of the structure's field is `Option<Type>`, the type of the new value
+///   parameter is simply `Type`. The use of this macro is intended to be paired with
+///   an [`unset`] implementation.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { number_on_street: u32, street_1: String, street_2_string: Option<String> }
+/// impl Address {
+///     // with!(pub street_2 => street_2_string, optional String);
+///
+///     /// Set the value of the field `number_on_street` within this structure, usually
+///     /// during construction. This function takes a mutable `self` parameter and returns
+///     /// `Self` allowing it to be chained during construction.
+///     pub fn with_street_2(mut self, street_2: String) -> Self {
+///         self.street_2_string = Some(street_2);
+///         self
+///     }
+/// }
+/// ```
+///
+/// ### `with!(viz name => [field_name,] [optional] boxed Type)`
+///
+/// This form generates a simple initializer function for a boxed field.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`.
+/// * `Type` is *not* the type of the field, but the type of the boxed value; the field's type
+///   is `Box<Type>`, or `Option<Box<Type>>` with the keyword `optional`.
+/// * The type of the new value parameter is the value type `Type`, which is boxed before
+///   being stored in the field.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Handler { path: String }
+/// # pub struct Server { handler: Box<Handler> }
+/// impl Server {
+///     // with!(pub handler => boxed Handler);
+///
+///     /// Set the value of the field `handler` within this structure, usually during construction.
+///     /// This function takes a mutable `self` parameter and returns `Self` allowing it to be
+///     /// chained during construction.
+///     pub fn with_handler(mut self, value: Handler) -> Self {
+///         self.handler = Box::new(value);
+///         self
+///     }
+/// }
+/// ```
+///
+/// ### `with!(viz name => [field_name,] [optional] shared Type)`
+///
+/// This form generates a simple initializer function for a shared, reference-counted, field.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`.
+/// * `Type` is the type of the field, either `Rc<T>` or `Arc<T>`, or `Option<Type>` with
+///   the keyword `optional`.
+/// * The type of the new value parameter is the value type `T`, which is stored in a new
+///   shared pointer; any other pointers to the field's previous value are unaffected.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # pub struct Config { port: u16 }
+/// # pub struct Server { config: Arc<Config> }
+/// impl Server {
+///     // with!(pub config => shared Arc<Config>);
+///
+///     /// Set the value of the field `config` within this structure, usually during construction.
+///     /// This function takes a mutable `self` parameter and returns `Self` allowing it to be
+///     /// chained during construction.
+///     pub fn with_config(mut self, value: Config) -> Self {
+///         self.config = Arc::new(value);
+///         self
+///     }
+/// }
+/// ```
+///
+/// ### `with!(viz name => [field_name,] cow 'a Type)`
+///
+/// This form generates a simple initializer function for a clone-on-write field.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * `Type` is *not* the type of the field, but the borrowed type of the field's value; the
+///   field's type is `Cow<'a, Type>`, where `'a` is the lifetime given, for example
+///   `cow 'a str` or `cow 'a [u8]`.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`.
+/// * The type of the new value parameter is the trait-bound type `T: Into<Cow<'a, Type>>`,
+///   so that either a borrowed value, such as `&'a str`, or an owned value, such as
+///   `String`, may be used.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # pub struct Token<'a> { text: Cow<'a, str> }
+/// impl<'a> Token<'a> {
+///     // with!(pub text => cow 'a str);
+///
+///     /// Set the value of the clone-on-write field `text` within this structure, usually
+///     /// during construction, from either a borrowed or an owned value. This function
+///     /// takes a mutable `self` parameter and returns `Self` allowing it to be chained
+///     /// during construction.
+///     pub fn with_text<T: Into<Cow<'a, str>>>(mut self, value: T) -> Self {
+///         self.text = value.into();
+///         self
+///     }
+/// }
+/// ```
+///
+/// ### `with!(viz name => [field_name,] [optional] [into] Type, validate = validator)`
+///
+/// This form generates a *validated* builder-style function, `try_with_name`, in place of the
+/// simple initializer.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The keywords `optional` and `into` have the same meaning as the forms above.
+/// * `validator` is the path to either a function declared with the
+///   [`validator`](crate::validator) macro, or a type that implements
+///   [`Validator<Type>`](crate::error::Validator).
+/// * This form requires mutability in the form of a `mut self`, and returns
+///   `Result<Self, <validator as Validator<Type>>::Error>`, so that initializers can be
+///   chained with the `?` operator; the error type is taken from the validator function's
+///   signature, or the validator type's implementation, and need not be repeated.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Order { quantity: u32 }
+/// # #[derive(Debug)] pub struct QuantityError;
+/// use jemmy::error::Validator;
+///
+/// jemmy::validator! {
+///     fn check_quantity(value: &u32) -> Result<(), QuantityError> {
+///         if *value > 0 { Ok(()) } else { Err(QuantityError) }
+///     }
+/// }
+///
+/// impl Order {
+///     // with!(pub quantity => u32, validate = check_quantity);
+///
+///     /// Set the value of the field `quantity` within this structure, usually during
+///     /// construction, if the value is accepted by the field's validator. This function
+///     /// takes a mutable `self` parameter and returns `Result<Self, _>` allowing it to be
+///     /// chained during construction.
+///     pub fn try_with_quantity(
+///         mut self,
+///         value: u32,
+///     ) -> Result<Self, <check_quantity as Validator<u32>>::Error> {
+///         <check_quantity as Validator<u32>>::validate(&value)?;
+///         self.quantity = value;
+///         Ok(self)
+///     }
+/// }
+/// ```
+///
+/// ### `with!(viz name => [field_name,] [optional] [into] Type, validate = validator -> Error)`
+///
+/// This form generates a *validated* builder-style function, `try_with_name`, in place of the
+/// simple initializer.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The keywords `optional` and `into` have the same meaning as the forms above.
+/// * This form is used with a validator function that is not declared with the
+///   [`validator`](crate::validator) macro, such as one from another crate, in which case the
+///   error type cannot be read from the function's signature and must be given.
+/// * `validator` is the path to a function with the signature `fn(&Type) -> Result<(), E>`
+///   where `Error` implements `From<E>`, usually `E` is simply `Error`.
+/// * This form requires mutability in the form of a `mut self`, and returns
+///   `Result<Self, Error>`, so that initializers can be chained with the `?` operator.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Order { quantity: u32 }
+/// # #[derive(Debug)] pub struct QuantityError;
+/// fn check_quantity(value: &u32) -> Result<(), QuantityError> {
+///     if *value > 0 { Ok(()) } else { Err(QuantityError) }
+/// }
+///
+/// impl Order {
+///     // with!(pub quantity => u32, validate = check_quantity -> QuantityError);
+///
+///     /// Set the value of the field `quantity` within this structure, usually during
+///     /// construction, if the value is accepted by the field's validator function. This
+///     /// function takes a mutable `self` parameter and returns `Result<Self, _>` allowing
+///     /// it to be chained during construction.
+///     pub fn try_with_quantity(mut self, value: u32) -> Result<Self, QuantityError> {
+///         check_quantity(&value)?;
+///         self.quantity = value;
+///         Ok(self)
+///     }
+/// }
+/// ```
+///
+/// ### `with!(viz name => [field_name,] [optional] [into] Type, constraint)`
+///
+/// This form generates a *validated* builder-style function, `try_with_name`, in place of the
+/// simple initializer, where the value is checked by one of the built-in constraints in the
+/// [`error`](crate::error) module.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The keywords `optional` and `into` have the same meaning as the forms above.
+/// * `constraint` is one of `range R`, `non_empty`, `max_len N`, or `matches predicate`
+///   where `predicate` is the path to a function with the signature `fn(&Type) -> bool`.
+/// * This form requires mutability in the form of a `mut self`, and returns
+///   `Result<Self, ValidationError>`, so that initializers can be chained with the `?` operator.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Person { name: String }
+/// impl Person {
+///     // with!(pub name => into String, non_empty);
+///
+///     /// Set the value of the field `name` within this structure, usually during
+///     /// construction, if the value satisfies the field's constraint. This function takes
+///     /// a mutable `self` parameter and returns `Result<Self, ValidationError>` allowing it
+///     /// to be chained during construction.
+///     pub fn try_with_name<T: Into<String>>(mut self, value: T) -> Result<Self, jemmy::error::ValidationError> {
+///         let value: String = value.into();
+///         jemmy::error::check_non_empty("name", &value)?;
+///         self.name = value;
+///         Ok(self)
+///     }
+/// }
+/// ```
+///
+/// ### `with!(viz name => [field_name,] [optional] [into] Type, invalidates lazy_name, ...)`
+///
+/// This form generates a simple initializer function that also invalidates one or more lazily
+/// computed fields, generated by the [`lazy_get`](crate::lazy_get) macro, which are derived
+/// from the value of this field.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`.
+/// * The keywords `optional` and `into` have the same meaning as the forms above.
+/// * Each `lazy_name` is the name given to a [`lazy_get`](crate::lazy_get) macro, whose
+///   `invalidate_lazy_name` function is called after the field is set.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::cell::OnceCell;
+/// # pub struct Packet { payload: Vec<u8>, checksum: OnceCell<u64> }
+/// # impl Packet { fn invalidate_checksum(&mut self) { let _ = self.checksum.take(); } }
+/// impl Packet {
+///     // with!(pub payload => Vec<u8>, invalidates checksum);
+///
+///     /// Set the value of the field `payload` within this structure, usually during
+///     /// construction, and invalidate the lazily computed fields that depend on it. This
+///     /// function takes a mutable `self` parameter and returns `Self` allowing it to be
+///     /// chained during construction.
+///     pub fn with_payload(mut self, value: Vec<u8>) -> Self {
+///         self.payload = value;
+///         self.invalidate_checksum();
+///         self
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! with {
+    // Case (17) without *field name* and with the constraint *non_empty* must precede the base
+    // case, which would otherwise match `String, non_empty` as a field name and a type.
+    ($fn_vis:vis $name:ident => $value_type:ty, non_empty) => {
+        $crate::with!($fn_vis $name => $name, $value_type, non_empty);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction. "
+                    "This function takes a mutable `self` parameter and returns `Self` allowing it to be "
+                    "chained during construction."]
+            $fn_vis fn [<with_ $fn_name>](mut self, value: $value_type) -> Self {
+                self.$field_name = value;
+                self
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::with!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction. "
+                    "This function takes a mutable `self` parameter and returns `Self` allowing it to be "
+                    "chained during construction."]
+            $fn_vis fn [<with_ $fn_name>]<T: Into<$value_type>>(mut self, value: T) -> Self {
+                self.$field_name = value.into();
+                self
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::with!($fn_vis $name => $name, into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (3) Base case with *optional*: `viz name => field_name, optional Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction. "
+                    "This function takes a mutable `self` parameter and returns `Self` allowing it to be "
+                    "chained during construction."]
+            $fn_vis fn [<with_ $fn_name>](mut self, value: $value_type) -> Self {
+                self.$field_name = Some(value);
+                self
+            }
+        }
+    };
+    // Case (3) without *field name*: `viz name => optional Type`
+    ($fn_vis:vis $name:ident => optional $value_type:ty) => {
+        $crate::with!($fn_vis $name => $name, optional $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (4) Case (3) with *into*: `viz name => field_name, optional into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction. "
+                    "This function takes a mutable `self` parameter and returns `Self` allowing it to be "
+                    "chained during construction."]
+            $fn_vis fn [<with_ $fn_name>]<T: Into<$value_type>>(mut self, value: T) -> Self {
+                self.$field_name = Some(value.into());
+                self
+            }
+        }
+    };
+    // Case (4) without *field name*: `viz name => optional into Type`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty) => {
+        $crate::with!($fn_vis $name => $name, optional into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (5) Base case with *validate*: `viz name => field_name, Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction, "
+                    "if the value is accepted by the field's validator function. This function takes a "
+                    "mutable `self` parameter and returns `Result<Self, _>` allowing it to be chained "
+                    "during construction."]
+            $fn_vis fn [<try_with_ $fn_name>](mut self, value: $value_type) -> Result<Self, $error_type> {
+                $($validator)::+(&value)?;
+                self.$field_name = value;
+                Ok(self)
+            }
+        }
+    };
+    // Case (5) without *field name*: `viz name => Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with!($fn_vis $name => $name, $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (6) Case (5) with *into*: `viz name => field_name, into Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction, "
+                    "if the value is accepted by the field's validator function. This function takes a "
+                    "mutable `self` parameter and returns `Result<Self, _>` allowing it to be chained "
+                    "during construction."]
+            $fn_vis fn [<try_with_ $fn_name>]<T: Into<$value_type>>(mut self, value: T) -> Result<Self, $error_type> {
+                let value: $value_type = value.into();
+                $($validator)::+(&value)?;
+                self.$field_name = value;
+                Ok(self)
+            }
+        }
+    };
+    // Case (6) without *field name*: `viz name => into Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with!($fn_vis $name => $name, into $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (7) Case (5) with *optional*: `viz name => field_name, optional Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction, "
+                    "if the value is accepted by the field's validator function. This function takes a "
+                    "mutable `self` parameter and returns `Result<Self, _>` allowing it to be chained "
+                    "during construction."]
+            $fn_vis fn [<try_with_ $fn_name>](mut self, value: $value_type) -> Result<Self, $error_type> {
+                $($validator)::+(&value)?;
+                self.$field_name = Some(value);
+                Ok(self)
+            }
+        }
+    };
+    // Case (7) without *field name*: `viz name => optional Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => optional $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with!($fn_vis $name => $name, optional $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (8) Case (7) with *into*: `viz name => field_name, optional into Type, validate = validator -> Error`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction, "
+                    "if the value is accepted by the field's validator function. This function takes a "
+                    "mutable `self` parameter and returns `Result<Self, _>` allowing it to be chained "
+                    "during construction."]
+            $fn_vis fn [<try_with_ $fn_name>]<T: Into<$value_type>>(mut self, value: T) -> Result<Self, $error_type> {
+                let value: $value_type = value.into();
+                $($validator)::+(&value)?;
+                self.$field_name = Some(value);
+                Ok(self)
+            }
+        }
+    };
+    // Case (8) without *field name*: `viz name => optional into Type, validate = validator -> Error`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty, validate = $($validator:ident)::+ -> $error_type:ty) => {
+        $crate::with!($fn_vis $name => $name, optional into $value_type, validate = $($validator)::+ -> $error_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (9) Case (5) with an inferred error: `viz name => field_name, Type, validate = validator`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty, validate = $($validator:ident)::+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during "
+                    "construction, if the value is accepted by the field's validator. This "
+                    "function takes a mutable `self` parameter and returns `Result<Self, _>` "
+                    "allowing it to be chained during construction."]
+            $fn_vis fn [<try_with_ $fn_name>](mut self, value: $value_type) -> Result<Self, <$($validator)::+ as $crate::error::Validator<$value_type>>::Error> {
+                <$($validator)::+ as $crate::error::Validator<$value_type>>::validate(&value)?;
+                self.$field_name = value;
+                Ok(self)
+            }
+        }
+    };
+    // Case (9) without *field name*: `viz name => Type, validate = validator`
+    ($fn_vis:vis $name:ident => $value_type:ty, validate = $($validator:ident)::+) => {
+        $crate::with!($fn_vis $name => $name, $value_type, validate = $($validator)::+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (10) Case (9) with *into*: `viz name => field_name, into Type, validate = validator`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty, validate = $($validator:ident)::+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during "
+                    "construction, if the value is accepted by the field's validator. This "
+                    "function takes a mutable `self` parameter and returns `Result<Self, _>` "
+                    "allowing it to be chained during construction."]
+            $fn_vis fn [<try_with_ $fn_name>]<T: Into<$value_type>>(mut self, value: T) -> Result<Self, <$($validator)::+ as $crate::error::Validator<$value_type>>::Error> {
+                let value: $value_type = value.into();
+                <$($validator)::+ as $crate::error::Validator<$value_type>>::validate(&value)?;
+                self.$field_name = value;
+                Ok(self)
+            }
+        }
+    };
+    // Case (10) without *field name*: `viz name => into Type, validate = validator`
+    ($fn_vis:vis $name:ident => into $value_type:ty, validate = $($validator:ident)::+) => {
+        $crate::with!($fn_vis $name => $name, into $value_type, validate = $($validator)::+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (11) Case (9) with *optional*: `viz name => field_name, optional Type, validate = validator`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty, validate = $($validator:ident)::+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during "
+                    "construction, if the value is accepted by the field's validator. This "
+                    "function takes a mutable `self` parameter and returns `Result<Self, _>` "
+                    "allowing it to be chained during construction."]
+            $fn_vis fn [<try_with_ $fn_name>](mut self, value: $value_type) -> Result<Self, <$($validator)::+ as $crate::error::Validator<$value_type>>::Error> {
+                <$($validator)::+ as $crate::error::Validator<$value_type>>::validate(&value)?;
+                self.$field_name = Some(value);
+                Ok(self)
+            }
+        }
+    };
+    // Case (11) without *field name*: `viz name => optional Type, validate = validator`
+    ($fn_vis:vis $name:ident => optional $value_type:ty, validate = $($validator:ident)::+) => {
+        $crate::with!($fn_vis $name => $name, optional $value_type, validate = $($validator)::+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (12) Case (11) with *into*: `viz name => field_name, optional into Type, validate = validator`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty, validate = $($validator:ident)::+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during "
+                    "construction, if the value is accepted by the field's validator. This "
+                    "function takes a mutable `self` parameter and returns `Result<Self, _>` "
+                    "allowing it to be chained during construction."]
+            $fn_vis fn [<try_with_ $fn_name>]<T: Into<$value_type>>(mut self, value: T) -> Result<Self, <$($validator)::+ as $crate::error::Validator<$value_type>>::Error> {
+                let value: $value_type = value.into();
+                <$($validator)::+ as $crate::error::Validator<$value_type>>::validate(&value)?;
+                self.$field_name = Some(value);
+                Ok(self)
+            }
+        }
+    };
+    // Case (12) without *field name*: `viz name => optional into Type, validate = validator`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty, validate = $($validator:ident)::+) => {
+        $crate::with!($fn_vis $name => $name, optional into $value_type, validate = $($validator)::+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (13) Base case with *invalidates*: `viz name => field_name, Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction, "
+                    "and invalidate the lazily computed fields that depend on it. This function takes a mutable "
+                    "`self` parameter and returns `Self` allowing it to be chained during construction."]
+            $fn_vis fn [<with_ $fn_name>](mut self, value: $value_type) -> Self {
+                self.$field_name = value;
+                $(self.[<invalidate_ $lazy_name>]();)+
+                self
+            }
+        }
+    };
+    // Case (13) without *field name*: `viz name => Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $name:ident => $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        $crate::with!($fn_vis $name => $name, $value_type, invalidates $($lazy_name),+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (14) Case (13) with *into*: `viz name => field_name, into Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction, "
+                    "and invalidate the lazily computed fields that depend on it. This function takes a mutable "
+                    "`self` parameter and returns `Self` allowing it to be chained during construction."]
+            $fn_vis fn [<with_ $fn_name>]<T: Into<$value_type>>(mut self, value: T) -> Self {
+                self.$field_name = value.into();
+                $(self.[<invalidate_ $lazy_name>]();)+
+                self
+            }
+        }
+    };
+    // Case (14) without *field name*: `viz name => into Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $name:ident => into $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        $crate::with!($fn_vis $name => $name, into $value_type, invalidates $($lazy_name),+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (15) Case (13) with *optional*: `viz name => field_name, optional Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction, "
+                    "and invalidate the lazily computed fields that depend on it. This function takes a mutable "
+                    "`self` parameter and returns `Self` allowing it to be chained during construction."]
+            $fn_vis fn [<with_ $fn_name>](mut self, value: $value_type) -> Self {
+                self.$field_name = Some(value);
+                $(self.[<invalidate_ $lazy_name>]();)+
+                self
+            }
+        }
+    };
+    // Case (15) without *field name*: `viz name => optional Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $name:ident => optional $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        $crate::with!($fn_vis $name => $name, optional $value_type, invalidates $($lazy_name),+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (16) Case (15) with *into*: `viz name => field_name, optional into Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction, "
+                    "and invalidate the lazily computed fields that depend on it. This function takes a mutable "
+                    "`self` parameter and returns `Self` allowing it to be chained during construction."]
+            $fn_vis fn [<with_ $fn_name>]<T: Into<$value_type>>(mut self, value: T) -> Self {
+                self.$field_name = Some(value.into());
+                $(self.[<invalidate_ $lazy_name>]();)+
+                self
+            }
+        }
+    };
+    // Case (16) without *field name*: `viz name => optional into Type, invalidates lazy_name, ...`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty, invalidates $($lazy_name:ident),+) => {
+        $crate::with!($fn_vis $name => $name, optional into $value_type, invalidates $($lazy_name),+);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (17) Base case with *constraint*: `viz name => field_name, Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty, $($constraint:tt)+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction, "
+                    "if the value satisfies the field's constraint. This function takes a mutable `self` "
+                    "parameter and returns `Result<Self, ValidationError>` allowing it to be chained "
+                    "during construction."]
+            $fn_vis fn [<try_with_ $fn_name>](mut self, value: $value_type) -> Result<Self, $crate::error::ValidationError> {
+                $crate::check_constraint!(stringify!($fn_name), &value, $($constraint)+)?;
+                self.$field_name = value;
+                Ok(self)
+            }
+        }
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (18) Case (17) with *into*: `viz name => field_name, into Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty, $($constraint:tt)+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction, "
+                    "if the value satisfies the field's constraint. This function takes a mutable `self` "
+                    "parameter and returns `Result<Self, ValidationError>` allowing it to be chained "
+                    "during construction."]
+            $fn_vis fn [<try_with_ $fn_name>]<T: Into<$value_type>>(mut self, value: T) -> Result<Self, $crate::error::ValidationError> {
+                let value: $value_type = value.into();
+                $crate::check_constraint!(stringify!($fn_name), &value, $($constraint)+)?;
+                self.$field_name = value;
+                Ok(self)
+            }
+        }
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (19) Case (17) with *optional*: `viz name => field_name, optional Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty, $($constraint:tt)+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction, "
+                    "if the value satisfies the field's constraint. This function takes a mutable `self` "
+                    "parameter and returns `Result<Self, ValidationError>` allowing it to be chained "
+                    "during construction."]
+            $fn_vis fn [<try_with_ $fn_name>](mut self, value: $value_type) -> Result<Self, $crate::error::ValidationError> {
+                $crate::check_constraint!(stringify!($fn_name), &value, $($constraint)+)?;
+                self.$field_name = Some(value);
+                Ok(self)
+            }
+        }
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (20) Case (19) with *into*: `viz name => field_name, optional into Type, constraint`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty, $($constraint:tt)+) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction, "
+                    "if the value satisfies the field's constraint. This function takes a mutable `self` "
+                    "parameter and returns `Result<Self, ValidationError>` allowing it to be chained "
+                    "during construction."]
+            $fn_vis fn [<try_with_ $fn_name>]<T: Into<$value_type>>(mut self, value: T) -> Result<Self, $crate::error::ValidationError> {
+                let value: $value_type = value.into();
+                $crate::check_constraint!(stringify!($fn_name), &value, $($constraint)+)?;
+                self.$field_name = Some(value);
+                Ok(self)
+            }
+        }
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (21) Base case with *boxed*: `viz name => field_name, boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, boxed $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction. "
+                    "This function takes a mutable `self` parameter and returns `Self` allowing it to be "
+                    "chained during construction."]
+            $fn_vis fn [<with_ $fn_name>](mut self, value: $value_type) -> Self {
+                self.$field_name = ::std::boxed::Box::new(value);
+                self
+            }
+        }
+    };
+    // Case (21) without *field name*: `viz name => boxed Type`
+    ($fn_vis:vis $name:ident => boxed $value_type:ty) => {
+        $crate::with!($fn_vis $name => $name, boxed $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (22) Case (3) with *boxed*: `viz name => field_name, optional boxed Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional boxed $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction. "
+                    "This function takes a mutable `self` parameter and returns `Self` allowing it to be "
+                    "chained during construction."]
+            $fn_vis fn [<with_ $fn_name>](mut self, value: $value_type) -> Self {
+                self.$field_name = Some(::std::boxed::Box::new(value));
+                self
+            }
+        }
+    };
+    // Case (22) without *field name*: `viz name => optional boxed Type`
+    ($fn_vis:vis $name:ident => optional boxed $value_type:ty) => {
+        $crate::with!($fn_vis $name => $name, optional boxed $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (23) Base case with *shared*: `viz name => field_name, shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, shared $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction. "
+                    "This function takes a mutable `self` parameter and returns `Self` allowing it to be "
+                    "chained during construction."]
+            $fn_vis fn [<with_ $fn_name>](mut self, value: <$value_type as ::core::ops::Deref>::Target) -> Self {
+                self.$field_name = $crate::field::pointer::SharedPointer::new_shared(value);
+                self
+            }
+        }
+    };
+    // Case (23) without *field name*: `viz name => shared Type`
+    ($fn_vis:vis $name:ident => shared $value_type:ty) => {
+        $crate::with!($fn_vis $name => $name, shared $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (24) Case (3) with *shared*: `viz name => field_name, optional shared Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional shared $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, usually during construction. "
+                    "This function takes a mutable `self` parameter and returns `Self` allowing it to be "
+                    "chained during construction."]
+            $fn_vis fn [<with_ $fn_name>](mut self, value: <$value_type as ::core::ops::Deref>::Target) -> Self {
+                self.$field_name = Some($crate::field::pointer::SharedPointer::new_shared(value));
+                self
+            }
+        }
+    };
+    // Case (24) without *field name*: `viz name => optional shared Type`
+    ($fn_vis:vis $name:ident => optional shared $value_type:ty) => {
+        $crate::with!($fn_vis $name => $name, optional shared $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (25) Base case with *cow*: `viz name => field_name, cow 'a Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, cow $lifetime:lifetime $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the clone-on-write field `" $fn_name "` within this structure, "
+                    "usually during construction, from either a borrowed or an owned value. This function takes "
+                    "a mutable `self` parameter and returns `Self` allowing it to be chained during construction."]
+            $fn_vis fn [<with_ $fn_name>]<T: Into<::std::borrow::Cow<$lifetime, $value_type>>>(mut self, value: T) -> Self {
+                self.$field_name = value.into();
+                self
+            }
+        }
+    };
+    // Case (25) without *field name*: `viz name => cow 'a Type`
+    ($fn_vis:vis $name:ident => cow $lifetime:lifetime $value_type:ty) => {
+        $crate::with!($fn_vis $name => $name, cow $lifetime $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // The constraint cases without *field name* must follow all those with *field name*, the
+    // trailing constraint tokens would otherwise match the delegated form's keywords.
+    // Case (17) without *field name*: `viz name => Type, constraint`
+    ($fn_vis:vis $name:ident => $value_type:ty, $($constraint:tt)+) => {
+        $crate::with!($fn_vis $name => $name, $value_type, $($constraint)+);
+    };
+    // Case (18) without *field name*: `viz name => into Type, constraint`
+    ($fn_vis:vis $name:ident => into $value_type:ty, $($constraint:tt)+) => {
+        $crate::with!($fn_vis $name => $name, into $value_type, $($constraint)+);
+    };
+    // Case (19) without *field name*: `viz name => optional Type, constraint`
+    ($fn_vis:vis $name:ident => optional $value_type:ty, $($constraint:tt)+) => {
+        $crate::with!($fn_vis $name => $name, optional $value_type, $($constraint)+);
+    };
+    // Case (20) without *field name*: `viz name => optional into Type, constraint`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty, $($constraint:tt)+) => {
+        $crate::with!($fn_vis $name => $name, optional into $value_type, $($constraint)+);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Take
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that moves the value out of a struct field.
+///
+/// ## Forms
+///
+/// ### `take!(viz name => [field_name,] Type)`
+///
+/// This form generates a function that returns the current value of the field, leaving the
+/// default value of `Type` in its place.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the generated function is the value type `Type`, assuming `Type` implements
+///   `Default`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_1: String, }
+/// impl Address {
+///     // take!(pub street_1 => String);
+///
+///     /// Takes the value of the field `street_1` out of this structure, leaving the
+///     /// default value in its place.
+///     pub fn take_street_1(&mut self) -> String {
+///         std::mem::take(&mut self.street_1)
+///     }
+/// }
+/// ```
+///
+/// ### `take!(viz name => [field_name,] optional Type)`
+///
+/// This form generates a function that returns the current value of an optional field,
+/// leaving `None` in its place.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the generated function is the value type `Option<Type>`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_2: Option<String>, }
+/// impl Address {
+///     // take!(pub street_2 => optional String);
+///
+///     /// Takes the value of the optional field `street_2` out of this structure, leaving
+///     /// `None` in its place.
+///     pub fn take_street_2(&mut self) -> Option<String> {
+///         self.street_2.take()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! take {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Takes the value of the field `" $fn_name "` out of this structure, leaving the "
+                    "default value in its place."]
+            $fn_vis fn [<take_ $fn_name>](&mut self) -> $value_type {
+                ::core::mem::take(&mut self.$field_name)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::take!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *optional*: `viz name => field_name, optional Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Takes the value of the optional field `" $fn_name "` out of this structure, "
+                    "leaving `None` in its place."]
+            $fn_vis fn [<take_ $fn_name>](&mut self) -> Option<$value_type> {
+                self.$field_name.take()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => optional Type`
+    ($fn_vis:vis $name:ident => optional $value_type:ty) => {
+        $crate::take!($fn_vis $name => $name, optional $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Replace
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that replaces the value of a struct field, returning the old value.
+///
+/// ## Forms
+///
+/// ### `replace!(viz name => [field_name,] [into] Type)`
+///
+/// This form generates a function that sets the value of the field and returns the value
+/// it previously held.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * If the keyword `into` is specified the type of the new value parameter is the trait-bound
+///   type `T: Into<Type>` rather than `Type` for flexibility.
+/// * The type of the generated function is the value type `Type`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_1: String, }
+/// impl Address {
+///     // replace!(pub street_1 => into String);
+///
+///     /// Set the value of the field `street_1` within this structure, returning the
+///     /// previous value.
+///     pub fn replace_street_1<T: Into<String>>(&mut self, value: T) -> String {
+///         std::mem::replace(&mut self.street_1, value.into())
+///     }
+/// }
+/// ```
+///
+/// ### `replace!(viz name => [field_name,] optional [into] Type)`
+///
+/// This form generates a function that sets the value of an optional field to `Some(value)`
+/// and returns the value it previously held.
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * If the keyword `into` is specified the type of the new value parameter is the trait-bound
+///   type `T: Into<Type>` rather than `Type` for flexibility.
+/// * The type of the generated function is the value type `Option<Type>`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_2: Option<String>, }
+/// impl Address {
+///     // replace!(pub street_2 => optional String);
+///
+///     /// Set the value of the optional field `street_2` within this structure to
+///     /// `Some(value)`, returning the previous value.
+///     pub fn replace_street_2(&mut self, value: String) -> Option<String> {
+///         self.street_2.replace(value)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! replace {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, returning the "
+                    "previous value."]
+            $fn_vis fn [<replace_ $fn_name>](&mut self, value: $value_type) -> $value_type {
+                ::core::mem::replace(&mut self.$field_name, value)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::replace!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the field `" $fn_name "` within this structure, returning the "
+                    "previous value."]
+            $fn_vis fn [<replace_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) -> $value_type {
+                ::core::mem::replace(&mut self.$field_name, value.into())
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::replace!($fn_vis $name => $name, into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (3) Base case with *optional*: `viz name => field_name, optional Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the optional field `" $fn_name "` within this structure to "
+                    "`Some(value)`, returning the previous value."]
+            $fn_vis fn [<replace_ $fn_name>](&mut self, value: $value_type) -> Option<$value_type> {
+                self.$field_name.replace(value)
+            }
+        }
+    };
+    // Case (3) without *field name*: `viz name => optional Type`
+    ($fn_vis:vis $name:ident => optional $value_type:ty) => {
+        $crate::replace!($fn_vis $name => $name, optional $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (4) Case (3) with *into*: `viz name => field_name, optional into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the optional field `" $fn_name "` within this structure to "
+                    "`Some(value)`, returning the previous value."]
+            $fn_vis fn [<replace_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) -> Option<$value_type> {
+                self.$field_name.replace(value.into())
+            }
+        }
+    };
+    // Case (4) without *field name*: `viz name => optional into Type`
+    ($fn_vis:vis $name:ident => optional into $value_type:ty) => {
+        $crate::replace!($fn_vis $name => $name, optional into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Swap
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that swaps the value of a struct field with the same field in another
+/// instance of the structure.
+///
+/// ## Forms
+///
+/// ### `swap!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`,
+///   as well as a mutable reference to the other instance.
+/// * As no values are created or returned this form works for optional and non-optional
+///   fields alike.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_1: String, }
+/// impl Address {
+///     // swap!(pub street_1);
+///
+///     /// Swaps the value of the field `street_1` within this structure with the value
+///     /// of the same field in `other`.
+///     pub fn swap_street_1(&mut self, other: &mut Self) {
+///         std::mem::swap(&mut self.street_1, &mut other.street_1)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! swap {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Swaps the value of the field `" $fn_name "` within this structure with the "
+                    "value of the same field in `other`."]
+            $fn_vis fn [<swap_ $fn_name>](&mut self, other: &mut Self) {
+                ::core::mem::swap(&mut self.$field_name, &mut other.$field_name)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::swap!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Update
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate closure-based methods that modify the value of a struct field.
+///
+/// ## Forms
+///
+/// ### `update!(viz name => [field_name,] Type)`
+///
+/// This form generates two functions, `update_name` which modifies the field in place and
+/// `map_name` which is a builder-style function that replaces the field's value with the
+/// result of a closure.
+///
+/// * In this form `name` is used to name the generated functions, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The function `update_name` requires mutability in the form of a mutable reference to
+///   self; `&mut self`.
+/// * The function `map_name` requires mutability in the form of a `mut self`, and returns
+///   `Self`, so that it can be chained during construction. As the field's value is moved into
+///   the closure the structure may not implement `Drop`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_1: String, }
+/// impl Address {
+///     // update!(pub street_1 => String);
+///
+///     /// Modify the value of the field `street_1` within this structure in place, using
+///     /// the closure `f`.
+///     pub fn update_street_1<F: FnOnce(&mut String)>(&mut self, f: F) {
+///         f(&mut self.street_1)
+///     }
+///
+///     /// Replace the value of the field `street_1` within this structure with the result
+///     /// of the closure `f`. This function takes a mutable `self` parameter and returns
+///     /// `Self` allowing it to be chained during construction.
+///     pub fn map_street_1<F: FnOnce(String) -> String>(mut self, f: F) -> Self {
+///         self.street_1 = f(self.street_1);
+///         self
+///     }
+/// }
+/// ```
+///
+/// ### `update!(viz name => [field_name,] optional Type)`
+///
+/// This form generates three functions for an optional field; `update_name_if_set` which
+/// modifies the field's value in place if it is present, `get_or_insert_name_with` which
+/// returns a mutable reference to the field's value, initializing it with the result of a
+/// closure if it is not present, and `map_name` as above which is only called if the
+/// field's value is present.
+///
+/// * In this form `name` is used to name the generated functions, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_2: Option<String>, }
+/// impl Address {
+///     // update!(pub street_2 => optional String);
+///
+///     /// Modify the value of the optional field `street_2` within this structure in place,
+///     /// using the closure `f`, if the field has a value.
+///     pub fn update_street_2_if_set<F: FnOnce(&mut String)>(&mut self, f: F) {
+///         if let Some(value) = self.street_2.as_mut() {
+///             f(value)
+///         }
+///     }
+///
+///     /// Returns a mutable reference to the value of the optional field `street_2` within
+///     /// this structure, first setting it to the result of the closure `f` if it has no value.
+///     pub fn get_or_insert_street_2_with<F: FnOnce() -> String>(&mut self, f: F) -> &mut String {
+///         self.street_2.get_or_insert_with(f)
+///     }
+///
+///     /// Replace the value of the optional field `street_2` within this structure with the
+///     /// result of the closure `f`, if the field has a value. This function takes a mutable
+///     /// `self` parameter and returns `Self` allowing it to be chained during construction.
+///     pub fn map_street_2<F: FnOnce(String) -> String>(mut self, f: F) -> Self {
+///         self.street_2 = self.street_2.map(f);
+///         self
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! update {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Modify the value of the field `" $fn_name "` within this structure in place, "
+                    "using the closure `f`."]
+            $fn_vis fn [<update_ $fn_name>]<F: FnOnce(&mut $value_type)>(&mut self, f: F) {
+                f(&mut self.$field_name)
+            }
+
+            #[doc = "Replace the value of the field `" $fn_name "` within this structure with the "
+                    "result of the closure `f`. This function takes a mutable `self` parameter and "
+                    "returns `Self` allowing it to be chained during construction."]
+            $fn_vis fn [<map_ $fn_name>]<F: FnOnce($value_type) -> $value_type>(mut self, f: F) -> Self {
+                self.$field_name = f(self.$field_name);
+                self
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::update!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *optional*: `viz name => field_name, optional Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, optional $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Modify the value of the optional field `" $fn_name "` within this structure in "
+                    "place, using the closure `f`, if the field has a value."]
+            $fn_vis fn [<update_ $fn_name _if_set>]<F: FnOnce(&mut $value_type)>(&mut self, f: F) {
+                if let Some(value) = self.$field_name.as_mut() {
+                    f(value)
+                }
+            }
+
+            #[doc = "Returns a mutable reference to the value of the optional field `" $fn_name "` "
+                    "within this structure, first setting it to the result of the closure `f` if it "
+                    "has no value."]
+            $fn_vis fn [<get_or_insert_ $fn_name _with>]<F: FnOnce() -> $value_type>(&mut self, f: F) -> &mut $value_type {
+                self.$field_name.get_or_insert_with(f)
+            }
+
+            #[doc = "Replace the value of the optional field `" $fn_name "` within this structure with "
+                    "the result of the closure `f`, if the field has a value. This function takes a "
+                    "mutable `self` parameter and returns `Self` allowing it to be chained during "
+                    "construction."]
+            $fn_vis fn [<map_ $fn_name>]<F: FnOnce($value_type) -> $value_type>(mut self, f: F) -> Self {
+                self.$field_name = self.$field_name.map(f);
+                self
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => optional Type`
+    ($fn_vis:vis $name:ident => optional $value_type:ty) => {
+        $crate::update!($fn_vis $name => $name, optional $value_type);
     };
 }
 
@@ -958,4 +3330,4 @@ macro_rules! with {
 // Re-export macros
 // ------------------------------------------------------------------------------------------------
 
-pub use crate::{get, get_mut, set, unset, with};
+pub use crate::{get, get_mut, replace, set, swap, take, unset, update, with};
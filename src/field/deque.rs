@@ -0,0 +1,514 @@
+/*!
+ * The set of macros for struct fields backed by `VecDeque<T>`.
+ *
+ * In all of these macros the `Type` given is the type of the deque's elements, `T`, and
+ * *not* the type of the field itself, `VecDeque<T>`.
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * use std::collections::VecDeque;
+ *
+ * pub struct Courier {
+ *     pending: VecDeque<String>, // demonstrates keyword 'into'
+ *     stops: VecDeque<u32>,      // demonstrates keyword 'copy'
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro               | field name | keywords | type   | generated signature                                          |
+ * |---------------------|------------|----------|--------|--------------------------------------------------------------|
+ * | `deque_push_front!` | pending    |          | String | `fn pending_push_front(&mut self, value: String)`            |
+ * | `deque_push_front!` | pending    | into     | String | `fn pending_push_front<T: Into<String>>(&mut self, value: T)` |
+ * | `deque_push_back!`  | pending    |          | String | `fn pending_push_back(&mut self, value: String)`             |
+ * | `deque_push_back!`  | pending    | into     | String | `fn pending_push_back<T: Into<String>>(&mut self, value: T)` |
+ * | `deque_pop_front!`  | pending    |          | String | `fn pending_pop_front(&mut self) -> Option<String>`          |
+ * | `deque_pop_back!`   | pending    |          | String | `fn pending_pop_back(&mut self) -> Option<String>`           |
+ * | `deque_front!`      | pending    |          | String | `fn pending_front(&self) -> Option<&String>`                 |
+ * | `deque_front!`      | stops      | copy     | u32    | `fn stops_front(&self) -> Option<u32>`                       |
+ * | `deque_back!`       | pending    |          | String | `fn pending_back(&self) -> Option<&String>`                  |
+ * | `deque_back!`       | stops      | copy     | u32    | `fn stops_back(&self) -> Option<u32>`                        |
+ * | `deque_len!`        | pending    |          |        | `fn pending_len(&self) -> usize`                             |
+ * | `deque_iter!`       | pending    |          | String | `fn pending_iter(&self) -> impl Iterator<Item = &String>`    |
+ * | `deque_iter!`       | stops      | copy     | u32    | `fn stops_iter(&self) -> impl Iterator<Item = u32> + '_`     |
+ *
+ */
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Deque <name>_push_front
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that prepends an element to the front of a deque field.
+///
+/// ## Forms
+///
+/// ### `deque_push_front!(viz name => [field_name,] [into] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * If the keyword `into` is specified the type of the new value parameter is the trait-bound
+///   type `T: Into<Type>` rather than `Type` for flexibility.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::VecDeque;
+/// # pub struct Courier { pending: VecDeque<String> }
+/// impl Courier {
+///     // deque_push_front!(pub pending => into String);
+///
+///     /// Prepends `value` to the front of the deque field `pending`.
+///     pub fn pending_push_front<T: Into<String>>(&mut self, value: T) {
+///         self.pending.push_front(value.into());
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! deque_push_front {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Prepends `value` to the front of the deque field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _push_front>](&mut self, value: $value_type) {
+                self.$field_name.push_front(value);
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::deque_push_front!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Prepends `value` to the front of the deque field `" $fn_name "` (Using `Into<" $value_type ">`)."]
+            $fn_vis fn [<$fn_name _push_front>]<T: Into<$value_type>>(&mut self, value: T) {
+                self.$field_name.push_front(value.into());
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::deque_push_front!($fn_vis $name => $name, into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Deque <name>_push_back
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that appends an element to the back of a deque field.
+///
+/// ## Forms
+///
+/// ### `deque_push_back!(viz name => [field_name,] [into] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * If the keyword `into` is specified the type of the new value parameter is the trait-bound
+///   type `T: Into<Type>` rather than `Type` for flexibility.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::VecDeque;
+/// # pub struct Courier { pending: VecDeque<String> }
+/// impl Courier {
+///     // deque_push_back!(pub pending => String);
+///
+///     /// Appends `value` to the back of the deque field `pending`.
+///     pub fn pending_push_back(&mut self, value: String) {
+///         self.pending.push_back(value);
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! deque_push_back {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Appends `value` to the back of the deque field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _push_back>](&mut self, value: $value_type) {
+                self.$field_name.push_back(value);
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::deque_push_back!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Appends `value` to the back of the deque field `" $fn_name "` (Using `Into<" $value_type ">`)."]
+            $fn_vis fn [<$fn_name _push_back>]<T: Into<$value_type>>(&mut self, value: T) {
+                self.$field_name.push_back(value.into());
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::deque_push_back!($fn_vis $name => $name, into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Deque <name>_pop_front
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that removes, and returns, the first element of a deque field.
+///
+/// ## Forms
+///
+/// ### `deque_pop_front!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the generated function is `Option<Type>`, it is `None` if the deque is empty.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::VecDeque;
+/// # pub struct Courier { pending: VecDeque<String> }
+/// impl Courier {
+///     // deque_pop_front!(pub pending => String);
+///
+///     /// Removes the first element from the deque field `pending` and returns it,
+///     /// or `None` if it is empty.
+///     pub fn pending_pop_front(&mut self) -> Option<String> {
+///         self.pending.pop_front()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! deque_pop_front {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Removes the first element from the deque field `" $fn_name "` and returns it, "
+                    "or `None` if it is empty."]
+            $fn_vis fn [<$fn_name _pop_front>](&mut self) -> Option<$value_type> {
+                self.$field_name.pop_front()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::deque_pop_front!($fn_vis $name => $name, $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Deque <name>_pop_back
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that removes, and returns, the last element of a deque field.
+///
+/// ## Forms
+///
+/// ### `deque_pop_back!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the generated function is `Option<Type>`, it is `None` if the deque is empty.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::VecDeque;
+/// # pub struct Courier { pending: VecDeque<String> }
+/// impl Courier {
+///     // deque_pop_back!(pub pending => String);
+///
+///     /// Removes the last element from the deque field `pending` and returns it,
+///     /// or `None` if it is empty.
+///     pub fn pending_pop_back(&mut self) -> Option<String> {
+///         self.pending.pop_back()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! deque_pop_back {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Removes the last element from the deque field `" $fn_name "` and returns it, "
+                    "or `None` if it is empty."]
+            $fn_vis fn [<$fn_name _pop_back>](&mut self) -> Option<$value_type> {
+                self.$field_name.pop_back()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::deque_pop_back!($fn_vis $name => $name, $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Deque <name>_front
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that returns the first element of a deque field.
+///
+/// ## Forms
+///
+/// ### `deque_front!(viz name => [field_name,] [copy] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is the reference `Option<&Type>`, or if `copy` is
+///   specified the value `Option<Type>`; it is `None` if the deque is empty.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::VecDeque;
+/// # pub struct Courier { pending: VecDeque<String> }
+/// impl Courier {
+///     // deque_front!(pub pending => String);
+///
+///     /// Returns a reference to the first element of the deque field `pending`, or `None`
+///     /// if it is empty.
+///     pub fn pending_front(&self) -> Option<&String> {
+///         self.pending.front()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! deque_front {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a reference to the first element of the deque field `" $fn_name
+                    "`, or `None` if it is empty."]
+            $fn_vis fn [<$fn_name _front>](&self) -> Option<&$value_type> {
+                self.$field_name.front()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::deque_front!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *copy*: `viz name => field_name, copy Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a copy of the first element of the deque field `" $fn_name
+                    "`, or `None` if it is empty."]
+            $fn_vis fn [<$fn_name _front>](&self) -> Option<$value_type> {
+                self.$field_name.front().copied()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => copy Type`
+    ($fn_vis:vis $name:ident => copy $value_type:ty) => {
+        $crate::deque_front!($fn_vis $name => $name, copy $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Deque <name>_back
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that returns the last element of a deque field.
+///
+/// ## Forms
+///
+/// ### `deque_back!(viz name => [field_name,] [copy] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is the reference `Option<&Type>`, or if `copy` is
+///   specified the value `Option<Type>`; it is `None` if the deque is empty.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::VecDeque;
+/// # pub struct Courier { stops: VecDeque<u32> }
+/// impl Courier {
+///     // deque_back!(pub stops => copy u32);
+///
+///     /// Returns a copy of the last element of the deque field `stops`, or `None`
+///     /// if it is empty.
+///     pub fn stops_back(&self) -> Option<u32> {
+///         self.stops.back().copied()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! deque_back {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a reference to the last element of the deque field `" $fn_name
+                    "`, or `None` if it is empty."]
+            $fn_vis fn [<$fn_name _back>](&self) -> Option<&$value_type> {
+                self.$field_name.back()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::deque_back!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *copy*: `viz name => field_name, copy Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a copy of the last element of the deque field `" $fn_name
+                    "`, or `None` if it is empty."]
+            $fn_vis fn [<$fn_name _back>](&self) -> Option<$value_type> {
+                self.$field_name.back().copied()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => copy Type`
+    ($fn_vis:vis $name:ident => copy $value_type:ty) => {
+        $crate::deque_back!($fn_vis $name => $name, copy $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Deque <name>_len
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that returns the number of elements in a deque field.
+///
+/// ## Forms
+///
+/// ### `deque_len!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::VecDeque;
+/// # pub struct Courier { pending: VecDeque<String> }
+/// impl Courier {
+///     // deque_len!(pub pending);
+///
+///     /// Returns the number of elements in the deque field `pending`.
+///     pub fn pending_len(&self) -> usize {
+///         self.pending.len()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! deque_len {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Returns the number of elements in the deque field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _len>](&self) -> usize {
+                self.$field_name.len()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::deque_len!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Deque <name>_iter
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that returns a front-to-back iterator over the elements of a deque field.
+///
+/// ## Forms
+///
+/// ### `deque_iter!(viz name => [field_name,] [copy] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is an iterator over immutable references `&Type`,
+///   or if `copy` is specified, over copies `Type`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::VecDeque;
+/// # pub struct Courier { pending: VecDeque<String> }
+/// impl Courier {
+///     // deque_iter!(pub pending => String);
+///
+///     /// Returns a front-to-back iterator over references to the elements of the deque
+///     /// field `pending`.
+///     pub fn pending_iter(&self) -> impl Iterator<Item = &String> {
+///         self.pending.iter()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! deque_iter {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a front-to-back iterator over references to the elements of the deque field `"
+                    $fn_name "`."]
+            $fn_vis fn [<$fn_name _iter>](&self) -> impl Iterator<Item = &$value_type> {
+                self.$field_name.iter()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::deque_iter!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *copy*: `viz name => field_name, copy Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a front-to-back iterator over copies of the elements of the deque field `"
+                    $fn_name "`."]
+            $fn_vis fn [<$fn_name _iter>](&self) -> impl Iterator<Item = $value_type> + '_ {
+                self.$field_name.iter().copied()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => copy Type`
+    ($fn_vis:vis $name:ident => copy $value_type:ty) => {
+        $crate::deque_iter!($fn_vis $name => $name, copy $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::{
+    deque_back, deque_front, deque_iter, deque_len, deque_pop_back, deque_pop_front,
+    deque_push_back, deque_push_front,
+};
@@ -0,0 +1,316 @@
+/*!
+ * The set of macros for struct fields of type `bool`, commonly used as flags.
+ *
+ * None of these macros take a `Type` as the field is always a `bool`, and the generated
+ * methods are named with a prefix rather than a suffix, following the common Rust idiom of
+ * `is_name` for boolean predicates.
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * pub struct Account {
+ *     active: bool,
+ *     locked: bool,
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro           | field name | generated signature                     |
+ * |-----------------|------------|-----------------------------------------|
+ * | `flag_is!`      | active     | `const fn is_active(&self) -> bool`     |
+ * | `flag_set!`     | active     | `fn set_active(&mut self, value: bool)` |
+ * | `flag_enable!`  | active     | `fn enable_active(&mut self)`           |
+ * | `flag_disable!` | active     | `fn disable_active(&mut self)`          |
+ * | `flag_toggle!`  | active     | `fn toggle_active(&mut self)`           |
+ * | `flag_with!`    | active     | `fn with_active(mut self) -> Self`      |
+ *
+ * The combinator macro [`flag`](crate::flag) generates all of the above for a single field.
+ *
+ */
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Flag is_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a predicate method that returns the value of a boolean field.
+///
+/// ## Forms
+///
+/// ### `flag_is!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Account { active: bool }
+/// impl Account {
+///     // flag_is!(pub active);
+///
+///     /// Returns `true` if the flag field `active` within this structure is set.
+///     pub const fn is_active(&self) -> bool {
+///         self.active
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! flag_is {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Returns `true` if the flag field `" $fn_name "` within this structure is set."]
+            $fn_vis const fn [<is_ $fn_name>](&self) -> bool {
+                self.$field_name
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::flag_is!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Flag set_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a setter method that sets a boolean field to the given value.
+///
+/// ## Forms
+///
+/// ### `flag_set!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Account { active: bool }
+/// impl Account {
+///     // flag_set!(pub active);
+///
+///     /// Set the value of the flag field `active` within this structure.
+///     pub fn set_active(&mut self, value: bool) {
+///         self.active = value;
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! flag_set {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Set the value of the flag field `" $fn_name "` within this structure."]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: bool) {
+                self.$field_name = value;
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::flag_set!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Flag enable_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that sets a boolean field to `true`.
+///
+/// ## Forms
+///
+/// ### `flag_enable!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Account { active: bool }
+/// impl Account {
+///     // flag_enable!(pub active);
+///
+///     /// Set the flag field `active` within this structure to `true`.
+///     pub fn enable_active(&mut self) {
+///         self.active = true;
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! flag_enable {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Set the flag field `" $fn_name "` within this structure to `true`."]
+            $fn_vis fn [<enable_ $fn_name>](&mut self) {
+                self.$field_name = true;
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::flag_enable!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Flag disable_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that sets a boolean field to `false`.
+///
+/// ## Forms
+///
+/// ### `flag_disable!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Account { active: bool }
+/// impl Account {
+///     // flag_disable!(pub active);
+///
+///     /// Set the flag field `active` within this structure to `false`.
+///     pub fn disable_active(&mut self) {
+///         self.active = false;
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! flag_disable {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Set the flag field `" $fn_name "` within this structure to `false`."]
+            $fn_vis fn [<disable_ $fn_name>](&mut self) {
+                self.$field_name = false;
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::flag_disable!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Flag toggle_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that inverts the value of a boolean field.
+///
+/// ## Forms
+///
+/// ### `flag_toggle!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Account { active: bool }
+/// impl Account {
+///     // flag_toggle!(pub active);
+///
+///     /// Invert the value of the flag field `active` within this structure.
+///     pub fn toggle_active(&mut self) {
+///         self.active = !self.active;
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! flag_toggle {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Invert the value of the flag field `" $fn_name "` within this structure."]
+            $fn_vis fn [<toggle_ $fn_name>](&mut self) {
+                self.$field_name = !self.$field_name;
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::flag_toggle!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Flag with_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate an initializer method that sets a boolean field to `true`.
+///
+/// ## Forms
+///
+/// ### `flag_with!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form takes ownership of, and returns, `self` so that it may be chained with other
+///   initializers.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Account { active: bool }
+/// impl Account {
+///     // flag_with!(pub active);
+///
+///     /// An initializer that sets the flag field `active` within this structure to `true`.
+///     pub fn with_active(mut self) -> Self {
+///         self.active = true;
+///         self
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! flag_with {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "An initializer that sets the flag field `" $fn_name "` within this structure to `true`."]
+            $fn_vis fn [<with_ $fn_name>](mut self) -> Self {
+                self.$field_name = true;
+                self
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::flag_with!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::{flag_disable, flag_enable, flag_is, flag_set, flag_toggle, flag_with};
@@ -0,0 +1,169 @@
+/*!
+ * The [`lazy_get`] macro, for computed values cached in a `OnceCell<T>` or `OnceLock<T>`
+ * struct field.
+ *
+ * The value is computed by an initializer function on first access and cached until the
+ * field is invalidated. The setters generated by [`set`] and the initializers generated by
+ * [`with`] accept an `invalidates` clause, naming the lazy fields derived from the field
+ * being set, so that the cached values are invalidated whenever their inputs change.
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * use std::cell::OnceCell;
+ *
+ * pub struct Packet {
+ *     payload: Vec<u8>,
+ *     checksum: OnceCell<u64>,
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro       | field name | keywords             | type            | generated signature                         |
+ * |-------------|------------|----------------------|-----------------|---------------------------------------------|
+ * | `lazy_get!` | checksum   |                      | `OnceCell<u64>` | `fn checksum(&self) -> &u64`                |
+ * | `lazy_get!` | checksum   |                      | `OnceCell<u64>` | `fn invalidate_checksum(&mut self)`         |
+ * | `set!`      | payload    | invalidates checksum | `Vec<u8>`       | `fn set_payload(&mut self, value: Vec<u8>)` |
+ *
+ */
+
+use std::cell::OnceCell;
+use std::sync::OnceLock;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implemented by the cell types that may be used with [`lazy_get`]; this allows the generated
+/// accessors to name, compute, and invalidate the cached value without knowing whether the
+/// field is a `OnceCell` or a `OnceLock`.
+///
+pub trait OnceCache {
+    /// The type of the cached value.
+    type Value;
+
+    /// Returns the cached value, first computing it with `f` if the cell is empty.
+    fn get_or_init<F: FnOnce() -> Self::Value>(&self, f: F) -> &Self::Value;
+
+    /// Discards, and returns, the cached value if any.
+    fn take(&mut self) -> Option<Self::Value>;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Lazy
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a getter method that computes, and caches, the value of a `OnceCell<T>` or
+/// `OnceLock<T>` struct field on first access, and a method to invalidate the cached value.
+///
+/// ## Forms
+///
+/// ### `lazy_get!(viz name => [field_name,] CellType, initializer)`
+///
+/// * In this form `name` is used to name the generated functions, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * `CellType` is the field's type, `OnceCell<Type>`, or `OnceLock<Type>` for a field that
+///   is shared between threads; it may be written as a path, such as
+///   `std::cell::OnceCell<Type>`, or as a type alias. The value's type, `Type`, is taken from
+///   the cell's implementation of [`OnceCache`].
+/// * `initializer` is the path to a function with the signature `fn(&Self) -> Type`, usually
+///   a method such as `Self::compute_name`; it must not call the generated getter, as the
+///   value is not yet initialized.
+/// * The getter function only requires a shared reference to self; `&self`, and returns the
+///   reference `&Type`.
+/// * The function `invalidate_name` requires mutability in the form of a mutable reference to
+///   self; `&mut self`, and discards the cached value so that it is computed again on the next
+///   access.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::cell::OnceCell;
+/// # pub struct Packet { payload: Vec<u8>, checksum: OnceCell<u64> }
+/// impl Packet {
+///     // lazy_get!(pub checksum => OnceCell<u64>, Self::compute_checksum);
+///
+///     /// Returns the value of the lazily computed field `checksum`, which is computed by its
+///     /// initializer on first access.
+///     pub fn checksum(&self) -> &u64 {
+///         self.checksum.get_or_init(|| Self::compute_checksum(self))
+///     }
+///
+///     /// Invalidate the lazily computed field `checksum`, so that it is computed again on
+///     /// the next access.
+///     pub fn invalidate_checksum(&mut self) {
+///         let _ = self.checksum.take();
+///     }
+///
+///     fn compute_checksum(&self) -> u64 {
+///         self.payload.iter().map(|byte| *byte as u64).sum()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! lazy_get {
+    // Base case: `viz name => field_name, CellType, initializer`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $cell_type:ty, $($initializer:ident)::+) => {
+        paste::paste! {
+            #[doc = "Returns the value of the lazily computed field `" $fn_name "`, which is computed by its "
+                    "initializer on first access."]
+            $fn_vis fn $fn_name(&self) -> &<$cell_type as $crate::field::lazy::OnceCache>::Value {
+                $crate::field::lazy::OnceCache::get_or_init(&self.$field_name, || $($initializer)::+(self))
+            }
+
+            #[doc = "Invalidate the lazily computed field `" $fn_name "`, so that it is computed again "
+                    "on the next access."]
+            $fn_vis fn [<invalidate_ $fn_name>](&mut self) {
+                let _ = $crate::field::lazy::OnceCache::take(&mut self.$field_name);
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => CellType, initializer`
+    ($fn_vis:vis $name:ident => $cell_type:ty, $($initializer:ident)::+) => {
+        $crate::lazy_get!($fn_vis $name => $name, $cell_type, $($initializer)::+);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ OnceCache
+// ------------------------------------------------------------------------------------------------
+
+impl<T> OnceCache for OnceCell<T> {
+    type Value = T;
+
+    #[inline(always)]
+    fn get_or_init<F: FnOnce() -> T>(&self, f: F) -> &T {
+        OnceCell::get_or_init(self, f)
+    }
+
+    #[inline(always)]
+    fn take(&mut self) -> Option<T> {
+        OnceCell::take(self)
+    }
+}
+
+impl<T> OnceCache for OnceLock<T> {
+    type Value = T;
+
+    #[inline(always)]
+    fn get_or_init<F: FnOnce() -> T>(&self, f: F) -> &T {
+        OnceLock::get_or_init(self, f)
+    }
+
+    #[inline(always)]
+    fn take(&mut self) -> Option<T> {
+        OnceLock::take(self)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::lazy_get;
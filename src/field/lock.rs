@@ -0,0 +1,658 @@
+/*!
+ * The set of macros for struct fields guarded by a lock, backed by `Mutex<T>` or `RwLock<T>`.
+ *
+ * In all of these macros the `Type` given is the type of the lock's content, `T`, and *not*
+ * the type of the field itself. All of the generated methods take a shared reference to self,
+ * `&self`, so that they may be used on shared state such as the inner value of an `Arc`.
+ *
+ * By default the generated methods panic, with a message naming the field, if the lock is
+ * poisoned. If the keyword `poison` is specified the generated methods instead return the
+ * `PoisonError` to the caller.
+ *
+ * The closure helpers, such as `with_name_locked`, hold the lock only for the duration of the
+ * closure and so never leak a guard to the caller.
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * use std::sync::{Mutex, RwLock};
+ *
+ * pub struct Inner {
+ *     sessions: Mutex<Vec<u32>>,
+ *     config: RwLock<String>,
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro                | field name | keywords | type       | generated signature                                                                       |
+ * |----------------------|------------|----------|------------|-------------------------------------------------------------------------------------------|
+ * | `mutex_lock!`        | sessions   |          | `Vec<u32>` | `fn lock_sessions(&self) -> MutexGuard<'_, Vec<u32>>`                                     |
+ * | `mutex_lock!`        | sessions   | poison   | `Vec<u32>` | `fn lock_sessions(&self) -> LockResult<MutexGuard<'_, Vec<u32>>>`                         |
+ * | `mutex_try_lock!`    | sessions   |          | `Vec<u32>` | `fn try_lock_sessions(&self) -> Option<MutexGuard<'_, Vec<u32>>>`                         |
+ * | `mutex_try_lock!`    | sessions   | poison   | `Vec<u32>` | `fn try_lock_sessions(&self) -> TryLockResult<MutexGuard<'_, Vec<u32>>>`                  |
+ * | `mutex_with_locked!` | sessions   |          | `Vec<u32>` | `fn with_sessions_locked<R, F: FnOnce(&mut Vec<u32>) -> R>(&self, f: F) -> R`             |
+ * | `mutex_with_locked!` | sessions   | poison   | `Vec<u32>` | `fn with_sessions_locked<R, F: FnOnce(&mut Vec<u32>) -> R>(&self, f: F) -> LockResult<R>` |
+ * | `rwlock_read!`       | config     |          | String     | `fn read_config(&self) -> RwLockReadGuard<'_, String>`                                    |
+ * | `rwlock_write!`      | config     |          | String     | `fn write_config(&self) -> RwLockWriteGuard<'_, String>`                                  |
+ * | `rwlock_with_read!`  | config     |          | String     | `fn with_config_read<R, F: FnOnce(&String) -> R>(&self, f: F) -> R`                       |
+ * | `rwlock_with_write!` | config     |          | String     | `fn with_config_write<R, F: FnOnce(&mut String) -> R>(&self, f: F) -> R`                  |
+ *
+ * The keyword `poison` is accepted by all of the `rwlock_*` macros in the same manner as the
+ * `mutex_*` macros.
+ *
+ */
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Mutex lock_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that locks a `Mutex<T>` field, returning the guard.
+///
+/// ## Forms
+///
+/// ### `mutex_lock!(viz name => [field_name,] [poison] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * If the keyword `poison` is specified the generated function returns the `PoisonError`,
+///   rather than panicking, if the lock is poisoned.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::Mutex;
+/// # pub struct Inner { sessions: Mutex<Vec<u32>> }
+/// impl Inner {
+///     // mutex_lock!(pub sessions => Vec<u32>);
+///
+///     /// Acquires the mutex field `sessions`, blocking until it is available.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If the mutex is poisoned.
+///     pub fn lock_sessions(&self) -> std::sync::MutexGuard<'_, Vec<u32>> {
+///         self.sessions
+///             .lock()
+///             .unwrap_or_else(|_| panic!("the mutex field `sessions` is poisoned"))
+///     }
+/// }
+/// ```
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::Mutex;
+/// # pub struct Inner { sessions: Mutex<Vec<u32>> }
+/// impl Inner {
+///     // mutex_lock!(pub sessions => poison Vec<u32>);
+///
+///     /// Acquires the mutex field `sessions`, blocking until it is available.
+///     pub fn lock_sessions(
+///         &self,
+///     ) -> std::sync::LockResult<std::sync::MutexGuard<'_, Vec<u32>>> {
+///         self.sessions.lock()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! mutex_lock {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Acquires the mutex field `" $fn_name "`, blocking until it is available.\n\n"
+                    "# Panics\n\nIf the mutex is poisoned."]
+            $fn_vis fn [<lock_ $fn_name>](&self) -> ::std::sync::MutexGuard<'_, $value_type> {
+                self.$field_name
+                    .lock()
+                    .unwrap_or_else(|_| panic!(concat!("the mutex field `", stringify!($fn_name), "` is poisoned")))
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::mutex_lock!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *poison*: `viz name => field_name, poison Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, poison $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Acquires the mutex field `" $fn_name "`, blocking until it is available."]
+            $fn_vis fn [<lock_ $fn_name>](&self) -> ::std::sync::LockResult<::std::sync::MutexGuard<'_, $value_type>> {
+                self.$field_name.lock()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => poison Type`
+    ($fn_vis:vis $name:ident => poison $value_type:ty) => {
+        $crate::mutex_lock!($fn_vis $name => $name, poison $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Mutex try_lock_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that attempts to lock a `Mutex<T>` field without blocking.
+///
+/// ## Forms
+///
+/// ### `mutex_try_lock!(viz name => [field_name,] [poison] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * The generated function returns `None` if the mutex is currently locked.
+/// * If the keyword `poison` is specified the generated function returns the `TryLockResult`
+///   from the mutex, rather than panicking if the lock is poisoned.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::Mutex;
+/// # pub struct Inner { sessions: Mutex<Vec<u32>> }
+/// impl Inner {
+///     // mutex_try_lock!(pub sessions => Vec<u32>);
+///
+///     /// Attempts to acquire the mutex field `sessions`, returning `None` if it is currently
+///     /// locked.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If the mutex is poisoned.
+///     pub fn try_lock_sessions(&self) -> Option<std::sync::MutexGuard<'_, Vec<u32>>> {
+///         match self.sessions.try_lock() {
+///             Ok(guard) => Some(guard),
+///             Err(std::sync::TryLockError::WouldBlock) => None,
+///             Err(std::sync::TryLockError::Poisoned(_)) => {
+///                 panic!("the mutex field `sessions` is poisoned")
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! mutex_try_lock {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Attempts to acquire the mutex field `" $fn_name "`, returning `None` if it is currently "
+                    "locked.\n\n# Panics\n\nIf the mutex is poisoned."]
+            $fn_vis fn [<try_lock_ $fn_name>](&self) -> Option<::std::sync::MutexGuard<'_, $value_type>> {
+                match self.$field_name.try_lock() {
+                    Ok(guard) => Some(guard),
+                    Err(::std::sync::TryLockError::WouldBlock) => None,
+                    Err(::std::sync::TryLockError::Poisoned(_)) => {
+                        panic!(concat!("the mutex field `", stringify!($fn_name), "` is poisoned"))
+                    }
+                }
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::mutex_try_lock!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *poison*: `viz name => field_name, poison Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, poison $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Attempts to acquire the mutex field `" $fn_name "` without blocking."]
+            $fn_vis fn [<try_lock_ $fn_name>](&self) -> ::std::sync::TryLockResult<::std::sync::MutexGuard<'_, $value_type>> {
+                self.$field_name.try_lock()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => poison Type`
+    ($fn_vis:vis $name:ident => poison $value_type:ty) => {
+        $crate::mutex_try_lock!($fn_vis $name => $name, poison $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Mutex with_<name>_locked
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that calls a closure with mutable access to the content of a `Mutex<T>`
+/// field, holding the lock only for the duration of the closure.
+///
+/// ## Forms
+///
+/// ### `mutex_with_locked!(viz name => [field_name,] [poison] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * The generated function returns the closure's result.
+/// * If the keyword `poison` is specified the generated function does not panic if the lock
+///   is poisoned; it calls the closure on the recovered content and returns its result
+///   within a `PoisonError`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::Mutex;
+/// # pub struct Inner { sessions: Mutex<Vec<u32>> }
+/// impl Inner {
+///     // mutex_with_locked!(pub sessions => Vec<u32>);
+///
+///     /// Calls `f` with mutable access to the content of the mutex field `sessions`, holding
+///     /// the lock only for the duration of the call.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If the mutex is poisoned.
+///     pub fn with_sessions_locked<R, F: FnOnce(&mut Vec<u32>) -> R>(&self, f: F) -> R {
+///         let mut guard = self
+///             .sessions
+///             .lock()
+///             .unwrap_or_else(|_| panic!("the mutex field `sessions` is poisoned"));
+///         f(&mut guard)
+///     }
+/// }
+/// ```
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::Mutex;
+/// # pub struct Inner { sessions: Mutex<Vec<u32>> }
+/// impl Inner {
+///     // mutex_with_locked!(pub sessions => poison Vec<u32>);
+///
+///     /// Calls `f` with mutable access to the content of the mutex field `sessions`, holding
+///     /// the lock only for the duration of the call.
+///     ///
+///     /// # Errors
+///     ///
+///     /// If the mutex is poisoned `f` is still called, on the recovered content, and its
+///     /// result is returned within the `PoisonError`.
+///     pub fn with_sessions_locked<R, F: FnOnce(&mut Vec<u32>) -> R>(
+///         &self,
+///         f: F,
+///     ) -> std::sync::LockResult<R> {
+///         match self.sessions.lock() {
+///             Ok(mut guard) => Ok(f(&mut guard)),
+///             Err(poisoned) => Err(std::sync::PoisonError::new(f(&mut poisoned.into_inner()))),
+///         }
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! mutex_with_locked {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Calls `f` with mutable access to the content of the mutex field `" $fn_name "`, holding "
+                    "the lock only for the duration of the call.\n\n# Panics\n\nIf the mutex is poisoned."]
+            $fn_vis fn [<with_ $fn_name _locked>]<R, F: FnOnce(&mut $value_type) -> R>(&self, f: F) -> R {
+                let mut guard = self
+                    .$field_name
+                    .lock()
+                    .unwrap_or_else(|_| panic!(concat!("the mutex field `", stringify!($fn_name), "` is poisoned")));
+                f(&mut guard)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::mutex_with_locked!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *poison*: `viz name => field_name, poison Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, poison $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Calls `f` with mutable access to the content of the mutex field `" $fn_name "`, holding "
+                    "the lock only for the duration of the call.\n\n# Errors\n\nIf the mutex is poisoned `f` is still called, on the recovered content, "
+                    "and its result is returned within the `PoisonError`."]
+            $fn_vis fn [<with_ $fn_name _locked>]<R, F: FnOnce(&mut $value_type) -> R>(&self, f: F) -> ::std::sync::LockResult<R> {
+                match self.$field_name.lock() {
+                    Ok(mut guard) => Ok(f(&mut guard)),
+                    Err(poisoned) => Err(::std::sync::PoisonError::new(f(&mut poisoned.into_inner()))),
+                }
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => poison Type`
+    ($fn_vis:vis $name:ident => poison $value_type:ty) => {
+        $crate::mutex_with_locked!($fn_vis $name => $name, poison $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ RwLock read_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that acquires shared read access to a `RwLock<T>` field, returning the
+/// guard.
+///
+/// ## Forms
+///
+/// ### `rwlock_read!(viz name => [field_name,] [poison] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * If the keyword `poison` is specified the generated function returns the `PoisonError`,
+///   rather than panicking, if the lock is poisoned.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::RwLock;
+/// # pub struct Inner { config: RwLock<String> }
+/// impl Inner {
+///     // rwlock_read!(pub config => String);
+///
+///     /// Acquires shared read access to the lock field `config`, blocking until it is
+///     /// available.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If the lock is poisoned.
+///     pub fn read_config(&self) -> std::sync::RwLockReadGuard<'_, String> {
+///         self.config
+///             .read()
+///             .unwrap_or_else(|_| panic!("the lock field `config` is poisoned"))
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! rwlock_read {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Acquires shared read access to the lock field `" $fn_name "`, blocking until it is "
+                    "available.\n\n# Panics\n\nIf the lock is poisoned."]
+            $fn_vis fn [<read_ $fn_name>](&self) -> ::std::sync::RwLockReadGuard<'_, $value_type> {
+                self.$field_name
+                    .read()
+                    .unwrap_or_else(|_| panic!(concat!("the lock field `", stringify!($fn_name), "` is poisoned")))
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::rwlock_read!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *poison*: `viz name => field_name, poison Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, poison $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Acquires shared read access to the lock field `" $fn_name "`, blocking until it is "
+                    "available."]
+            $fn_vis fn [<read_ $fn_name>](&self) -> ::std::sync::LockResult<::std::sync::RwLockReadGuard<'_, $value_type>> {
+                self.$field_name.read()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => poison Type`
+    ($fn_vis:vis $name:ident => poison $value_type:ty) => {
+        $crate::rwlock_read!($fn_vis $name => $name, poison $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ RwLock write_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that acquires exclusive write access to a `RwLock<T>` field, returning
+/// the guard.
+///
+/// ## Forms
+///
+/// ### `rwlock_write!(viz name => [field_name,] [poison] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * If the keyword `poison` is specified the generated function returns the `PoisonError`,
+///   rather than panicking, if the lock is poisoned.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::RwLock;
+/// # pub struct Inner { config: RwLock<String> }
+/// impl Inner {
+///     // rwlock_write!(pub config => String);
+///
+///     /// Acquires exclusive write access to the lock field `config`, blocking until it is
+///     /// available.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If the lock is poisoned.
+///     pub fn write_config(&self) -> std::sync::RwLockWriteGuard<'_, String> {
+///         self.config
+///             .write()
+///             .unwrap_or_else(|_| panic!("the lock field `config` is poisoned"))
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! rwlock_write {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Acquires exclusive write access to the lock field `" $fn_name "`, blocking until it is "
+                    "available.\n\n# Panics\n\nIf the lock is poisoned."]
+            $fn_vis fn [<write_ $fn_name>](&self) -> ::std::sync::RwLockWriteGuard<'_, $value_type> {
+                self.$field_name
+                    .write()
+                    .unwrap_or_else(|_| panic!(concat!("the lock field `", stringify!($fn_name), "` is poisoned")))
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::rwlock_write!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *poison*: `viz name => field_name, poison Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, poison $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Acquires exclusive write access to the lock field `" $fn_name "`, blocking until it is "
+                    "available."]
+            $fn_vis fn [<write_ $fn_name>](&self) -> ::std::sync::LockResult<::std::sync::RwLockWriteGuard<'_, $value_type>> {
+                self.$field_name.write()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => poison Type`
+    ($fn_vis:vis $name:ident => poison $value_type:ty) => {
+        $crate::rwlock_write!($fn_vis $name => $name, poison $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ RwLock with_<name>_read
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that calls a closure with read-only access to the content of a `RwLock<T>`
+/// field, holding the shared read lock only for the duration of the closure.
+///
+/// ## Forms
+///
+/// ### `rwlock_with_read!(viz name => [field_name,] [poison] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * The generated function returns the closure's result.
+/// * If the keyword `poison` is specified the generated function does not panic if the lock
+///   is poisoned; it calls the closure on the recovered content and returns its result
+///   within a `PoisonError`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::RwLock;
+/// # pub struct Inner { config: RwLock<String> }
+/// impl Inner {
+///     // rwlock_with_read!(pub config => String);
+///
+///     /// Calls `f` with read-only access to the content of the lock field `config`, holding the
+///     /// shared read lock only for the duration of the call.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If the lock is poisoned.
+///     pub fn with_config_read<R, F: FnOnce(&String) -> R>(&self, f: F) -> R {
+///         let guard = self
+///             .config
+///             .read()
+///             .unwrap_or_else(|_| panic!("the lock field `config` is poisoned"));
+///         f(&guard)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! rwlock_with_read {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Calls `f` with read-only access to the content of the lock field `" $fn_name "`, holding the "
+                    "shared read lock only for the duration of the call.\n\n# Panics\n\nIf the lock is poisoned."]
+            $fn_vis fn [<with_ $fn_name _read>]<R, F: FnOnce(&$value_type) -> R>(&self, f: F) -> R {
+                let guard = self
+                    .$field_name
+                    .read()
+                    .unwrap_or_else(|_| panic!(concat!("the lock field `", stringify!($fn_name), "` is poisoned")));
+                f(&guard)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::rwlock_with_read!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *poison*: `viz name => field_name, poison Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, poison $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Calls `f` with read-only access to the content of the lock field `" $fn_name "`, holding the "
+                    "shared read lock only for the duration of the call.\n\n# Errors\n\nIf the lock is poisoned `f` is still called, on the recovered content, "
+                    "and its result is returned within the `PoisonError`."]
+            $fn_vis fn [<with_ $fn_name _read>]<R, F: FnOnce(&$value_type) -> R>(&self, f: F) -> ::std::sync::LockResult<R> {
+                match self.$field_name.read() {
+                    Ok(guard) => Ok(f(&guard)),
+                    Err(poisoned) => Err(::std::sync::PoisonError::new(f(&poisoned.into_inner()))),
+                }
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => poison Type`
+    ($fn_vis:vis $name:ident => poison $value_type:ty) => {
+        $crate::rwlock_with_read!($fn_vis $name => $name, poison $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ RwLock with_<name>_write
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that calls a closure with mutable access to the content of a `RwLock<T>`
+/// field, holding the exclusive write lock only for the duration of the closure.
+///
+/// ## Forms
+///
+/// ### `rwlock_with_write!(viz name => [field_name,] [poison] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form only requires a shared reference to self; `&self`.
+/// * The generated function returns the closure's result.
+/// * If the keyword `poison` is specified the generated function does not panic if the lock
+///   is poisoned; it calls the closure on the recovered content and returns its result
+///   within a `PoisonError`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::RwLock;
+/// # pub struct Inner { config: RwLock<String> }
+/// impl Inner {
+///     // rwlock_with_write!(pub config => String);
+///
+///     /// Calls `f` with mutable access to the content of the lock field `config`, holding the
+///     /// exclusive write lock only for the duration of the call.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If the lock is poisoned.
+///     pub fn with_config_write<R, F: FnOnce(&mut String) -> R>(&self, f: F) -> R {
+///         let mut guard = self
+///             .config
+///             .write()
+///             .unwrap_or_else(|_| panic!("the lock field `config` is poisoned"));
+///         f(&mut guard)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! rwlock_with_write {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Calls `f` with mutable access to the content of the lock field `" $fn_name "`, holding the "
+                    "exclusive write lock only for the duration of the call.\n\n# Panics\n\nIf the lock is poisoned."]
+            $fn_vis fn [<with_ $fn_name _write>]<R, F: FnOnce(&mut $value_type) -> R>(&self, f: F) -> R {
+                let mut guard = self
+                    .$field_name
+                    .write()
+                    .unwrap_or_else(|_| panic!(concat!("the lock field `", stringify!($fn_name), "` is poisoned")));
+                f(&mut guard)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::rwlock_with_write!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *poison*: `viz name => field_name, poison Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, poison $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Calls `f` with mutable access to the content of the lock field `" $fn_name "`, holding the "
+                    "exclusive write lock only for the duration of the call.\n\n# Errors\n\nIf the lock is poisoned `f` is still called, on the recovered content, "
+                    "and its result is returned within the `PoisonError`."]
+            $fn_vis fn [<with_ $fn_name _write>]<R, F: FnOnce(&mut $value_type) -> R>(&self, f: F) -> ::std::sync::LockResult<R> {
+                match self.$field_name.write() {
+                    Ok(mut guard) => Ok(f(&mut guard)),
+                    Err(poisoned) => Err(::std::sync::PoisonError::new(f(&mut poisoned.into_inner()))),
+                }
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => poison Type`
+    ($fn_vis:vis $name:ident => poison $value_type:ty) => {
+        $crate::rwlock_with_write!($fn_vis $name => $name, poison $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::{
+    mutex_lock, mutex_try_lock, mutex_with_locked, rwlock_read, rwlock_with_read,
+    rwlock_with_write, rwlock_write,
+};
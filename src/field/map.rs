@@ -0,0 +1,725 @@
+/*!
+ * The set of macros for struct fields backed by `HashMap<K, V>` or `BTreeMap<K, V>`.
+ *
+ * In all of these macros the types given are the key type `K` and value type `V` of the map
+ * and *not* the type of the field itself. As the generated methods only use the operations common
+ * to both map types the same macros are used for either.
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * # pub struct Address;
+ * use std::collections::{BTreeMap, HashMap};
+ *
+ * pub struct AddressBook {
+ *     addresses: HashMap<String, Address>, // demonstrates keyword 'into'
+ *     unit_counts: BTreeMap<u32, u32>,     // demonstrates keyword 'copy'
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro                | field name  | keywords             | types           | generated signature                                                                    |
+ * |----------------------|-------------|----------------------|-----------------|----------------------------------------------------------------------------------------|
+ * | `map_get!`           | addresses   |                      | String, Address | `fn addresses_get(&self, key: &String) -> Option<&Address>`                            |
+ * | `map_get!`           | unit_counts | copy (value)         | u32, u32        | `fn unit_counts_get(&self, key: &u32) -> Option<u32>`                                  |
+ * | `map_get_mut!`       | addresses   |                      | String, Address | `fn addresses_get_mut(&mut self, key: &String) -> Option<&mut Address>`                |
+ * | `map_insert!`        | addresses   |                      | String, Address | `fn addresses_insert(&mut self, key: String, value: Address) -> Option<Address>`       |
+ * | `map_insert!`        | addresses   | into (key)           | String, Address | `fn addresses_insert<K: Into<String>>(&mut self, key: K, value: Address) -> Option<Address>` |
+ * | `map_remove!`        | addresses   |                      | String, Address | `fn addresses_remove(&mut self, key: &String) -> Option<Address>`                      |
+ * | `map_contains_key!`  | addresses   |                      | String          | `fn contains_addresses_key(&self, key: &String) -> bool`                               |
+ * | `map_keys!`          | addresses   |                      | String          | `fn addresses_keys(&self) -> impl Iterator<Item = &String>`                            |
+ * | `map_keys!`          | unit_counts | copy                 | u32             | `fn unit_counts_keys(&self) -> impl Iterator<Item = u32> + '_`                         |
+ * | `map_values!`        | addresses   |                      | Address         | `fn addresses_values(&self) -> impl Iterator<Item = &Address>`                         |
+ * | `map_values!`        | unit_counts | copy                 | u32             | `fn unit_counts_values(&self) -> impl Iterator<Item = u32> + '_`                       |
+ * | `map_len!`           | addresses   |                      |                 | `fn addresses_len(&self) -> usize`                                                     |
+ * | `map_is_empty!`      | addresses   |                      |                 | `fn is_addresses_empty(&self) -> bool`                                                 |
+ * | `map_with_entry!`    | addresses   |                      | String, Address | `fn with_addresses_entry(mut self, key: String, value: Address) -> Self`               |
+ * | `map_with_entry!`    | addresses   | into (key and value) | String, Address | `fn with_addresses_entry<K: Into<String>, V: Into<Address>>(mut self, key: K, value: V) -> Self` |
+ *
+ */
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Map <name>_get
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a keyed getter method for a map field.
+///
+/// ## Forms
+///
+/// ### `map_get!(viz name => [field_name,] KeyType, ValueType)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is the reference `Option<&ValueType>`, it is `None`
+///   if the map does not contain `key`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address;
+/// # use std::collections::HashMap;
+/// # pub struct AddressBook { addresses: HashMap<String, Address> }
+/// impl AddressBook {
+///     // map_get!(pub addresses => String, Address);
+///
+///     /// Returns a reference to the value corresponding to `key` in the map field
+///     /// `addresses`, or `None` if the map does not contain `key`.
+///     pub fn addresses_get(&self, key: &String) -> Option<&Address> {
+///         self.addresses.get(key)
+///     }
+/// }
+/// ```
+///
+/// ### `map_get!(viz name => [field_name,] KeyType, copy ValueType)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is the value `Option<ValueType>`, assuming `ValueType`
+///   implements `Copy`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::BTreeMap;
+/// # pub struct AddressBook { unit_counts: BTreeMap<u32, u32> }
+/// impl AddressBook {
+///     // map_get!(pub unit_counts => u32, copy u32);
+///
+///     /// Returns a copy of the value corresponding to `key` in the map field
+///     /// `unit_counts`, or `None` if the map does not contain `key`.
+///     pub fn unit_counts_get(&self, key: &u32) -> Option<u32> {
+///         self.unit_counts.get(key).copied()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! map_get {
+    // Base case: `viz name => field_name, KeyType, ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $key_type:ty, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a reference to the value corresponding to `key` in the map field `" $fn_name
+                    "`, or `None` if the map does not contain `key`. The returned value is an optional "
+                    "immutable reference `Option<&" $value_type ">`."]
+            $fn_vis fn [<$fn_name _get>](&self, key: &$key_type) -> Option<&$value_type> {
+                self.$field_name.get(key)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => KeyType, ValueType`
+    ($fn_vis:vis $name:ident => $key_type:ty, $value_type:ty) => {
+        $crate::map_get!($fn_vis $name => $name, $key_type, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *copy*: `viz name => field_name, KeyType, copy ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $key_type:ty, copy $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a copy of the value corresponding to `key` in the map field `" $fn_name
+                    "`, or `None` if the map does not contain `key`. The returned value is an optional "
+                    "copy `Option<" $value_type ">`."]
+            $fn_vis fn [<$fn_name _get>](&self, key: &$key_type) -> Option<$value_type> {
+                self.$field_name.get(key).copied()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => KeyType, copy ValueType`
+    ($fn_vis:vis $name:ident => $key_type:ty, copy $value_type:ty) => {
+        $crate::map_get!($fn_vis $name => $name, $key_type, copy $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Map <name>_get_mut
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a keyed mutable getter method for a map field.
+///
+/// ## Forms
+///
+/// ### `map_get_mut!(viz name => [field_name,] KeyType, ValueType)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the generated function is the reference `Option<&mut ValueType>`, it is `None`
+///   if the map does not contain `key`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address;
+/// # use std::collections::HashMap;
+/// # pub struct AddressBook { addresses: HashMap<String, Address> }
+/// impl AddressBook {
+///     // map_get_mut!(pub addresses => String, Address);
+///
+///     /// Returns a *mutable* reference to the value corresponding to `key` in the map
+///     /// field `addresses`, or `None` if the map does not contain `key`.
+///     pub fn addresses_get_mut(&mut self, key: &String) -> Option<&mut Address> {
+///         self.addresses.get_mut(key)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! map_get_mut {
+    // Base case: `viz name => field_name, KeyType, ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $key_type:ty, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a *mutable* reference to the value corresponding to `key` in the map field `"
+                    $fn_name "`, or `None` if the map does not contain `key`. The returned value is an "
+                    "optional mutable reference `Option<&mut " $value_type ">`."]
+            $fn_vis fn [<$fn_name _get_mut>](&mut self, key: &$key_type) -> Option<&mut $value_type> {
+                self.$field_name.get_mut(key)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => KeyType, ValueType`
+    ($fn_vis:vis $name:ident => $key_type:ty, $value_type:ty) => {
+        $crate::map_get_mut!($fn_vis $name => $name, $key_type, $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Map <name>_insert
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that inserts a key-value pair into a map field.
+///
+/// ## Forms
+///
+/// ### `map_insert!(viz name => [field_name,] [into] KeyType, [into] ValueType)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * If the keyword `into` precedes `KeyType` the type of the key parameter is the trait-bound
+///   type `K: Into<KeyType>`, similarly if it precedes `ValueType` the type of the value
+///   parameter is `V: Into<ValueType>`.
+/// * The type of the generated function is `Option<ValueType>`, the value previously
+///   associated with the key, if any.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address;
+/// # use std::collections::HashMap;
+/// # pub struct AddressBook { addresses: HashMap<String, Address> }
+/// impl AddressBook {
+///     // map_insert!(pub addresses => into String, Address);
+///
+///     /// Inserts the `value` into the map field `addresses` with the key `key`, returning
+///     /// any value previously associated with the key.
+///     pub fn addresses_insert<K: Into<String>>(&mut self, key: K, value: Address) -> Option<Address> {
+///         self.addresses.insert(key.into(), value)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! map_insert {
+    // Base case: `viz name => field_name, KeyType, ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $key_type:ty, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Inserts the `value` into the map field `" $fn_name "` with the key `key`, returning "
+                    "any value previously associated with the key."]
+            $fn_vis fn [<$fn_name _insert>](&mut self, key: $key_type, value: $value_type) -> Option<$value_type> {
+                self.$field_name.insert(key, value)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => KeyType, ValueType`
+    ($fn_vis:vis $name:ident => $key_type:ty, $value_type:ty) => {
+        $crate::map_insert!($fn_vis $name => $name, $key_type, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into* key: `viz name => field_name, into KeyType, ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $key_type:ty, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Inserts the `value` into the map field `" $fn_name "` with the key `key` (Using `Into<"
+                    $key_type ">`), returning any value previously associated with the key."]
+            $fn_vis fn [<$fn_name _insert>]<K: Into<$key_type>>(&mut self, key: K, value: $value_type) -> Option<$value_type> {
+                self.$field_name.insert(key.into(), value)
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into KeyType, ValueType`
+    ($fn_vis:vis $name:ident => into $key_type:ty, $value_type:ty) => {
+        $crate::map_insert!($fn_vis $name => $name, into $key_type, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (3) Base case with *into* value: `viz name => field_name, KeyType, into ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $key_type:ty, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Inserts the `value` (Using `Into<" $value_type ">`) into the map field `" $fn_name
+                    "` with the key `key`, returning any value previously associated with the key."]
+            $fn_vis fn [<$fn_name _insert>]<V: Into<$value_type>>(&mut self, key: $key_type, value: V) -> Option<$value_type> {
+                self.$field_name.insert(key, value.into())
+            }
+        }
+    };
+    // Case (3) without *field name*: `viz name => KeyType, into ValueType`
+    ($fn_vis:vis $name:ident => $key_type:ty, into $value_type:ty) => {
+        $crate::map_insert!($fn_vis $name => $name, $key_type, into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (4) Case (2) with *into* value: `viz name => field_name, into KeyType, into ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $key_type:ty, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Inserts the `value` (Using `Into<" $value_type ">`) into the map field `" $fn_name
+                    "` with the key `key` (Using `Into<" $key_type ">`), returning any value previously "
+                    "associated with the key."]
+            $fn_vis fn [<$fn_name _insert>]<K: Into<$key_type>, V: Into<$value_type>>(&mut self, key: K, value: V) -> Option<$value_type> {
+                self.$field_name.insert(key.into(), value.into())
+            }
+        }
+    };
+    // Case (4) without *field name*: `viz name => into KeyType, into ValueType`
+    ($fn_vis:vis $name:ident => into $key_type:ty, into $value_type:ty) => {
+        $crate::map_insert!($fn_vis $name => $name, into $key_type, into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Map <name>_remove
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that removes a key, and returns its value, from a map field.
+///
+/// ## Forms
+///
+/// ### `map_remove!(viz name => [field_name,] KeyType, ValueType)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the generated function is `Option<ValueType>`, it is `None` if the map
+///   did not contain `key`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address;
+/// # use std::collections::HashMap;
+/// # pub struct AddressBook { addresses: HashMap<String, Address> }
+/// impl AddressBook {
+///     // map_remove!(pub addresses => String, Address);
+///
+///     /// Removes `key` from the map field `addresses`, returning the value previously
+///     /// associated with the key, if any.
+///     pub fn addresses_remove(&mut self, key: &String) -> Option<Address> {
+///         self.addresses.remove(key)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! map_remove {
+    // Base case: `viz name => field_name, KeyType, ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $key_type:ty, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Removes `key` from the map field `" $fn_name "`, returning the value previously "
+                    "associated with the key, if any."]
+            $fn_vis fn [<$fn_name _remove>](&mut self, key: &$key_type) -> Option<$value_type> {
+                self.$field_name.remove(key)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => KeyType, ValueType`
+    ($fn_vis:vis $name:ident => $key_type:ty, $value_type:ty) => {
+        $crate::map_remove!($fn_vis $name => $name, $key_type, $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Map contains_<name>_key
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a predicate method that returns `true` if a map field contains a given key.
+///
+/// ## Forms
+///
+/// ### `map_contains_key!(viz name => [field_name,] KeyType)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address;
+/// # use std::collections::HashMap;
+/// # pub struct AddressBook { addresses: HashMap<String, Address> }
+/// impl AddressBook {
+///     // map_contains_key!(pub addresses => String);
+///
+///     /// Returns `true` if the map field `addresses` contains a value for `key`, else `false`.
+///     pub fn contains_addresses_key(&self, key: &String) -> bool {
+///         self.addresses.contains_key(key)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! map_contains_key {
+    // Base case: `viz name => field_name, KeyType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $key_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns `true` if the map field `" $fn_name "` contains a value for `key`, else `false`."]
+            $fn_vis fn [<contains_ $fn_name _key>](&self, key: &$key_type) -> bool {
+                self.$field_name.contains_key(key)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => KeyType`
+    ($fn_vis:vis $name:ident => $key_type:ty) => {
+        $crate::map_contains_key!($fn_vis $name => $name, $key_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Map <name>_keys
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that returns an iterator over the keys of a map field.
+///
+/// ## Forms
+///
+/// ### `map_keys!(viz name => [field_name,] [copy] KeyType)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is an iterator over immutable references `&KeyType`,
+///   or if `copy` is specified, over copies `KeyType`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::collections::BTreeMap;
+/// # pub struct AddressBook { unit_counts: BTreeMap<u32, u32> }
+/// impl AddressBook {
+///     // map_keys!(pub unit_counts => copy u32);
+///
+///     /// Returns an iterator over copies of the keys of the map field `unit_counts`.
+///     pub fn unit_counts_keys(&self) -> impl Iterator<Item = u32> + '_ {
+///         self.unit_counts.keys().copied()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! map_keys {
+    // Base case: `viz name => field_name, KeyType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $key_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns an iterator over references to the keys of the map field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _keys>](&self) -> impl Iterator<Item = &$key_type> {
+                self.$field_name.keys()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => KeyType`
+    ($fn_vis:vis $name:ident => $key_type:ty) => {
+        $crate::map_keys!($fn_vis $name => $name, $key_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *copy*: `viz name => field_name, copy KeyType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $key_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns an iterator over copies of the keys of the map field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _keys>](&self) -> impl Iterator<Item = $key_type> + '_ {
+                self.$field_name.keys().copied()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => copy KeyType`
+    ($fn_vis:vis $name:ident => copy $key_type:ty) => {
+        $crate::map_keys!($fn_vis $name => $name, copy $key_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Map <name>_values
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that returns an iterator over the values of a map field.
+///
+/// ## Forms
+///
+/// ### `map_values!(viz name => [field_name,] [copy] ValueType)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is an iterator over immutable references `&ValueType`,
+///   or if `copy` is specified, over copies `ValueType`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address;
+/// # use std::collections::HashMap;
+/// # pub struct AddressBook { addresses: HashMap<String, Address> }
+/// impl AddressBook {
+///     // map_values!(pub addresses => Address);
+///
+///     /// Returns an iterator over references to the values of the map field `addresses`.
+///     pub fn addresses_values(&self) -> impl Iterator<Item = &Address> {
+///         self.addresses.values()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! map_values {
+    // Base case: `viz name => field_name, ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns an iterator over references to the values of the map field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _values>](&self) -> impl Iterator<Item = &$value_type> {
+                self.$field_name.values()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => ValueType`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::map_values!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *copy*: `viz name => field_name, copy ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns an iterator over copies of the values of the map field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _values>](&self) -> impl Iterator<Item = $value_type> + '_ {
+                self.$field_name.values().copied()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => copy ValueType`
+    ($fn_vis:vis $name:ident => copy $value_type:ty) => {
+        $crate::map_values!($fn_vis $name => $name, copy $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Map <name>_len
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that returns the number of entries in a map field.
+///
+/// ## Forms
+///
+/// ### `map_len!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address;
+/// # use std::collections::HashMap;
+/// # pub struct AddressBook { addresses: HashMap<String, Address> }
+/// impl AddressBook {
+///     // map_len!(pub addresses);
+///
+///     /// Returns the number of entries in the map field `addresses`.
+///     pub fn addresses_len(&self) -> usize {
+///         self.addresses.len()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! map_len {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Returns the number of entries in the map field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _len>](&self) -> usize {
+                self.$field_name.len()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::map_len!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Map is_<name>_empty
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a predicate method that returns `true` if a map field is empty.
+///
+/// ## Forms
+///
+/// ### `map_is_empty!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address;
+/// # use std::collections::HashMap;
+/// # pub struct AddressBook { addresses: HashMap<String, Address> }
+/// impl AddressBook {
+///     // map_is_empty!(pub addresses);
+///
+///     /// Returns `true` if the map field `addresses` contains no entries, else `false`.
+///     pub fn is_addresses_empty(&self) -> bool {
+///         self.addresses.is_empty()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! map_is_empty {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Returns `true` if the map field `" $fn_name "` contains no entries, else `false`."]
+            $fn_vis fn [<is_ $fn_name _empty>](&self) -> bool {
+                self.$field_name.is_empty()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::map_is_empty!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Map with_<name>_entry
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a builder style initializer method that adds an entry to a map field.
+///
+/// ## Forms
+///
+/// ### `map_with_entry!(viz name => [field_name,] [into] KeyType, [into] ValueType)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
+///   that initializers can be chained.
+/// * If the keyword `into` precedes `KeyType` the type of the key parameter is the trait-bound
+///   type `K: Into<KeyType>`, similarly if it precedes `ValueType` the type of the value
+///   parameter is `V: Into<ValueType>`.
+/// * Any value previously associated with the key is replaced.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address;
+/// # use std::collections::HashMap;
+/// # pub struct AddressBook { addresses: HashMap<String, Address> }
+/// impl AddressBook {
+///     // map_with_entry!(pub addresses => into String, Address);
+///
+///     /// Add the entry `key` and `value` to the map field `addresses` within this structure,
+///     /// usually during construction. This function takes a mutable `self` parameter and
+///     /// returns `Self` allowing it to be chained during construction.
+///     pub fn with_addresses_entry<K: Into<String>>(mut self, key: K, value: Address) -> Self {
+///         let _ = self.addresses.insert(key.into(), value);
+///         self
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! map_with_entry {
+    // Base case: `viz name => field_name, KeyType, ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $key_type:ty, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add the entry `key` and `value` to the map field `" $fn_name "` within this structure, "
+                    "usually during construction. This function takes a mutable `self` parameter and returns "
+                    "`Self` allowing it to be chained during construction."]
+            $fn_vis fn [<with_ $fn_name _entry>](mut self, key: $key_type, value: $value_type) -> Self {
+                let _ = self.$field_name.insert(key, value);
+                self
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => KeyType, ValueType`
+    ($fn_vis:vis $name:ident => $key_type:ty, $value_type:ty) => {
+        $crate::map_with_entry!($fn_vis $name => $name, $key_type, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into* key: `viz name => field_name, into KeyType, ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $key_type:ty, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add the entry `key` (Using `Into<" $key_type ">`) and `value` to the map field `" $fn_name
+                    "` within this structure, usually during construction. This function takes a mutable "
+                    "`self` parameter and returns `Self` allowing it to be chained during construction."]
+            $fn_vis fn [<with_ $fn_name _entry>]<K: Into<$key_type>>(mut self, key: K, value: $value_type) -> Self {
+                let _ = self.$field_name.insert(key.into(), value);
+                self
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into KeyType, ValueType`
+    ($fn_vis:vis $name:ident => into $key_type:ty, $value_type:ty) => {
+        $crate::map_with_entry!($fn_vis $name => $name, into $key_type, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (3) Base case with *into* value: `viz name => field_name, KeyType, into ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $key_type:ty, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add the entry `key` and `value` (Using `Into<" $value_type ">`) to the map field `" $fn_name
+                    "` within this structure, usually during construction. This function takes a mutable "
+                    "`self` parameter and returns `Self` allowing it to be chained during construction."]
+            $fn_vis fn [<with_ $fn_name _entry>]<V: Into<$value_type>>(mut self, key: $key_type, value: V) -> Self {
+                let _ = self.$field_name.insert(key, value.into());
+                self
+            }
+        }
+    };
+    // Case (3) without *field name*: `viz name => KeyType, into ValueType`
+    ($fn_vis:vis $name:ident => $key_type:ty, into $value_type:ty) => {
+        $crate::map_with_entry!($fn_vis $name => $name, $key_type, into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (4) Case (2) with *into* value: `viz name => field_name, into KeyType, into ValueType`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $key_type:ty, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add the entry `key` (Using `Into<" $key_type ">`) and `value` (Using `Into<" $value_type
+                    ">`) to the map field `" $fn_name "` within this structure, usually during construction. "
+                    "This function takes a mutable `self` parameter and returns `Self` allowing it to be "
+                    "chained during construction."]
+            $fn_vis fn [<with_ $fn_name _entry>]<K: Into<$key_type>, V: Into<$value_type>>(mut self, key: K, value: V) -> Self {
+                let _ = self.$field_name.insert(key.into(), value.into());
+                self
+            }
+        }
+    };
+    // Case (4) without *field name*: `viz name => into KeyType, into ValueType`
+    ($fn_vis:vis $name:ident => into $key_type:ty, into $value_type:ty) => {
+        $crate::map_with_entry!($fn_vis $name => $name, into $key_type, into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::{
+    map_contains_key, map_get, map_get_mut, map_insert, map_is_empty, map_keys, map_len,
+    map_remove, map_values, map_with_entry,
+};
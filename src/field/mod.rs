@@ -25,18 +25,113 @@ pub struct Address {
 | `get!`     | street_2         | optional      | String | `const fn street_2(&self) -> Option<&String>`                       |
 | `get!`     | number_on_street | optional copy | u64    | `const fn number_on_street(&self) -> Option<number_on_street>`      |
 | `get_mut!` | street_1         |               | String | `const fn street_1_mut(&mut self) -> &mut String`                   |
+| `get_mut!` | street_2         | optional      | String | `const fn street_2_mut(&mut self) -> Option<&mut String>`           |
 | `set!`     | number_on_street |               | u32    | `fn set_number_on_street(&mut self, number_on_street: u32)`         |
 | `set!`     | street_1         | into          | String | `fn set_street_1<T: Into<String>(&mut self, street_1: T)`           |
 | `set!`     | street_2         | optional      | String | `fn set_street_2(&mut self, street_2: String)`                      |
 | `set!`     | street_2         | optional into | String | `fn set_street_2<T: Into<String>(&mut self, street_2: T)`           |
 | `unset!`   | street_2         |               | String | `fn unset_street_2(&mut self)`                                      |
+| `take!`    | street_1         |               | String | `fn take_street_1(&mut self) -> String`                             |
+| `take!`    | street_2         | optional      | String | `fn take_street_2(&mut self) -> Option<String>`                     |
+| `replace!` | street_1         | into          | String | `fn replace_street_1<T: Into<String>(&mut self, value: T) -> String` |
+| `replace!` | street_2         | optional      | String | `fn replace_street_2(&mut self, value: String) -> Option<String>`   |
+| `swap!`    | street_1         |               |        | `fn swap_street_1(&mut self, other: &mut Self)`                     |
+| `update!`  | street_1         |               | String | `fn update_street_1<F: FnOnce(&mut String)>(&mut self, f: F)`       |
+| `update!`  | street_2         | optional      | String | `fn update_street_2_if_set<F: FnOnce(&mut String)>(&mut self, f: F)` |
 
 */
 
 #[macro_use]
 pub mod core;
-pub use core::{get, get_mut, set, unset, with};
+pub use core::{get, get_mut, replace, set, swap, take, unset, update, with};
+
+#[macro_use]
+pub mod pointer;
+pub use pointer::weak;
+
+#[macro_use]
+pub mod vector;
+pub use vector::{
+    vec_clear, vec_contains, vec_extend, vec_get, vec_get_mut, vec_is_empty, vec_iter,
+    vec_iter_mut, vec_length, vec_pop, vec_push, vec_remove, vec_with_from_iter,
+};
+
+#[macro_use]
+pub mod map;
+pub use map::{
+    map_contains_key, map_get, map_get_mut, map_insert, map_is_empty, map_keys, map_len,
+    map_remove, map_values, map_with_entry,
+};
+
+#[macro_use]
+pub mod collection_set;
+pub use collection_set::{
+    collection_set_contains, collection_set_extend, collection_set_insert, collection_set_iter,
+    collection_set_len, collection_set_remove, collection_set_union, collection_set_with_member,
+};
+
+#[macro_use]
+pub mod deque;
+pub use deque::{
+    deque_back, deque_front, deque_iter, deque_len, deque_pop_back, deque_pop_front,
+    deque_push_back, deque_push_front,
+};
+
+#[macro_use]
+pub mod numeric;
+pub use numeric::{
+    num_add_to, num_checked_add_to, num_decrement, num_increment, num_reset,
+    num_saturating_sub_from,
+};
+
+#[macro_use]
+pub mod flag;
+pub use flag::{flag_disable, flag_enable, flag_is, flag_set, flag_toggle, flag_with};
+
+#[macro_use]
+pub mod bits;
+pub use bits::bits;
+
+#[macro_use]
+pub mod bytes;
+pub use bytes::{
+    be_bytes, be_f32, be_f64, be_i128, be_i16, be_i32, be_i64, be_i8, be_u128, be_u16, be_u32,
+    be_u64, be_u8, le_bytes, le_f32, le_f64, le_i128, le_i16, le_i32, le_i64, le_i8, le_u128,
+    le_u16, le_u32, le_u64, le_u8,
+};
+
+#[macro_use]
+pub mod cell;
+pub use cell::{
+    cell_get, cell_replace, cell_set, refcell_get, refcell_get_mut, refcell_try_get,
+    refcell_try_get_mut,
+};
+
+#[macro_use]
+pub mod lock;
+pub use lock::{
+    mutex_lock, mutex_try_lock, mutex_with_locked, rwlock_read, rwlock_with_read,
+    rwlock_with_write, rwlock_write,
+};
+
+#[macro_use]
+pub mod lazy;
+pub use lazy::lazy_get;
+
+#[macro_use]
+pub mod atomic;
+pub use atomic::{
+    atomic_compare_exchange, atomic_fetch_add, atomic_fetch_sub, atomic_load, atomic_store,
+    atomic_swap,
+};
+
+#[macro_use]
+pub mod pin;
+pub use pin::pin_get;
 
 #[macro_use]
 pub mod combination;
-pub use combination::{get_and_set, get_set_and_unset, with_get_and_set, with_get_set_and_unset};
+pub use combination::{
+    flag, get_and_set, get_mut_set_and_unset, get_set_and_unset, mutex_accessors, rwlock_accessors,
+    vec_accessors, vec_accessors_mut, with_get_and_set, with_get_set_and_unset,
+};
@@ -0,0 +1,639 @@
+/*!
+ * The set of macros for integer struct fields, typically counters and quantities.
+ *
+ * The arithmetic methods generated by these macros never wrap silently; `increment_name`,
+ * `decrement_name`, and `add_to_name` use checked arithmetic and panic on overflow in both
+ * debug and release builds, while `checked_add_to_name` and `saturating_sub_from_name` never
+ * panic.
+ *
+ * The keyword `into` is used in the same way as the core module's macros, denoting that a
+ * value parameter is of the trait-bound type `T: Into<Type>` rather than `Type`. The keyword
+ * `returning` denotes that the generated method will return the field's new value; `copy`
+ * is not used, as in the core module it denotes returning a value rather than a reference.
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * pub struct Statistics {
+ *     requests: u64,
+ *     bytes_sent: u64,
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro                      | field name | keywords  | type | generated signature                                                  |
+ * |----------------------------|------------|-----------|------|----------------------------------------------------------------------|
+ * | `num_increment!`           | requests   |           |      | `fn increment_requests(&mut self)`                                   |
+ * | `num_increment!`           | requests   | returning | u64  | `fn increment_requests(&mut self) -> u64`                            |
+ * | `num_decrement!`           | requests   |           |      | `fn decrement_requests(&mut self)`                                   |
+ * | `num_decrement!`           | requests   | returning | u64  | `fn decrement_requests(&mut self) -> u64`                            |
+ * | `num_add_to!`              | bytes_sent |           | u64  | `fn add_to_bytes_sent(&mut self, value: u64)`                        |
+ * | `num_add_to!`              | bytes_sent | into      | u64  | `fn add_to_bytes_sent<T: Into<u64>>(&mut self, value: T)`            |
+ * | `num_add_to!`              | bytes_sent | returning | u64  | `fn add_to_bytes_sent(&mut self, value: u64) -> u64`                 |
+ * | `num_checked_add_to!`      | bytes_sent |           | u64  | `fn checked_add_to_bytes_sent(&mut self, value: u64) -> Option<()>`  |
+ * | `num_checked_add_to!`      | bytes_sent | returning | u64  | `fn checked_add_to_bytes_sent(&mut self, value: u64) -> Option<u64>` |
+ * | `num_saturating_sub_from!` | bytes_sent |           | u64  | `fn saturating_sub_from_bytes_sent(&mut self, value: u64)`           |
+ * | `num_saturating_sub_from!` | bytes_sent | returning | u64  | `fn saturating_sub_from_bytes_sent(&mut self, value: u64) -> u64`    |
+ * | `num_reset!`               | requests   |           |      | `fn reset_requests(&mut self)`                                       |
+ *
+ * The `returning` and `into` keywords may be combined, as `returning into Type`, for the
+ * macros [`num_add_to`], [`num_checked_add_to`], and [`num_saturating_sub_from`].
+ *
+ */
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Numeric increment_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that adds one to an integer field.
+///
+/// ## Forms
+///
+/// ### `num_increment!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The generated function panics if the addition overflows, in both debug and release
+///   builds, rather than wrapping.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Statistics { requests: u64 }
+/// impl Statistics {
+///     // num_increment!(pub requests);
+///
+///     /// Add one to the value of the field `requests` within this structure.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If the addition overflows.
+///     pub fn increment_requests(&mut self) {
+///         self.requests = self
+///             .requests
+///             .checked_add(1)
+///             .expect("attempt to increment the field `requests` with overflow");
+///     }
+/// }
+/// ```
+///
+/// ### `num_increment!(viz name => [field_name,] returning Type)`
+///
+/// * The keyword `returning` denotes that the generated function returns the field's new
+///   value.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Statistics { requests: u64 }
+/// impl Statistics {
+///     // num_increment!(pub requests => returning u64);
+///
+///     /// Add one to the value of the field `requests` within this structure, returning
+///     /// the new value.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If the addition overflows.
+///     pub fn increment_requests(&mut self) -> u64 {
+///         self.requests = self
+///             .requests
+///             .checked_add(1)
+///             .expect("attempt to increment the field `requests` with overflow");
+///         self.requests
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! num_increment {
+    // (2) Base case with *returning*: `viz name => field_name, returning Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, returning $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add one to the value of the field `" $fn_name "` within this structure, "
+                    "returning the new value.\n\n# Panics\n\nIf the addition overflows."]
+            $fn_vis fn [<increment_ $fn_name>](&mut self) -> $value_type {
+                self.$field_name = self.$field_name.checked_add(1).expect(concat!(
+                    "attempt to increment the field `", stringify!($field_name), "` with overflow"
+                ));
+                self.$field_name
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => returning Type`
+    ($fn_vis:vis $name:ident => returning $value_type:ty) => {
+        $crate::num_increment!($fn_vis $name => $name, returning $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Add one to the value of the field `" $fn_name "` within this structure."
+                    "\n\n# Panics\n\nIf the addition overflows."]
+            $fn_vis fn [<increment_ $fn_name>](&mut self) {
+                self.$field_name = self.$field_name.checked_add(1).expect(concat!(
+                    "attempt to increment the field `", stringify!($field_name), "` with overflow"
+                ));
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::num_increment!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Numeric decrement_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that subtracts one from an integer field.
+///
+/// ## Forms
+///
+/// ### `num_decrement!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The generated function panics if the subtraction overflows, in both debug and release
+///   builds, rather than wrapping.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Statistics { requests: u64 }
+/// impl Statistics {
+///     // num_decrement!(pub requests);
+///
+///     /// Subtract one from the value of the field `requests` within this structure.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If the subtraction overflows.
+///     pub fn decrement_requests(&mut self) {
+///         self.requests = self
+///             .requests
+///             .checked_sub(1)
+///             .expect("attempt to decrement the field `requests` with overflow");
+///     }
+/// }
+/// ```
+///
+/// ### `num_decrement!(viz name => [field_name,] returning Type)`
+///
+/// * The keyword `returning` denotes that the generated function returns the field's new
+///   value.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Statistics { requests: u64 }
+/// impl Statistics {
+///     // num_decrement!(pub requests => returning u64);
+///
+///     /// Subtract one from the value of the field `requests` within this structure,
+///     /// returning the new value.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If the subtraction overflows.
+///     pub fn decrement_requests(&mut self) -> u64 {
+///         self.requests = self
+///             .requests
+///             .checked_sub(1)
+///             .expect("attempt to decrement the field `requests` with overflow");
+///         self.requests
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! num_decrement {
+    // (2) Base case with *returning*: `viz name => field_name, returning Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, returning $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Subtract one from the value of the field `" $fn_name "` within this structure, "
+                    "returning the new value.\n\n# Panics\n\nIf the subtraction overflows."]
+            $fn_vis fn [<decrement_ $fn_name>](&mut self) -> $value_type {
+                self.$field_name = self.$field_name.checked_sub(1).expect(concat!(
+                    "attempt to decrement the field `", stringify!($field_name), "` with overflow"
+                ));
+                self.$field_name
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => returning Type`
+    ($fn_vis:vis $name:ident => returning $value_type:ty) => {
+        $crate::num_decrement!($fn_vis $name => $name, returning $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Subtract one from the value of the field `" $fn_name "` within this structure."
+                    "\n\n# Panics\n\nIf the subtraction overflows."]
+            $fn_vis fn [<decrement_ $fn_name>](&mut self) {
+                self.$field_name = self.$field_name.checked_sub(1).expect(concat!(
+                    "attempt to decrement the field `", stringify!($field_name), "` with overflow"
+                ));
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::num_decrement!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Numeric add_to_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that adds a value to an integer field.
+///
+/// ## Forms
+///
+/// ### `num_add_to!(viz name => [field_name,] [returning] [into] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * If the keyword `returning` is specified the generated function returns the field's new
+///   value.
+/// * If the keyword `into` is specified the type of the value parameter is the trait-bound
+///   type `T: Into<Type>` rather than `Type` for flexibility.
+/// * The generated function panics if the addition overflows, in both debug and release
+///   builds, rather than wrapping; use [`num_checked_add_to`] to handle overflow.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Statistics { bytes_sent: u64 }
+/// impl Statistics {
+///     // num_add_to!(pub bytes_sent => into u64);
+///
+///     /// Add `value` to the value of the field `bytes_sent` within this structure.
+///     ///
+///     /// # Panics
+///     ///
+///     /// If the addition overflows.
+///     pub fn add_to_bytes_sent<T: Into<u64>>(&mut self, value: T) {
+///         self.bytes_sent = self
+///             .bytes_sent
+///             .checked_add(value.into())
+///             .expect("attempt to add to the field `bytes_sent` with overflow");
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! num_add_to {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add `value` to the value of the field `" $fn_name "` within this structure."
+                    "\n\n# Panics\n\nIf the addition overflows."]
+            $fn_vis fn [<add_to_ $fn_name>](&mut self, value: $value_type) {
+                self.$field_name = self.$field_name.checked_add(value).expect(concat!(
+                    "attempt to add to the field `", stringify!($field_name), "` with overflow"
+                ));
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::num_add_to!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add `value` to the value of the field `" $fn_name "` within this structure."
+                    "\n\n# Panics\n\nIf the addition overflows."]
+            $fn_vis fn [<add_to_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) {
+                self.$field_name = self.$field_name.checked_add(value.into()).expect(concat!(
+                    "attempt to add to the field `", stringify!($field_name), "` with overflow"
+                ));
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::num_add_to!($fn_vis $name => $name, into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (3) Base case with *returning*: `viz name => field_name, returning Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, returning $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add `value` to the value of the field `" $fn_name "` within this structure, "
+                    "returning the new value.\n\n# Panics\n\nIf the addition overflows."]
+            $fn_vis fn [<add_to_ $fn_name>](&mut self, value: $value_type) -> $value_type {
+                self.$field_name = self.$field_name.checked_add(value).expect(concat!(
+                    "attempt to add to the field `", stringify!($field_name), "` with overflow"
+                ));
+                self.$field_name
+            }
+        }
+    };
+    // Case (3) without *field name*: `viz name => returning Type`
+    ($fn_vis:vis $name:ident => returning $value_type:ty) => {
+        $crate::num_add_to!($fn_vis $name => $name, returning $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (4) Case (3) with *into*: `viz name => field_name, returning into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, returning into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add `value` to the value of the field `" $fn_name "` within this structure, "
+                    "returning the new value.\n\n# Panics\n\nIf the addition overflows."]
+            $fn_vis fn [<add_to_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) -> $value_type {
+                self.$field_name = self.$field_name.checked_add(value.into()).expect(concat!(
+                    "attempt to add to the field `", stringify!($field_name), "` with overflow"
+                ));
+                self.$field_name
+            }
+        }
+    };
+    // Case (4) without *field name*: `viz name => returning into Type`
+    ($fn_vis:vis $name:ident => returning into $value_type:ty) => {
+        $crate::num_add_to!($fn_vis $name => $name, returning into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Numeric checked_add_to_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that adds a value to an integer field, only if the addition does not
+/// overflow.
+///
+/// ## Forms
+///
+/// ### `num_checked_add_to!(viz name => [field_name,] [returning] [into] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The generated function returns `None`, and leaves the field unchanged, if the addition
+///   would overflow; otherwise it returns `Some(())`.
+/// * If the keyword `returning` is specified the generated function returns `Some` of the field's
+///   new value rather than `Some(())`.
+/// * If the keyword `into` is specified the type of the value parameter is the trait-bound
+///   type `T: Into<Type>` rather than `Type` for flexibility.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Statistics { bytes_sent: u64 }
+/// impl Statistics {
+///     // num_checked_add_to!(pub bytes_sent => u64);
+///
+///     /// Add `value` to the value of the field `bytes_sent` within this structure,
+///     /// returning `None` and leaving the field unchanged if the addition would overflow.
+///     pub fn checked_add_to_bytes_sent(&mut self, value: u64) -> Option<()> {
+///         self.bytes_sent
+///             .checked_add(value)
+///             .map(|new_value| self.bytes_sent = new_value)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! num_checked_add_to {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add `value` to the value of the field `" $fn_name "` within this structure, "
+                    "returning `None` and leaving the field unchanged if the addition would overflow."]
+            $fn_vis fn [<checked_add_to_ $fn_name>](&mut self, value: $value_type) -> Option<()> {
+                self.$field_name
+                    .checked_add(value)
+                    .map(|new_value| self.$field_name = new_value)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::num_checked_add_to!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add `value` to the value of the field `" $fn_name "` within this structure, "
+                    "returning `None` and leaving the field unchanged if the addition would overflow."]
+            $fn_vis fn [<checked_add_to_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) -> Option<()> {
+                self.$field_name
+                    .checked_add(value.into())
+                    .map(|new_value| self.$field_name = new_value)
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::num_checked_add_to!($fn_vis $name => $name, into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (3) Base case with *returning*: `viz name => field_name, returning Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, returning $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add `value` to the value of the field `" $fn_name "` within this structure, "
+                    "returning the new value, or `None` and leaving the field unchanged if the "
+                    "addition would overflow."]
+            $fn_vis fn [<checked_add_to_ $fn_name>](&mut self, value: $value_type) -> Option<$value_type> {
+                self.$field_name.checked_add(value).map(|new_value| {
+                    self.$field_name = new_value;
+                    new_value
+                })
+            }
+        }
+    };
+    // Case (3) without *field name*: `viz name => returning Type`
+    ($fn_vis:vis $name:ident => returning $value_type:ty) => {
+        $crate::num_checked_add_to!($fn_vis $name => $name, returning $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (4) Case (3) with *into*: `viz name => field_name, returning into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, returning into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Add `value` to the value of the field `" $fn_name "` within this structure, "
+                    "returning the new value, or `None` and leaving the field unchanged if the "
+                    "addition would overflow."]
+            $fn_vis fn [<checked_add_to_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) -> Option<$value_type> {
+                self.$field_name.checked_add(value.into()).map(|new_value| {
+                    self.$field_name = new_value;
+                    new_value
+                })
+            }
+        }
+    };
+    // Case (4) without *field name*: `viz name => returning into Type`
+    ($fn_vis:vis $name:ident => returning into $value_type:ty) => {
+        $crate::num_checked_add_to!($fn_vis $name => $name, returning into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Numeric saturating_sub_from_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that subtracts a value from an integer field, saturating at the
+/// type's minimum value rather than overflowing.
+///
+/// ## Forms
+///
+/// ### `num_saturating_sub_from!(viz name => [field_name,] [returning] [into] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * If the keyword `returning` is specified the generated function returns the field's new
+///   value.
+/// * If the keyword `into` is specified the type of the value parameter is the trait-bound
+///   type `T: Into<Type>` rather than `Type` for flexibility.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Statistics { bytes_sent: u64 }
+/// impl Statistics {
+///     // num_saturating_sub_from!(pub bytes_sent => returning u64);
+///
+///     /// Subtract `value` from the value of the field `bytes_sent` within this structure,
+///     /// saturating at the type's minimum value, and returning the new value.
+///     pub fn saturating_sub_from_bytes_sent(&mut self, value: u64) -> u64 {
+///         self.bytes_sent = self.bytes_sent.saturating_sub(value);
+///         self.bytes_sent
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! num_saturating_sub_from {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Subtract `value` from the value of the field `" $fn_name "` within this "
+                    "structure, saturating at the type's minimum value."]
+            $fn_vis fn [<saturating_sub_from_ $fn_name>](&mut self, value: $value_type) {
+                self.$field_name = self.$field_name.saturating_sub(value);
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::num_saturating_sub_from!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Subtract `value` from the value of the field `" $fn_name "` within this "
+                    "structure, saturating at the type's minimum value."]
+            $fn_vis fn [<saturating_sub_from_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) {
+                self.$field_name = self.$field_name.saturating_sub(value.into());
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::num_saturating_sub_from!($fn_vis $name => $name, into $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (3) Base case with *returning*: `viz name => field_name, returning Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, returning $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Subtract `value` from the value of the field `" $fn_name "` within this "
+                    "structure, saturating at the type's minimum value, and returning the new value."]
+            $fn_vis fn [<saturating_sub_from_ $fn_name>](&mut self, value: $value_type) -> $value_type {
+                self.$field_name = self.$field_name.saturating_sub(value);
+                self.$field_name
+            }
+        }
+    };
+    // Case (3) without *field name*: `viz name => returning Type`
+    ($fn_vis:vis $name:ident => returning $value_type:ty) => {
+        $crate::num_saturating_sub_from!($fn_vis $name => $name, returning $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (4) Case (3) with *into*: `viz name => field_name, returning into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, returning into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Subtract `value` from the value of the field `" $fn_name "` within this "
+                    "structure, saturating at the type's minimum value, and returning the new value."]
+            $fn_vis fn [<saturating_sub_from_ $fn_name>]<T: Into<$value_type>>(&mut self, value: T) -> $value_type {
+                self.$field_name = self.$field_name.saturating_sub(value.into());
+                self.$field_name
+            }
+        }
+    };
+    // Case (4) without *field name*: `viz name => returning into Type`
+    ($fn_vis:vis $name:ident => returning into $value_type:ty) => {
+        $crate::num_saturating_sub_from!($fn_vis $name => $name, returning into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Numeric reset_<name>
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a method that resets an integer field to its default value, zero.
+///
+/// ## Forms
+///
+/// ### `num_reset!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Statistics { requests: u64 }
+/// impl Statistics {
+///     // num_reset!(pub requests);
+///
+///     /// Reset the value of the field `requests` within this structure to its default value.
+///     pub fn reset_requests(&mut self) {
+///         self.requests = Default::default();
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! num_reset {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Reset the value of the field `" $fn_name "` within this structure to its "
+                    "default value."]
+            $fn_vis fn [<reset_ $fn_name>](&mut self) {
+                self.$field_name = ::core::default::Default::default();
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::num_reset!($fn_vis $name => $name);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::{
+    num_add_to, num_checked_add_to, num_decrement, num_increment, num_reset,
+    num_saturating_sub_from,
+};
@@ -0,0 +1,193 @@
+/*!
+ * The [`pin_get`] macro, for pin-projection accessors over the fields of a pinned structure,
+ * such as a hand-written future or stream.
+ *
+ * A method with the receiver `self: Pin<&mut Self>` cannot simply borrow a field of the
+ * structure; it must *project* the pin, either to a pinned reference `Pin<&mut T>` for a
+ * field that is *structurally pinned*, or to a plain reference `&mut T` for a field that is
+ * not. Both projections require `unsafe` code, and are sound only if each field is
+ * consistently projected in one way. The [`pin_get`] macro generates these projections so
+ * that the `unsafe` code is written once; as the macro cannot check that the projections
+ * are sound each call must begin with the keyword `unsafe`, in the same way as an
+ * `unsafe impl`, to denote that the macro user upholds the requirements below.
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * use std::time::Instant;
+ *
+ * pub struct Timed<F> {
+ *     inner: F,
+ *     started: Option<Instant>,
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro      | field name | keywords         | type              | generated signature                                                 |
+ * |------------|------------|------------------|-------------------|---------------------------------------------------------------------|
+ * | `pin_get!` | inner      | unsafe, pinned   | F                 | `fn inner_pinned(self: Pin<&mut Self>) -> Pin<&mut F>`              |
+ * | `pin_get!` | started    | unsafe, unpinned | `Option<Instant>` | `fn started_unpinned(self: Pin<&mut Self>) -> &mut Option<Instant>` |
+ *
+ * ## Safety
+ *
+ * The generated methods are safe to call, the macro user, by writing `unsafe`, is instead
+ * responsible for the following, which cannot be checked by the macro. Breaking any of these,
+ * for example projecting one field as both `pinned` and `unpinned`, is undefined behavior.
+ *
+ * 1. Each field is projected as *either* `pinned` or `unpinned`, never both, and a
+ *    `pinned` field is never moved, or exposed by `&mut`, by any other method taking a
+ *    pinned `Self`.
+ * 2. The structure does not implement `Unpin` unless the types of all its `pinned` fields
+ *    also implement `Unpin`; the automatically derived implementation satisfies this.
+ * 3. Any `Drop` implementation for the structure does not move a `pinned` field; it should
+ *    be written as if it took `self: Pin<&mut Self>`.
+ * 4. The structure is not `#[repr(packed)]`; this is checked by the compiler for any field
+ *    that would be unaligned.
+ *
+ */
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Pin
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate pin-projection methods for a struct field, for use in methods that take a pinned
+/// receiver `self: Pin<&mut Self>`.
+///
+/// ## Forms
+///
+/// ### `pin_get!(unsafe viz name => [field_name,] pinned Type)`
+///
+/// * The leading keyword `unsafe` is required, it denotes that the macro user upholds the
+///   requirements listed in the [module documentation](crate::field::pin) for this field.
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The keyword `pinned` denotes that the field is *structurally pinned*; the generated
+///   function `name_pinned` projects the pinned structure to the pinned reference
+///   `Pin<&mut Type>`.
+/// * This form is used for fields that are themselves polled, such as an inner future or
+///   stream, which need not implement `Unpin`.
+/// * Each call consumes the pinned reference to self, use `self.as_mut()` to reborrow it
+///   when projecting more than one field.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::pin::Pin;
+/// # pub struct Timed<F> { inner: F }
+/// impl<F> Timed<F> {
+///     // pin_get!(unsafe pub inner => pinned F);
+///
+///     /// Returns a pinned mutable reference to the structurally pinned field `inner` within
+///     /// this structure.
+///     ///
+///     /// The macro user asserted, by `unsafe`, that this field is always projected as pinned
+///     /// and never as unpinned, as required by the
+///     /// [module documentation](crate::field::pin).
+///     pub fn inner_pinned(self: Pin<&mut Self>) -> Pin<&mut F> {
+///         // SAFETY: the macro user asserted, by `unsafe`, that the field `inner` is
+///         // structurally pinned.
+///         unsafe { self.map_unchecked_mut(|this| &mut this.inner) }
+///     }
+/// }
+/// ```
+///
+/// ### `pin_get!(unsafe viz name => [field_name,] unpinned Type)`
+///
+/// * The leading keyword `unsafe` is required, it denotes that the macro user upholds the
+///   requirements listed in the [module documentation](crate::field::pin) for this field.
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The keyword `unpinned` denotes that the field is *not* structurally pinned; the generated
+///   function `name_unpinned` projects the pinned structure to the plain reference
+///   `&mut Type`.
+/// * This form is used for the state of a future or stream, such as counters, buffers, and
+///   deadlines, which may be freely moved and replaced.
+/// * Each call consumes the pinned reference to self, use `self.as_mut()` to reborrow it
+///   when projecting more than one field.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::pin::Pin;
+/// # use std::time::Instant;
+/// # pub struct Timed<F> { inner: F, started: Option<Instant> }
+/// impl<F> Timed<F> {
+///     // pin_get!(unsafe pub started => unpinned Option<Instant>);
+///
+///     /// Returns a mutable reference to the field `started`, which is not structurally
+///     /// pinned, within this structure.
+///     ///
+///     /// The macro user asserted, by `unsafe`, that this field is always projected as
+///     /// unpinned and never as pinned, as required by the
+///     /// [module documentation](crate::field::pin).
+///     pub fn started_unpinned(self: Pin<&mut Self>) -> &mut Option<Instant> {
+///         // SAFETY: the macro user asserted, by `unsafe`, that the field `started` is
+///         // not structurally pinned.
+///         unsafe { &mut self.get_unchecked_mut().started }
+///     }
+/// }
+/// ```
+///
+/// As the projections cannot be checked by the macro, the following fails to compile as the
+/// keyword `unsafe` is missing.
+///
+/// ```rust,compile_fail
+/// use jemmy::*;
+/// pub struct Timed<F> { inner: F }
+/// impl<F> Timed<F> {
+///     pin_get!(pub inner => pinned F);
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! pin_get {
+    // (1) Base case with *pinned*: `unsafe viz name => field_name, pinned Type`
+    (unsafe $fn_vis:vis $fn_name:ident => $field_name:ident, pinned $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a pinned mutable reference to the structurally pinned field `" $fn_name
+                    "` within this structure.\n\nThe macro user asserted, by `unsafe`, that this field is "
+                    "always projected as pinned and never as unpinned, as required by the "
+                    "[module documentation](crate::field::pin)."]
+            $fn_vis fn [<$fn_name _pinned>](
+                self: ::core::pin::Pin<&mut Self>,
+            ) -> ::core::pin::Pin<&mut $value_type> {
+                // SAFETY: the macro user asserted, by `unsafe`, that the field is structurally
+                // pinned.
+                unsafe { self.map_unchecked_mut(|this| &mut this.$field_name) }
+            }
+        }
+    };
+    // Case (1) without *field name*: `unsafe viz name => pinned Type`
+    (unsafe $fn_vis:vis $name:ident => pinned $value_type:ty) => {
+        $crate::pin_get!(unsafe $fn_vis $name => $name, pinned $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *unpinned*: `unsafe viz name => field_name, unpinned Type`
+    (unsafe $fn_vis:vis $fn_name:ident => $field_name:ident, unpinned $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a mutable reference to the field `" $fn_name "`, which is not "
+                    "structurally pinned, within this structure.\n\nThe macro user asserted, by `unsafe`, "
+                    "that this field is always projected as unpinned and never as pinned, as required by "
+                    "the [module documentation](crate::field::pin)."]
+            $fn_vis fn [<$fn_name _unpinned>](self: ::core::pin::Pin<&mut Self>) -> &mut $value_type {
+                // SAFETY: the macro user asserted, by `unsafe`, that the field is not
+                // structurally pinned.
+                unsafe { &mut self.get_unchecked_mut().$field_name }
+            }
+        }
+    };
+    // Case (2) without *field name*: `unsafe viz name => unpinned Type`
+    (unsafe $fn_vis:vis $name:ident => unpinned $value_type:ty) => {
+        $crate::pin_get!(unsafe $fn_vis $name => $name, unpinned $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::pin_get;
@@ -0,0 +1,307 @@
+/*!
+ * Support for smart pointer fields; the keywords `boxed` and `shared`, used by the core
+ * accessor macros, and the [`weak`] macro.
+ *
+ * For a field of type `Box<T>`, `Rc<T>`, or `Arc<T>` the simple forms of [`get`] and [`set`]
+ * expose the pointer itself, so a getter returns `&Box<T>` rather than the `&T` a caller is
+ * interested in. The pointer keywords instead generate accessors in terms of the pointed-to
+ * value.
+ *
+ * * `boxed Type` is used for a field of type `Box<Type>`; getters return `&Type` and
+ *   setters take a `Type` and box it.
+ * * `shared Type` is used for a field of type `Rc<T>` or `Arc<T>`, where `Type` is the
+ *   pointer type itself; getters return `&T` and setters take a `T`. In addition [`get`]
+ *   generates a `name_arc` method returning a clone of the pointer, and [`get_mut`]
+ *   generates a copy-on-write `name_make_mut` method in place of `name_mut`.
+ * * `cow 'a Type` is used for a field of type `Cow<'a, Type>`, such as `Cow<'a, str>` or
+ *   `Cow<'a, [u8]>`; getters return `&Type` and setters take any `Into<Cow<'a, Type>>`, so
+ *   either a borrowed or an owned value. In addition [`get`] generates a consuming
+ *   `into_owned_name` method, and [`get_mut`] generates a clone-on-write `name_to_mut`
+ *   method in place of `name_mut`.
+ *
+ * This module also provides the [`weak`] macro for weak reference fields, such as a parent
+ * back-pointer in a tree, which are not covered by the `optional` keyword.
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * use std::borrow::Cow;
+ * use std::sync::Arc;
+ *
+ * pub struct Handler {
+ *     // ...
+ * }
+ *
+ * #[derive(Clone)]
+ * pub struct Config {
+ *     // ...
+ * }
+ *
+ * pub struct Server {
+ *     handler: Box<Handler>,
+ *     config: Arc<Config>,
+ *     parent: std::rc::Weak<Server>,
+ *     // ...
+ * }
+ *
+ * pub struct Request<'a> {
+ *     path: Cow<'a, str>,
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro      | field name | keywords | type          | generated signature                                              |
+ * |------------|------------|----------|---------------|------------------------------------------------------------------|
+ * | `get!`     | handler    | boxed    | Handler       | `fn handler(&self) -> &Handler`                                  |
+ * | `get_mut!` | handler    | boxed    | Handler       | `fn handler_mut(&mut self) -> &mut Handler`                      |
+ * | `set!`     | handler    | boxed    | Handler       | `fn set_handler(&mut self, value: Handler)`                      |
+ * | `with!`    | handler    | boxed    | Handler       | `fn with_handler(mut self, value: Handler) -> Self`              |
+ * | `get!`     | config     | shared   | `Arc<Config>` | `fn config(&self) -> &Config`                                    |
+ * | `get!`     | config     | shared   | `Arc<Config>` | `fn config_arc(&self) -> Arc<Config>`                            |
+ * | `get_mut!` | config     | shared   | `Arc<Config>` | `fn config_make_mut(&mut self) -> &mut Config`                   |
+ * | `set!`     | config     | shared   | `Arc<Config>` | `fn set_config(&mut self, value: Config)`                        |
+ * | `with!`    | config     | shared   | `Arc<Config>` | `fn with_config(mut self, value: Config) -> Self`                |
+ * | `get!`     | path       | cow 'a   | str           | `fn path(&self) -> &str`                                         |
+ * | `get!`     | path       | cow 'a   | str           | `fn into_owned_path(self) -> String`                             |
+ * | `get_mut!` | path       | cow 'a   | str           | `fn path_to_mut(&mut self) -> &mut String`                       |
+ * | `set!`     | path       | cow 'a   | str           | `fn set_path(&mut self, value: impl Into<Cow<'a, str>>)`         |
+ * | `with!`    | path       | cow 'a   | str           | `fn with_path(mut self, value: impl Into<Cow<'a, str>>) -> Self` |
+ * | `weak!`    | parent     |          | Server        | `fn parent(&self) -> Option<Rc<Server>>`                         |
+ * | `weak!`    | parent     |          | Server        | `fn set_parent(&mut self, value: &Rc<Server>)`                   |
+ * | `weak!`    | parent     |          | Server        | `fn has_parent(&self) -> bool`                                   |
+ * | `weak!`    | parent     |          | Server        | `fn unset_parent(&mut self)`                                     |
+ *
+ */
+
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implemented by the reference-counted pointer types that may be used with the `shared`
+/// keyword; this allows the generated accessors to construct, and copy-on-write, the pointer
+/// without knowing whether it is an `Rc` or an `Arc`.
+///
+pub trait SharedPointer: Clone + Deref {
+    /// Construct a new pointer to `value`.
+    fn new_shared(value: Self::Target) -> Self
+    where
+        Self::Target: Sized;
+
+    /// Returns a mutable reference to the pointer's value, first cloning the value into a new
+    /// allocation if it is shared with other pointers.
+    fn make_mut(this: &mut Self) -> &mut Self::Target
+    where
+        Self::Target: Clone;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Accessor Macros ❱ Weak
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generate getter, setter, predicate, and un-setter methods for a weak reference struct
+/// field.
+///
+/// ## Forms
+///
+/// ### `weak!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated functions, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * `Type` is *not* the type of the field, but the type of the referenced value; the field's
+///   type is `std::rc::Weak<Type>`.
+/// * The getter function upgrades the weak reference, returning `None` if the value has
+///   been dropped, or the field was never set.
+/// * The setter function takes a reference to an `Rc<Type>` and stores a weak reference to
+///   its value, it does not keep the value alive.
+/// * The predicate function `has_name` returns `true` if the getter would return a value.
+/// * The setter and un-setter functions require mutability in the form of a mutable reference
+///   to self; `&mut self`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::rc::{Rc, Weak};
+/// # pub struct Node { parent: Weak<Node> }
+/// impl Node {
+///     // weak!(pub parent => Node);
+///
+///     /// Returns the value referenced by the weak field `parent`, or `None` if the value has
+///     /// been dropped.
+///     pub fn parent(&self) -> Option<Rc<Node>> {
+///         self.parent.upgrade()
+///     }
+///
+///     /// Set the weak field `parent` to reference the value of `value`.
+///     pub fn set_parent(&mut self, value: &Rc<Node>) {
+///         self.parent = Rc::downgrade(value);
+///     }
+///
+///     /// Returns `true` if the value referenced by the weak field `parent` has not been
+///     /// dropped.
+///     pub fn has_parent(&self) -> bool {
+///         self.parent.strong_count() > 0
+///     }
+///
+///     /// Set the weak field `parent` to reference no value.
+///     pub fn unset_parent(&mut self) {
+///         self.parent = Weak::new();
+///     }
+/// }
+/// ```
+///
+/// ### `weak!(viz name => [field_name,] sync Type)`
+///
+/// * The keyword `sync` denotes that the field's type is `std::sync::Weak<Type>`, the getter
+///   returns `Option<Arc<Type>>`, and the setter takes a reference to an `Arc<Type>`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # use std::sync::{Arc, Weak};
+/// # pub struct Node { parent: Weak<Node> }
+/// impl Node {
+///     // weak!(pub parent => sync Node);
+///
+///     /// Returns the value referenced by the weak field `parent`, or `None` if the value has
+///     /// been dropped.
+///     pub fn parent(&self) -> Option<Arc<Node>> {
+///         self.parent.upgrade()
+///     }
+///
+///     /// Set the weak field `parent` to reference the value of `value`.
+///     pub fn set_parent(&mut self, value: &Arc<Node>) {
+///         self.parent = Arc::downgrade(value);
+///     }
+///
+///     /// Returns `true` if the value referenced by the weak field `parent` has not been
+///     /// dropped.
+///     pub fn has_parent(&self) -> bool {
+///         self.parent.strong_count() > 0
+///     }
+///
+///     /// Set the weak field `parent` to reference no value.
+///     pub fn unset_parent(&mut self) {
+///         self.parent = Weak::new();
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! weak {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns the value referenced by the weak field `" $fn_name "`, or `None` if the "
+                    "value has been dropped."]
+            $fn_vis fn $fn_name(&self) -> Option<::std::rc::Rc<$value_type>> {
+                self.$field_name.upgrade()
+            }
+
+            #[doc = "Set the weak field `" $fn_name "` to reference the value of `value`."]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: &::std::rc::Rc<$value_type>) {
+                self.$field_name = ::std::rc::Rc::downgrade(value);
+            }
+
+            #[doc = "Returns `true` if the value referenced by the weak field `" $fn_name "` has not "
+                    "been dropped."]
+            $fn_vis fn [<has_ $fn_name>](&self) -> bool {
+                self.$field_name.strong_count() > 0
+            }
+
+            #[doc = "Set the weak field `" $fn_name "` to reference no value."]
+            $fn_vis fn [<unset_ $fn_name>](&mut self) {
+                self.$field_name = ::std::rc::Weak::new();
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::weak!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *sync*: `viz name => field_name, sync Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, sync $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns the value referenced by the weak field `" $fn_name "`, or `None` if the "
+                    "value has been dropped."]
+            $fn_vis fn $fn_name(&self) -> Option<::std::sync::Arc<$value_type>> {
+                self.$field_name.upgrade()
+            }
+
+            #[doc = "Set the weak field `" $fn_name "` to reference the value of `value`."]
+            $fn_vis fn [<set_ $fn_name>](&mut self, value: &::std::sync::Arc<$value_type>) {
+                self.$field_name = ::std::sync::Arc::downgrade(value);
+            }
+
+            #[doc = "Returns `true` if the value referenced by the weak field `" $fn_name "` has not "
+                    "been dropped."]
+            $fn_vis fn [<has_ $fn_name>](&self) -> bool {
+                self.$field_name.strong_count() > 0
+            }
+
+            #[doc = "Set the weak field `" $fn_name "` to reference no value."]
+            $fn_vis fn [<unset_ $fn_name>](&mut self) {
+                self.$field_name = ::std::sync::Weak::new();
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => sync Type`
+    ($fn_vis:vis $name:ident => sync $value_type:ty) => {
+        $crate::weak!($fn_vis $name => $name, sync $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ SharedPointer
+// ------------------------------------------------------------------------------------------------
+
+impl<T: ?Sized> SharedPointer for Rc<T> {
+    #[inline(always)]
+    fn new_shared(value: T) -> Self
+    where
+        T: Sized,
+    {
+        Rc::new(value)
+    }
+
+    #[inline(always)]
+    fn make_mut(this: &mut Self) -> &mut T
+    where
+        T: Clone,
+    {
+        Rc::make_mut(this)
+    }
+}
+
+impl<T: ?Sized> SharedPointer for Arc<T> {
+    #[inline(always)]
+    fn new_shared(value: T) -> Self
+    where
+        T: Sized,
+    {
+        Arc::new(value)
+    }
+
+    #[inline(always)]
+    fn make_mut(this: &mut Self) -> &mut T
+    where
+        T: Clone,
+    {
+        Arc::make_mut(this)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::weak;
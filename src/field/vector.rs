@@ -1,55 +1,875 @@
 /*!
  * The set of macros for struct fields backed by `Vec<T>`.
+ *
+ * In all of these macros the `Type` given is the type of the vector's elements, `T`, and
+ * *not* the type of the field itself, `Vec<T>`.
+ *
+ * The following struct definition is used as an example in the forms below to demonstrate
+ * the generated code.
+ *
+ * ```rust
+ * pub struct Address {
+ *     street_lines: Vec<String>, // demonstrates keyword 'into'
+ *     unit_numbers: Vec<u32>,    // demonstrates keyword 'copy'
+ *     // ...
+ * }
+ * ```
+ *
+ * ## Summary
+ *
+ * | Macro                 | field name   | keywords | type   | generated signature                                                                        |
+ * |-----------------------|--------------|----------|--------|--------------------------------------------------------------------------------------------|
+ * | `vec_is_empty!`       | street_lines |          |        | `fn is_street_lines_empty(&self) -> bool`                                                  |
+ * | `vec_length!`         | street_lines |          |        | `fn street_lines_length(&self) -> usize`                                                   |
+ * | `vec_contains!`       | street_lines |          | String | `fn street_lines_contains(&self, value: &String) -> bool`                                  |
+ * | `vec_get!`            | street_lines |          | String | `fn street_lines_get(&self, index: usize) -> Option<&String>`                              |
+ * | `vec_get!`            | unit_numbers | copy     | u32    | `fn unit_numbers_get(&self, index: usize) -> Option<u32>`                                  |
+ * | `vec_get_mut!`        | street_lines |          | String | `fn street_lines_get_mut(&mut self, index: usize) -> Option<&mut String>`                  |
+ * | `vec_remove!`         | street_lines |          | String | `fn street_lines_remove(&mut self, index: usize) -> String`                                |
+ * | `vec_push!`           | street_lines |          | String | `fn street_lines_push(&mut self, value: String)`                                           |
+ * | `vec_push!`           | street_lines | into     | String | `fn street_lines_push<T: Into<String>>(&mut self, value: T)`                               |
+ * | `vec_pop!`            | street_lines |          | String | `fn street_lines_pop(&mut self) -> Option<String>`                                         |
+ * | `vec_extend!`         | street_lines |          | String | `fn street_lines_extend<I: IntoIterator<Item = String>>(&mut self, values: I)`             |
+ * | `vec_extend!`         | street_lines | into     | String | `fn street_lines_extend<T: Into<String>, I: IntoIterator<Item = T>>(&mut self, values: I)` |
+ * | `vec_clear!`          | street_lines |          |        | `fn street_lines_clear(&mut self)`                                                         |
+ * | `vec_iter!`           | street_lines |          | String | `fn street_lines_iter(&self) -> impl Iterator<Item = &String>`                             |
+ * | `vec_iter!`           | unit_numbers | copy     | u32    | `fn unit_numbers_iter(&self) -> impl Iterator<Item = u32> + '_`                            |
+ * | `vec_iter_mut!`       | street_lines |          | String | `fn street_lines_iter_mut(&mut self) -> impl Iterator<Item = &mut String>`                 |
+ * | `vec_with_from_iter!` | street_lines |          | String | `fn with_street_lines_from_iter<I: IntoIterator<Item = String>>(mut self, values: I) -> Self` |
+ *
  */
 
 // ------------------------------------------------------------------------------------------------
 // Accessor Macros ❱ Vector is_<name>_empty
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Generate a predicate method that returns `true` if a vector field is empty.
+///
+/// ## Forms
+///
+/// ### `vec_is_empty!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_is_empty!(pub street_lines);
+///
+///     /// Returns `true` if the vector field `street_lines` contains no elements, else `false`.
+///     pub fn is_street_lines_empty(&self) -> bool {
+///         self.street_lines.is_empty()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! vec_is_empty {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Returns `true` if the vector field `" $fn_name "` contains no elements, else `false`."]
+            $fn_vis fn [<is_ $fn_name _empty>](&self) -> bool {
+                self.$field_name.is_empty()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::vec_is_empty!($fn_vis $name => $name);
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Accessor Macros ❱ Vector <name>_length
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Generate a method that returns the number of elements in a vector field.
+///
+/// ## Forms
+///
+/// ### `vec_length!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_length!(pub street_lines);
+///
+///     /// Returns the number of elements in the vector field `street_lines`.
+///     pub fn street_lines_length(&self) -> usize {
+///         self.street_lines.len()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! vec_length {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Returns the number of elements in the vector field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _length>](&self) -> usize {
+                self.$field_name.len()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::vec_length!($fn_vis $name => $name);
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Accessor Macros ❱ Vector <name>_contains
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Generate a predicate method that returns `true` if a vector field contains a given value.
+///
+/// ## Forms
+///
+/// ### `vec_contains!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The element type `Type` must implement `PartialEq`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_contains!(pub street_lines => String);
+///
+///     /// Returns `true` if the vector field `street_lines` contains an element equal
+///     /// to `value`, else `false`.
+///     pub fn street_lines_contains(&self, value: &String) -> bool {
+///         self.street_lines.contains(value)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! vec_contains {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns `true` if the vector field `" $fn_name "` contains an element equal to `value`, else `false`."]
+            $fn_vis fn [<$fn_name _contains>](&self, value: &$value_type) -> bool {
+                self.$field_name.contains(value)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::vec_contains!($fn_vis $name => $name, $value_type);
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Accessor Macros ❱ Vector <name>_get
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Generate an indexed getter method for a vector field.
+///
+/// ## Forms
+///
+/// ### `vec_get!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is the reference `Option<&Type>`, it is `None`
+///   if `index` is out of bounds.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_get!(pub street_lines => String);
+///
+///     /// Returns a reference to the element at `index` in the vector field `street_lines`,
+///     /// or `None` if `index` is out of bounds.
+///     pub fn street_lines_get(&self, index: usize) -> Option<&String> {
+///         self.street_lines.get(index)
+///     }
+/// }
+/// ```
+///
+/// ### `vec_get!(viz name => [field_name,] copy Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is the value `Option<Type>`, assuming `Type`
+///   implements `Copy`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { unit_numbers: Vec<u32> }
+/// impl Address {
+///     // vec_get!(pub unit_numbers => copy u32);
+///
+///     /// Returns a copy of the element at `index` in the vector field `unit_numbers`,
+///     /// or `None` if `index` is out of bounds.
+///     pub fn unit_numbers_get(&self, index: usize) -> Option<u32> {
+///         self.unit_numbers.get(index).copied()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! vec_get {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a reference to the element at `index` in the vector field `" $fn_name
+                    "`, or `None` if `index` is out of bounds. The returned value is an optional "
+                    "immutable reference `Option<&" $value_type ">`."]
+            $fn_vis fn [<$fn_name _get>](&self, index: usize) -> Option<&$value_type> {
+                self.$field_name.get(index)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::vec_get!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *copy*: `viz name => field_name, copy Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a copy of the element at `index` in the vector field `" $fn_name
+                    "`, or `None` if `index` is out of bounds. The returned value is an optional "
+                    "copy `Option<" $value_type ">`."]
+            $fn_vis fn [<$fn_name _get>](&self, index: usize) -> Option<$value_type> {
+                self.$field_name.get(index).copied()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => copy Type`
+    ($fn_vis:vis $name:ident => copy $value_type:ty) => {
+        $crate::vec_get!($fn_vis $name => $name, copy $value_type);
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Accessor Macros ❱ Vector <name>_get_mut
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Generate an indexed mutable getter method for a vector field.
+///
+/// ## Forms
+///
+/// ### `vec_get_mut!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the generated function is the reference `Option<&mut Type>`, it is `None`
+///   if `index` is out of bounds.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_get_mut!(pub street_lines => String);
+///
+///     /// Returns a *mutable* reference to the element at `index` in the vector field
+///     /// `street_lines`, or `None` if `index` is out of bounds.
+///     pub fn street_lines_get_mut(&mut self, index: usize) -> Option<&mut String> {
+///         self.street_lines.get_mut(index)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! vec_get_mut {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns a *mutable* reference to the element at `index` in the vector field `" $fn_name
+                    "`, or `None` if `index` is out of bounds. The returned value is an optional "
+                    "mutable reference `Option<&mut " $value_type ">`."]
+            $fn_vis fn [<$fn_name _get_mut>](&mut self, index: usize) -> Option<&mut $value_type> {
+                self.$field_name.get_mut(index)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::vec_get_mut!($fn_vis $name => $name, $value_type);
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Accessor Macros ❱ Vector <name>_remove
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Generate a method that removes, and returns, the element at a given index in a vector field.
+///
+/// ## Forms
+///
+/// ### `vec_remove!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * As with `Vec::remove` the generated function will panic if `index` is out of bounds.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_remove!(pub street_lines => String);
+///
+///     /// Removes and returns the element at `index` in the vector field `street_lines`,
+///     /// shifting all elements after it to the left.
+///     ///
+///     /// # Panics
+///     ///
+///     /// Panics if `index` is out of bounds.
+///     pub fn street_lines_remove(&mut self, index: usize) -> String {
+///         self.street_lines.remove(index)
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! vec_remove {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Removes and returns the element at `index` in the vector field `" $fn_name
+                    "`, shifting all elements after it to the left."]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = ""]
+            #[doc = "Panics if `index` is out of bounds."]
+            $fn_vis fn [<$fn_name _remove>](&mut self, index: usize) -> $value_type {
+                self.$field_name.remove(index)
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::vec_remove!($fn_vis $name => $name, $value_type);
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Accessor Macros ❱ Vector <name>_push
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Generate a method that appends an element to the end of a vector field.
+///
+/// ## Forms
+///
+/// ### `vec_push!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the new value parameter is the value type `Type`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_push!(pub street_lines => String);
+///
+///     /// Appends `value` to the end of the vector field `street_lines`.
+///     pub fn street_lines_push(&mut self, value: String) {
+///         self.street_lines.push(value);
+///     }
+/// }
+/// ```
+///
+/// ### `vec_push!(viz name => [field_name,] into Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the new value parameter is the trait-bound type `T: Into<Type>` rather
+///   than `Type` for flexibility.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_push!(pub street_lines => into String);
+///
+///     /// Appends `value` to the end of the vector field `street_lines`.
+///     pub fn street_lines_push<T: Into<String>>(&mut self, value: T) {
+///         self.street_lines.push(value.into());
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! vec_push {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Appends `value` to the end of the vector field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _push>](&mut self, value: $value_type) {
+                self.$field_name.push(value);
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::vec_push!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Appends `value` to the end of the vector field `" $fn_name "` (Using `Into<" $value_type ">`)."]
+            $fn_vis fn [<$fn_name _push>]<T: Into<$value_type>>(&mut self, value: T) {
+                self.$field_name.push(value.into());
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::vec_push!($fn_vis $name => $name, into $value_type);
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Accessor Macros ❱ Vector <name>_pop
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Generate a method that removes, and returns, the last element of a vector field.
+///
+/// ## Forms
+///
+/// ### `vec_pop!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the generated function is `Option<Type>`, it is `None` if the vector is empty.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_pop!(pub street_lines => String);
+///
+///     /// Removes the last element from the vector field `street_lines` and returns it,
+///     /// or `None` if it is empty.
+///     pub fn street_lines_pop(&mut self) -> Option<String> {
+///         self.street_lines.pop()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! vec_pop {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Removes the last element from the vector field `" $fn_name "` and returns it, "
+                    "or `None` if it is empty."]
+            $fn_vis fn [<$fn_name _pop>](&mut self) -> Option<$value_type> {
+                self.$field_name.pop()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::vec_pop!($fn_vis $name => $name, $value_type);
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Accessor Macros ❱ Vector <name>_extend
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Generate a method that appends all the elements of an iterator to a vector field.
+///
+/// ## Forms
+///
+/// ### `vec_extend!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the new values parameter is the trait-bound type `I: IntoIterator<Item = Type>`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_extend!(pub street_lines => String);
+///
+///     /// Appends all the elements of `values` to the end of the vector field `street_lines`.
+///     pub fn street_lines_extend<I: IntoIterator<Item = String>>(&mut self, values: I) {
+///         self.street_lines.extend(values);
+///     }
+/// }
+/// ```
+///
+/// ### `vec_extend!(viz name => [field_name,] into Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the new values parameter is the trait-bound type `I: IntoIterator<Item = T>`
+///   where `T: Into<Type>`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_extend!(pub street_lines => into String);
+///
+///     /// Appends all the elements of `values` to the end of the vector field `street_lines`.
+///     pub fn street_lines_extend<T: Into<String>, I: IntoIterator<Item = T>>(&mut self, values: I) {
+///         self.street_lines.extend(values.into_iter().map(Into::into));
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! vec_extend {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Appends all the elements of `values` to the end of the vector field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _extend>]<I: IntoIterator<Item = $value_type>>(&mut self, values: I) {
+                self.$field_name.extend(values);
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::vec_extend!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Appends all the elements of `values` to the end of the vector field `" $fn_name
+                    "` (Using `Into<" $value_type ">`)."]
+            $fn_vis fn [<$fn_name _extend>]<T: Into<$value_type>, I: IntoIterator<Item = T>>(&mut self, values: I) {
+                self.$field_name.extend(values.into_iter().map(Into::into));
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::vec_extend!($fn_vis $name => $name, into $value_type);
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Accessor Macros ❱ Vector <name>_clear
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Generate a method that removes all elements from a vector field.
+///
+/// ## Forms
+///
+/// ### `vec_clear!(viz name [=> field_name])`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_clear!(pub street_lines);
+///
+///     /// Removes all elements from the vector field `street_lines`.
+///     pub fn street_lines_clear(&mut self) {
+///         self.street_lines.clear();
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! vec_clear {
+    // Base case: `viz name => field_name`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident) => {
+        paste::paste! {
+            #[doc = "Removes all elements from the vector field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _clear>](&mut self) {
+                self.$field_name.clear();
+            }
+        }
+    };
+    // Base case without *field name*: `viz name`
+    ($fn_vis:vis $name:ident) => {
+        $crate::vec_clear!($fn_vis $name => $name);
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Accessor Macros ❱ Vector <name>_iter
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Generate a method that returns an iterator over the elements of a vector field.
+///
+/// ## Forms
+///
+/// ### `vec_iter!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is an iterator over immutable references `&Type`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_iter!(pub street_lines => String);
+///
+///     /// Returns an iterator over references to the elements of the vector field `street_lines`.
+///     pub fn street_lines_iter(&self) -> impl Iterator<Item = &String> {
+///         self.street_lines.iter()
+///     }
+/// }
+/// ```
+///
+/// ### `vec_iter!(viz name => [field_name,] copy Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * The type of the generated function is an iterator over copies of the elements, `Type`,
+///   assuming `Type` implements `Copy`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { unit_numbers: Vec<u32> }
+/// impl Address {
+///     // vec_iter!(pub unit_numbers => copy u32);
+///
+///     /// Returns an iterator over copies of the elements of the vector field `unit_numbers`.
+///     pub fn unit_numbers_iter(&self) -> impl Iterator<Item = u32> + '_ {
+///         self.unit_numbers.iter().copied()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! vec_iter {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns an iterator over references to the elements of the vector field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _iter>](&self) -> impl Iterator<Item = &$value_type> {
+                self.$field_name.iter()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::vec_iter!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *copy*: `viz name => field_name, copy Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, copy $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns an iterator over copies of the elements of the vector field `" $fn_name "`."]
+            $fn_vis fn [<$fn_name _iter>](&self) -> impl Iterator<Item = $value_type> + '_ {
+                self.$field_name.iter().copied()
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => copy Type`
+    ($fn_vis:vis $name:ident => copy $value_type:ty) => {
+        $crate::vec_iter!($fn_vis $name => $name, copy $value_type);
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Accessor Macros ❱ Vector <name>_iter_mut
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Generate a method that returns a mutable iterator over the elements of a vector field.
+///
+/// ## Forms
+///
+/// ### `vec_iter_mut!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a mutable reference to self; `&mut self`.
+/// * The type of the generated function is an iterator over mutable references `&mut Type`.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_iter_mut!(pub street_lines => String);
+///
+///     /// Returns an iterator over *mutable* references to the elements of the vector
+///     /// field `street_lines`.
+///     pub fn street_lines_iter_mut(&mut self) -> impl Iterator<Item = &mut String> {
+///         self.street_lines.iter_mut()
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! vec_iter_mut {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Returns an iterator over *mutable* references to the elements of the vector field `"
+                    $fn_name "`."]
+            $fn_vis fn [<$fn_name _iter_mut>](&mut self) -> impl Iterator<Item = &mut $value_type> {
+                self.$field_name.iter_mut()
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::vec_iter_mut!($fn_vis $name => $name, $value_type);
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Accessor Macros ❱ Vector with_<name>_from_iter
 // ------------------------------------------------------------------------------------------------
+
+///
+/// Generate a builder style initializer method for a vector field that collects the elements
+/// of an iterator.
+///
+/// ## Forms
+///
+/// ### `vec_with_from_iter!(viz name => [field_name,] Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
+///   that initializers can be chained.
+/// * The type of the new values parameter is the trait-bound type `I: IntoIterator<Item = Type>`.
+/// * Any existing elements of the field are replaced.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_with_from_iter!(pub street_lines => String);
+///
+///     /// Set the value of the vector field `street_lines` within this structure from the
+///     /// elements of `values`, usually during construction. This function takes a mutable
+///     /// `self` parameter and returns `Self` allowing it to be chained during construction.
+///     pub fn with_street_lines_from_iter<I: IntoIterator<Item = String>>(mut self, values: I) -> Self {
+///         self.street_lines = values.into_iter().collect();
+///         self
+///     }
+/// }
+/// ```
+///
+/// ### `vec_with_from_iter!(viz name => [field_name,] into Type)`
+///
+/// * In this form `name` is used to name the generated function, while the optional
+///   `field_name` is the name of the structure's field; if not specified `name` is used.
+/// * This form requires mutability in the form of a `mut self`, and returns `Self`, so
+///   that initializers can be chained.
+/// * The type of the new values parameter is the trait-bound type `I: IntoIterator<Item = T>`
+///   where `T: Into<Type>`.
+/// * Any existing elements of the field are replaced.
+///
+/// The following — commented line and following implementation — are therefore equivalent:
+///
+/// ```rust
+/// # pub struct Address { street_lines: Vec<String> }
+/// impl Address {
+///     // vec_with_from_iter!(pub street_lines => into String);
+///
+///     /// Set the value of the vector field `street_lines` within this structure from the
+///     /// elements of `values`, usually during construction. This function takes a mutable
+///     /// `self` parameter and returns `Self` allowing it to be chained during construction.
+///     pub fn with_street_lines_from_iter<T: Into<String>, I: IntoIterator<Item = T>>(mut self, values: I) -> Self {
+///         self.street_lines = values.into_iter().map(Into::into).collect();
+///         self
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! vec_with_from_iter {
+    // Base case: `viz name => field_name, Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the vector field `" $fn_name "` within this structure from the elements "
+                    "of `values`, usually during construction. This function takes a mutable `self` parameter "
+                    "and returns `Self` allowing it to be chained during construction."]
+            $fn_vis fn [<with_ $fn_name _from_iter>]<I: IntoIterator<Item = $value_type>>(mut self, values: I) -> Self {
+                self.$field_name = values.into_iter().collect();
+                self
+            }
+        }
+    };
+    // Base case without *field name*: `viz name => Type`
+    ($fn_vis:vis $name:ident => $value_type:ty) => {
+        $crate::vec_with_from_iter!($fn_vis $name => $name, $value_type);
+    };
+    // - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+    // (2) Base case with *into*: `viz name => field_name, into Type`
+    ($fn_vis:vis $fn_name:ident => $field_name:ident, into $value_type:ty) => {
+        paste::paste! {
+            #[doc = "Set the value of the vector field `" $fn_name "` within this structure from the elements "
+                    "of `values` (Using `Into<" $value_type ">`), usually during construction. This function "
+                    "takes a mutable `self` parameter and returns `Self` allowing it to be chained during "
+                    "construction."]
+            $fn_vis fn [<with_ $fn_name _from_iter>]<T: Into<$value_type>, I: IntoIterator<Item = T>>(mut self, values: I) -> Self {
+                self.$field_name = values.into_iter().map(Into::into).collect();
+                self
+            }
+        }
+    };
+    // Case (2) without *field name*: `viz name => into Type`
+    ($fn_vis:vis $name:ident => into $value_type:ty) => {
+        $crate::vec_with_from_iter!($fn_vis $name => $name, into $value_type);
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Re-export macros
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::{
+    vec_clear, vec_contains, vec_extend, vec_get, vec_get_mut, vec_is_empty, vec_iter,
+    vec_iter_mut, vec_length, vec_pop, vec_push, vec_remove, vec_with_from_iter,
+};
@@ -132,6 +132,8 @@ The elements of these forms are described below.
 
  */
 
+pub mod error;
+
 #[macro_use]
 pub mod field;
 
@@ -0,0 +1,187 @@
+use jemmy::*;
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU64, AtomicUsize};
+use std::sync::Arc;
+use std::thread;
+
+const EX_REQUESTS: u64 = 42;
+const EX_THREADS: usize = 4;
+const EX_INCREMENTS: usize = 1000;
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Atomics ❱ atomic_load!, atomic_store!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_atomic_load_and_store_base_case() {
+    pub struct Metrics {
+        requests: AtomicU64,
+    }
+    impl Metrics {
+        atomic_load!(pub requests => relaxed u64);
+        atomic_store!(pub requests => relaxed u64);
+    }
+
+    let metrics = Metrics {
+        requests: AtomicU64::new(0),
+    };
+    assert_eq!(metrics.requests(), 0);
+    metrics.set_requests(EX_REQUESTS);
+    assert_eq!(metrics.requests(), EX_REQUESTS);
+}
+
+#[test]
+fn test_atomic_load_and_store_base_case_rename() {
+    pub struct Metrics {
+        total_requests: AtomicU64,
+    }
+    impl Metrics {
+        atomic_load!(pub requests => total_requests, acq_rel u64);
+        atomic_store!(pub requests => total_requests, acq_rel u64);
+    }
+
+    let metrics = Metrics {
+        total_requests: AtomicU64::new(0),
+    };
+    metrics.set_requests(EX_REQUESTS);
+    assert_eq!(metrics.requests(), EX_REQUESTS);
+}
+
+#[test]
+fn test_atomic_load_and_store_bool_case() {
+    pub struct Server {
+        shutdown: AtomicBool,
+    }
+    impl Server {
+        atomic_load!(pub shutdown => seq_cst bool);
+        atomic_store!(pub shutdown => seq_cst bool);
+    }
+
+    let server = Server {
+        shutdown: AtomicBool::new(false),
+    };
+    assert!(!server.shutdown());
+    server.set_shutdown(true);
+    assert!(server.shutdown());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Atomics ❱ atomic_fetch_add!, atomic_fetch_sub!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_atomic_fetch_add_base_case() {
+    pub struct Metrics {
+        requests: AtomicU64,
+    }
+    impl Metrics {
+        atomic_load!(pub requests => relaxed u64);
+        atomic_fetch_add!(pub requests => relaxed u64);
+    }
+
+    let metrics = Metrics {
+        requests: AtomicU64::new(EX_REQUESTS),
+    };
+    assert_eq!(metrics.fetch_add_requests(1), EX_REQUESTS);
+    assert_eq!(metrics.requests(), EX_REQUESTS + 1);
+}
+
+#[test]
+fn test_atomic_fetch_sub_base_case_rename() {
+    pub struct Metrics {
+        active_connections: AtomicI32,
+    }
+    impl Metrics {
+        atomic_load!(pub connections => active_connections, acq_rel i32);
+        atomic_fetch_sub!(pub connections => active_connections, acq_rel i32);
+    }
+
+    let metrics = Metrics {
+        active_connections: AtomicI32::new(0),
+    };
+    assert_eq!(metrics.fetch_sub_connections(1), 0);
+    assert_eq!(metrics.connections(), -1);
+}
+
+#[test]
+fn test_atomic_fetch_add_across_threads() {
+    pub struct Metrics {
+        requests: AtomicUsize,
+    }
+    impl Metrics {
+        atomic_load!(pub requests => seq_cst usize);
+        atomic_fetch_add!(pub requests => relaxed usize);
+    }
+
+    let metrics = Arc::new(Metrics {
+        requests: AtomicUsize::new(0),
+    });
+    let handles: Vec<_> = (0..EX_THREADS)
+        .map(|_| {
+            let metrics = Arc::clone(&metrics);
+            thread::spawn(move || {
+                for _ in 0..EX_INCREMENTS {
+                    metrics.fetch_add_requests(1);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(metrics.requests(), EX_THREADS * EX_INCREMENTS);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Atomics ❱ atomic_swap!, atomic_compare_exchange!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_atomic_swap_base_case() {
+    pub struct Server {
+        shutdown: AtomicBool,
+    }
+    impl Server {
+        atomic_swap!(pub shutdown => acq_rel bool);
+    }
+
+    let server = Server {
+        shutdown: AtomicBool::new(false),
+    };
+    assert!(!server.swap_shutdown(true));
+    assert!(server.swap_shutdown(true));
+}
+
+#[test]
+fn test_atomic_compare_exchange_base_case() {
+    pub struct Metrics {
+        requests: AtomicU64,
+    }
+    impl Metrics {
+        atomic_compare_exchange!(pub requests => acq_rel u64);
+    }
+
+    let metrics = Metrics {
+        requests: AtomicU64::new(EX_REQUESTS),
+    };
+    assert_eq!(
+        metrics.compare_exchange_requests(EX_REQUESTS, 0),
+        Ok(EX_REQUESTS)
+    );
+    assert_eq!(metrics.compare_exchange_requests(EX_REQUESTS, 0), Err(0));
+}
+
+#[test]
+fn test_atomic_compare_exchange_base_case_rename() {
+    pub struct Server {
+        is_shutdown: AtomicBool,
+    }
+    impl Server {
+        atomic_compare_exchange!(pub shutdown => is_shutdown, relaxed bool);
+    }
+
+    let server = Server {
+        is_shutdown: AtomicBool::new(false),
+    };
+    assert_eq!(server.compare_exchange_shutdown(false, true), Ok(false));
+    assert_eq!(server.compare_exchange_shutdown(false, true), Err(true));
+}
@@ -0,0 +1,176 @@
+use jemmy::*;
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Bits ❱ bits!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_bits_base_case() {
+    #[derive(Default)]
+    pub struct Header {
+        raw: u32,
+    }
+    impl Header {
+        bits!(pub mode => raw, 4..7, u8);
+    }
+
+    let mut header = Header {
+        raw: 0b1111_0101_1111,
+    };
+    assert_eq!(header.mode(), 0b101);
+
+    header.set_mode(0b010);
+    assert_eq!(header.mode(), 0b010);
+    assert_eq!(header.raw, 0b1111_0010_1111);
+
+    header.set_mode(0);
+    assert_eq!(header.raw, 0b1111_0000_1111);
+}
+
+#[test]
+fn test_bits_base_case_full_type_width() {
+    #[derive(Default)]
+    pub struct Header {
+        raw: u64,
+    }
+    impl Header {
+        bits!(pub length => raw, 16..32, u16);
+        bits!(pub kind => raw, 0..16, u16);
+    }
+
+    let header = Header::default().with_length(u16::MAX).with_kind(0x0102);
+    assert_eq!(header.length(), u16::MAX);
+    assert_eq!(header.kind(), 0x0102);
+    assert_eq!(header.raw, 0xFFFF_0102);
+}
+
+#[test]
+fn test_bits_base_case_full_field_width() {
+    #[derive(Default)]
+    pub struct Header {
+        raw: u32,
+    }
+    impl Header {
+        bits!(pub all => raw, 0..32, u32);
+    }
+
+    let mut header = Header::default();
+    header.set_all(u32::MAX);
+    assert_eq!(header.all(), u32::MAX);
+    assert_eq!(header.raw, u32::MAX);
+    header.set_all(0x1234_5678);
+    assert_eq!(header.all(), 0x1234_5678);
+}
+
+#[test]
+fn test_bits_base_case_full_u128_width() {
+    #[derive(Default)]
+    pub struct Wide {
+        raw: u128,
+    }
+    impl Wide {
+        bits!(pub all => raw, 0..128, u128);
+        bits!(pub top => raw, 64..128, u64);
+    }
+
+    let wide = Wide::default().with_all(u128::MAX);
+    assert_eq!(wide.all(), u128::MAX);
+    assert_eq!(wide.top(), u64::MAX);
+    let wide = wide.with_top(0);
+    assert_eq!(wide.all(), u64::MAX as u128);
+}
+
+#[test]
+fn test_bits_with_case() {
+    #[derive(Default)]
+    pub struct Register {
+        raw: u8,
+    }
+    impl Register {
+        bits!(pub low => raw, 0..4, u8);
+        bits!(pub high => raw, 4..8, u8);
+    }
+
+    let register = Register::default().with_low(0xA).with_high(0x5);
+    assert_eq!(register.raw, 0x5A);
+    assert_eq!(register.low(), 0xA);
+    assert_eq!(register.high(), 0x5);
+}
+
+#[test]
+#[should_panic(expected = "value 8 overflows the bits 4..7 of field `mode`")]
+fn test_bits_overflow_case() {
+    #[derive(Default)]
+    pub struct Header {
+        raw: u32,
+    }
+    impl Header {
+        bits!(pub mode => raw, 4..7, u8);
+    }
+
+    let mut header = Header::default();
+    header.set_mode(8);
+}
+
+#[test]
+fn test_bits_const_case() {
+    pub struct Header {
+        raw: u16,
+    }
+    impl Header {
+        bits!(pub version => raw, 12..16, u8);
+    }
+
+    const HEADER: Header = Header { raw: 0x4000 };
+    const VERSION: u8 = HEADER.version();
+    assert_eq!(VERSION, 4);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Bits ❱ bits! flag
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_bits_flag_case() {
+    #[derive(Default)]
+    pub struct Header {
+        raw: u32,
+    }
+    impl Header {
+        bits!(pub ready => raw, 0, flag);
+        bits!(pub error => raw, 31, flag);
+    }
+
+    let mut header = Header::default();
+    assert!(!header.is_ready());
+
+    header.set_ready(true);
+    assert!(header.is_ready());
+    assert_eq!(header.raw, 1);
+
+    header.set_error(true);
+    assert!(header.is_error());
+    assert_eq!(header.raw, 0x8000_0001);
+
+    header.set_ready(false);
+    assert!(!header.is_ready());
+    assert_eq!(header.raw, 0x8000_0000);
+}
+
+#[test]
+fn test_bits_flag_with_case() {
+    #[derive(Default)]
+    pub struct Header {
+        raw: u8,
+    }
+    impl Header {
+        bits!(pub ready => raw, 3, flag);
+        bits!(pub mode => raw, 0..3, u8);
+    }
+
+    let header = Header::default().with_mode(0b111).with_ready(true);
+    assert_eq!(header.raw, 0b1111);
+    let header = header.with_ready(false);
+    assert_eq!(header.raw, 0b0111);
+    assert_eq!(header.mode(), 0b111);
+}
@@ -0,0 +1,183 @@
+use jemmy::*;
+
+const EX_LENGTH: u16 = 0x0102;
+const EX_CHECKSUM: u32 = 0xDEAD_BEEF;
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Bytes ❱ be_bytes! & le_bytes!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_be_bytes_array_case() {
+    pub struct Packet {
+        buf: [u8; 8],
+    }
+    impl Packet {
+        be_bytes!(pub length => buf, 2, u16);
+    }
+
+    let mut packet = Packet {
+        buf: [0, 0, 0x01, 0x02, 0, 0, 0, 0],
+    };
+    assert_eq!(packet.length(), EX_LENGTH);
+
+    packet.set_length(0xABCD);
+    assert_eq!(packet.buf, [0, 0, 0xAB, 0xCD, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_le_bytes_vec_case() {
+    pub struct Packet {
+        buf: Vec<u8>,
+    }
+    impl Packet {
+        le_bytes!(pub checksum => buf, 4, u32);
+    }
+
+    let mut packet = Packet { buf: vec![0; 8] };
+    packet.set_checksum(EX_CHECKSUM);
+    assert_eq!(packet.buf, vec![0, 0, 0, 0, 0xEF, 0xBE, 0xAD, 0xDE]);
+    assert_eq!(packet.checksum(), EX_CHECKSUM);
+}
+
+#[test]
+fn test_be_bytes_const_offset_case() {
+    const HEADER_LENGTH: usize = 4;
+
+    pub struct Packet {
+        buf: [u8; 12],
+    }
+    impl Packet {
+        be_bytes!(pub payload_length => buf, HEADER_LENGTH + 2, u32);
+    }
+
+    let mut packet = Packet { buf: [0; 12] };
+    packet.set_payload_length(EX_CHECKSUM);
+    assert_eq!(&packet.buf[6..10], &[0xDE, 0xAD, 0xBE, 0xEF]);
+    assert_eq!(packet.payload_length(), EX_CHECKSUM);
+}
+
+#[test]
+#[should_panic]
+fn test_le_bytes_vec_out_of_bounds_case() {
+    pub struct Packet {
+        buf: Vec<u8>,
+    }
+    impl Packet {
+        le_bytes!(pub checksum => buf, 4, u32);
+    }
+
+    let packet = Packet { buf: vec![0; 6] };
+    let _ = packet.checksum();
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Bytes ❱ Shorthand
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_be_u16_case() {
+    pub struct Packet {
+        buf: [u8; 4],
+    }
+    impl Packet {
+        be_u16!(pub length => buf, 2);
+    }
+
+    let mut packet = Packet { buf: [0; 4] };
+    packet.set_length(EX_LENGTH);
+    assert_eq!(packet.buf, [0, 0, 0x01, 0x02]);
+    assert_eq!(packet.length(), EX_LENGTH);
+}
+
+#[test]
+fn test_le_u16_case() {
+    pub struct Packet {
+        buf: [u8; 2],
+    }
+    impl Packet {
+        le_u16!(pub length => buf, 0);
+    }
+
+    let mut packet = Packet { buf: [0; 2] };
+    packet.set_length(EX_LENGTH);
+    assert_eq!(packet.buf, [0x02, 0x01]);
+}
+
+#[test]
+fn test_integer_shorthand_case() {
+    pub struct Packet {
+        buf: [u8; 64],
+    }
+    impl Packet {
+        be_u32!(pub a => buf, 0);
+        le_u64!(pub b => buf, 4);
+        be_u128!(pub c => buf, 12);
+        le_i16!(pub d => buf, 28);
+        be_i32!(pub e => buf, 30);
+        le_i64!(pub f => buf, 34);
+        be_i128!(pub g => buf, 42);
+    }
+
+    let mut packet = Packet { buf: [0; 64] };
+    packet.set_a(u32::MAX - 1);
+    packet.set_b(u64::MAX - 2);
+    packet.set_c(u128::MAX - 3);
+    packet.set_d(i16::MIN);
+    packet.set_e(-5);
+    packet.set_f(i64::MIN + 6);
+    packet.set_g(-7);
+
+    assert_eq!(packet.a(), u32::MAX - 1);
+    assert_eq!(packet.b(), u64::MAX - 2);
+    assert_eq!(packet.c(), u128::MAX - 3);
+    assert_eq!(packet.d(), i16::MIN);
+    assert_eq!(packet.e(), -5);
+    assert_eq!(packet.f(), i64::MIN + 6);
+    assert_eq!(packet.g(), -7);
+    assert_eq!(&packet.buf[30..34], &(-5_i32).to_be_bytes());
+}
+
+#[test]
+fn test_byte_shorthand_case() {
+    pub struct Packet {
+        buf: [u8; 4],
+    }
+    impl Packet {
+        be_u8!(pub version => buf, 0);
+        le_u8!(pub flags => buf, 1);
+        be_i8!(pub offset => buf, 2);
+        le_i8!(pub delta => buf, 3);
+    }
+
+    let mut packet = Packet { buf: [0; 4] };
+    packet.set_version(u8::MAX);
+    packet.set_flags(0x5A);
+    packet.set_offset(i8::MIN);
+    packet.set_delta(-1);
+
+    assert_eq!(packet.version(), u8::MAX);
+    assert_eq!(packet.flags(), 0x5A);
+    assert_eq!(packet.offset(), i8::MIN);
+    assert_eq!(packet.delta(), -1);
+    assert_eq!(packet.buf, [0xFF, 0x5A, 0x80, 0xFF]);
+}
+
+#[test]
+fn test_float_shorthand_case() {
+    pub struct Sample {
+        buf: Vec<u8>,
+    }
+    impl Sample {
+        be_f32!(pub gain => buf, 0);
+        le_f64!(pub offset => buf, 4);
+    }
+
+    let mut sample = Sample { buf: vec![0; 12] };
+    sample.set_gain(1.5);
+    sample.set_offset(-0.25);
+    assert_eq!(sample.gain(), 1.5);
+    assert_eq!(sample.offset(), -0.25);
+    assert_eq!(&sample.buf[0..4], &1.5_f32.to_be_bytes());
+    assert_eq!(&sample.buf[4..12], &(-0.25_f64).to_le_bytes());
+}
@@ -0,0 +1,193 @@
+use jemmy::*;
+use std::cell::{Cell, RefCell};
+
+const EX_VISITS: u32 = 42;
+const EX_CHILD_1: &str = "left";
+const EX_CHILD_2: &str = "right";
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Cells ❱ cell_get!, cell_set! & cell_replace!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_cell_get_base_case() {
+    pub struct Node {
+        visits: Cell<u32>,
+    }
+    impl Node {
+        cell_get!(pub visits => u32);
+    }
+
+    let node = Node {
+        visits: Cell::new(EX_VISITS),
+    };
+    assert_eq!(node.visits(), EX_VISITS);
+}
+
+#[test]
+fn test_cell_get_base_case_rename() {
+    pub struct Node {
+        visit_count: Cell<u32>,
+    }
+    impl Node {
+        cell_get!(pub visits => visit_count, u32);
+    }
+
+    let node = Node {
+        visit_count: Cell::new(EX_VISITS),
+    };
+    assert_eq!(node.visits(), EX_VISITS);
+}
+
+#[test]
+fn test_cell_set_base_case() {
+    pub struct Node {
+        visits: Cell<u32>,
+    }
+    impl Node {
+        cell_set!(pub visits => u32);
+    }
+
+    let node = Node {
+        visits: Cell::new(0),
+    };
+    node.set_visits(EX_VISITS);
+    assert_eq!(node.visits.get(), EX_VISITS);
+}
+
+#[test]
+fn test_cell_set_into_case_rename() {
+    pub struct Node {
+        visit_count: Cell<u64>,
+    }
+    impl Node {
+        cell_set!(pub visits => visit_count, into u64);
+    }
+
+    let node = Node {
+        visit_count: Cell::new(0),
+    };
+    node.set_visits(EX_VISITS);
+    assert_eq!(node.visit_count.get(), EX_VISITS as u64);
+}
+
+#[test]
+fn test_cell_replace_base_case() {
+    pub struct Node {
+        visits: Cell<u32>,
+    }
+    impl Node {
+        cell_replace!(pub visits => u32);
+    }
+
+    let node = Node {
+        visits: Cell::new(1),
+    };
+    assert_eq!(node.replace_visits(EX_VISITS), 1);
+    assert_eq!(node.visits.get(), EX_VISITS);
+}
+
+#[test]
+fn test_cell_replace_into_case() {
+    pub struct Node {
+        label: Cell<Option<&'static str>>,
+    }
+    impl Node {
+        cell_replace!(pub label => into Option<&'static str>);
+    }
+
+    let node = Node {
+        label: Cell::new(None),
+    };
+    assert_eq!(node.replace_label(EX_CHILD_1), None);
+    assert_eq!(node.replace_label(EX_CHILD_2), Some(EX_CHILD_1));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Cells ❱ refcell_get!, refcell_get_mut!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_refcell_get_base_case() {
+    pub struct Node {
+        children: RefCell<Vec<String>>,
+    }
+    impl Node {
+        refcell_get!(pub children => Vec<String>);
+    }
+
+    let node = Node {
+        children: RefCell::new(vec![EX_CHILD_1.to_string()]),
+    };
+    assert_eq!(*node.children(), vec![EX_CHILD_1.to_string()]);
+}
+
+#[test]
+fn test_refcell_get_mut_base_case_rename() {
+    pub struct Node {
+        kids: RefCell<Vec<String>>,
+    }
+    impl Node {
+        refcell_get!(pub children => kids, Vec<String>);
+        refcell_get_mut!(pub children => kids, Vec<String>);
+    }
+
+    let node = Node {
+        kids: RefCell::new(vec![EX_CHILD_1.to_string()]),
+    };
+    node.children_mut().push(EX_CHILD_2.to_string());
+    assert_eq!(
+        *node.children(),
+        vec![EX_CHILD_1.to_string(), EX_CHILD_2.to_string()]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_refcell_get_while_mutably_borrowed() {
+    pub struct Node {
+        children: RefCell<Vec<String>>,
+    }
+    impl Node {
+        refcell_get!(pub children => Vec<String>);
+        refcell_get_mut!(pub children => Vec<String>);
+    }
+
+    let node = Node {
+        children: RefCell::new(Vec::new()),
+    };
+    let _borrowed = node.children_mut();
+    let _ = node.children();
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Cells ❱ refcell_try_get!, refcell_try_get_mut!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_refcell_try_get_base_case() {
+    pub struct Node {
+        children: RefCell<Vec<String>>,
+    }
+    impl Node {
+        refcell_try_get!(pub children => Vec<String>);
+        refcell_try_get_mut!(pub children => Vec<String>);
+    }
+
+    let node = Node {
+        children: RefCell::new(Vec::new()),
+    };
+    {
+        let borrowed = node.try_children_mut().unwrap();
+        assert!(node.try_children().is_err());
+        assert!(node.try_children_mut().is_err());
+        drop(borrowed);
+    }
+    {
+        let borrowed = node.try_children().unwrap();
+        assert!(node.try_children().is_ok());
+        assert!(node.try_children_mut().is_err());
+        drop(borrowed);
+    }
+    assert!(node.try_children_mut().is_ok());
+}
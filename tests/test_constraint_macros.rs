@@ -0,0 +1,337 @@
+use jemmy::error::{Constraint, ValidationError};
+use jemmy::*;
+
+const EX_AGE: u8 = 42;
+const EX_NAME: &str = "Jemmy Button";
+const EX_EMAIL: &str = "jemmy@example.com";
+
+fn is_email(value: &str) -> bool {
+    value.contains('@')
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Errors ❱ ValidationError
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_validation_error_display() {
+    let error = ValidationError::new("age", Constraint::Range, "value 200 is out of range");
+    assert_eq!(error.field(), "age");
+    assert_eq!(error.constraint(), Constraint::Range);
+    assert_eq!(error.message(), "value 200 is out of range");
+    assert_eq!(
+        error.to_string(),
+        "invalid value for field `age`, constraint range: value 200 is out of range"
+    );
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Constraints ❱ range
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_set_range_case() {
+    #[derive(Default)]
+    pub struct Person {
+        age: u8,
+    }
+    impl Person {
+        set!(pub age => u8, range 0..=150);
+    }
+
+    let mut person = Person::default();
+    assert!(person.try_set_age(EX_AGE).is_ok());
+    assert_eq!(person.age, EX_AGE);
+
+    let error = person.try_set_age(200).unwrap_err();
+    assert_eq!(error.field(), "age");
+    assert_eq!(error.constraint(), Constraint::Range);
+    assert_eq!(error.message(), "value 200 is not within the range 0..=150");
+    assert_eq!(person.age, EX_AGE);
+}
+
+#[test]
+fn test_set_range_case_rename() {
+    #[derive(Default)]
+    pub struct Order {
+        qty: u32,
+    }
+    impl Order {
+        set!(pub quantity => qty, u32, range 1..);
+    }
+
+    let mut order = Order::default();
+    let error = order.try_set_quantity(0).unwrap_err();
+    assert_eq!(error.field(), "quantity");
+    assert!(order.try_set_quantity(12).is_ok());
+    assert_eq!(order.qty, 12);
+}
+
+#[test]
+fn test_with_range_optional_case() {
+    #[derive(Debug, Default)]
+    pub struct Person {
+        age: Option<u8>,
+    }
+    impl Person {
+        with!(pub age => optional u8, range 0..=150);
+    }
+
+    assert!(Person::default().try_with_age(151).is_err());
+    let person = Person::default().try_with_age(EX_AGE).unwrap();
+    assert_eq!(person.age, Some(EX_AGE));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Constraints ❱ non_empty
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_set_non_empty_case() {
+    #[derive(Default)]
+    pub struct Person {
+        name: String,
+    }
+    impl Person {
+        set!(pub name => into String, non_empty);
+    }
+
+    let mut person = Person::default();
+    let error = person.try_set_name("").unwrap_err();
+    assert_eq!(error.constraint(), Constraint::NonEmpty);
+    assert!(person.try_set_name(EX_NAME).is_ok());
+    assert_eq!(person.name, EX_NAME);
+}
+
+#[test]
+fn test_set_non_empty_string_case() {
+    #[derive(Default)]
+    pub struct Person {
+        name: String,
+    }
+    impl Person {
+        set!(pub name => String, non_empty);
+    }
+
+    let mut person = Person::default();
+    let error = person.try_set_name(String::new()).unwrap_err();
+    assert_eq!(error.field(), "name");
+    assert_eq!(error.constraint(), Constraint::NonEmpty);
+    assert!(person.try_set_name(EX_NAME.to_string()).is_ok());
+    assert_eq!(person.name, EX_NAME);
+}
+
+#[test]
+fn test_with_non_empty_string_case() {
+    #[derive(Debug, Default)]
+    pub struct Person {
+        name: String,
+    }
+    impl Person {
+        with!(pub name => String, non_empty);
+    }
+
+    assert!(Person::default().try_with_name(String::new()).is_err());
+    let person = Person::default()
+        .try_with_name(EX_NAME.to_string())
+        .unwrap();
+    assert_eq!(person.name, EX_NAME);
+}
+
+#[test]
+fn test_set_non_empty_vec_case() {
+    #[derive(Default)]
+    pub struct Person {
+        nick_names: Vec<String>,
+    }
+    impl Person {
+        set!(pub nick_names => Vec<String>, non_empty);
+    }
+
+    let mut person = Person::default();
+    assert!(person.try_set_nick_names(Vec::new()).is_err());
+    assert!(person.try_set_nick_names(vec![EX_NAME.to_string()]).is_ok());
+    assert_eq!(person.nick_names.len(), 1);
+}
+
+#[test]
+fn test_with_non_empty_optional_into_case_rename() {
+    #[derive(Debug, Default)]
+    pub struct Person {
+        middle: Option<String>,
+    }
+    impl Person {
+        with!(pub middle_name => middle, optional into String, non_empty);
+    }
+
+    assert!(Person::default().try_with_middle_name("").is_err());
+    let person = Person::default().try_with_middle_name(EX_NAME).unwrap();
+    assert_eq!(person.middle, Some(EX_NAME.to_string()));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Constraints ❱ max_len
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_set_max_len_case() {
+    #[derive(Default)]
+    pub struct Person {
+        name: String,
+    }
+    impl Person {
+        set!(pub name => String, max_len 12);
+    }
+
+    let mut person = Person::default();
+    assert!(person.try_set_name(EX_NAME.to_string()).is_ok());
+    let error = person.try_set_name(format!("{EX_NAME}!")).unwrap_err();
+    assert_eq!(error.constraint(), Constraint::MaxLength);
+    assert_eq!(
+        error.message(),
+        "value has length 13, greater than the maximum 12"
+    );
+    assert_eq!(person.name, EX_NAME);
+}
+
+#[test]
+fn test_set_max_len_counts_chars_case() {
+    #[derive(Default)]
+    pub struct Person {
+        name: String,
+    }
+    impl Person {
+        set!(pub name => into String, max_len 4);
+    }
+
+    let mut person = Person::default();
+    assert!(person.try_set_name("Zoë").is_ok());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Constraints ❱ matches
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_set_matches_case() {
+    #[derive(Default)]
+    pub struct Person {
+        email: String,
+    }
+    impl Person {
+        set!(pub email => into String, matches is_email);
+    }
+
+    let mut person = Person::default();
+    let error = person.try_set_email(EX_NAME).unwrap_err();
+    assert_eq!(error.constraint(), Constraint::Matches);
+    assert_eq!(error.message(), "value is not accepted by `is_email`");
+    assert!(person.try_set_email(EX_EMAIL).is_ok());
+    assert_eq!(person.email, EX_EMAIL);
+}
+
+#[test]
+fn test_set_matches_path_case() {
+    #[derive(Default)]
+    pub struct Person {
+        age: u8,
+    }
+    impl Person {
+        fn is_adult(value: &u8) -> bool {
+            *value >= 18
+        }
+        set!(pub age => u8, matches Self::is_adult);
+    }
+
+    let mut person = Person::default();
+    assert!(person.try_set_age(12).is_err());
+    assert!(person.try_set_age(EX_AGE).is_ok());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Constraints ❱ Combinators
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_get_and_set_range_copy_case() {
+    #[derive(Default)]
+    pub struct Person {
+        age: u8,
+    }
+    impl Person {
+        get_and_set!(pub age => copy u8, range 0..=150);
+    }
+
+    let mut person = Person::default();
+    assert!(person.try_set_age(EX_AGE).is_ok());
+    assert_eq!(person.age(), EX_AGE);
+    assert!(person.try_set_age(151).is_err());
+    assert_eq!(person.age(), EX_AGE);
+}
+
+#[test]
+fn test_get_and_set_matches_optional_case() {
+    #[derive(Default)]
+    pub struct Person {
+        email: Option<String>,
+    }
+    impl Person {
+        get_and_set!(pub email => optional String, matches is_email);
+    }
+
+    let mut person = Person::default();
+    assert!(person.try_set_email(EX_NAME.to_string()).is_err());
+    assert_eq!(person.email(), None);
+    assert!(person.try_set_email(EX_EMAIL.to_string()).is_ok());
+    assert_eq!(person.email(), Some(&EX_EMAIL.to_string()));
+}
+
+#[test]
+fn test_with_get_and_set_max_len_into_case() {
+    #[derive(Debug, Default)]
+    pub struct Person {
+        name: String,
+    }
+    impl Person {
+        with_get_and_set!(pub name => into String, max_len 64);
+    }
+
+    let mut person = Person::default().try_with_name(EX_NAME).unwrap();
+    assert_eq!(person.name(), EX_NAME);
+    assert!(person.try_set_name("x".repeat(65)).is_err());
+    assert_eq!(person.name(), EX_NAME);
+}
+
+#[test]
+fn test_get_and_set_non_empty_string_case() {
+    #[derive(Default)]
+    pub struct Person {
+        name: String,
+    }
+    impl Person {
+        get_and_set!(pub name => String, non_empty);
+    }
+
+    let mut person = Person::default();
+    assert!(person.try_set_name(String::new()).is_err());
+    assert!(person.try_set_name(EX_NAME.to_string()).is_ok());
+    assert_eq!(person.name(), EX_NAME);
+}
+
+#[test]
+fn test_with_get_and_set_non_empty_string_case() {
+    #[derive(Debug, Default)]
+    pub struct Person {
+        name: String,
+    }
+    impl Person {
+        with_get_and_set!(pub name => String, non_empty);
+    }
+
+    let mut person = Person::default()
+        .try_with_name(EX_NAME.to_string())
+        .unwrap();
+    assert_eq!(person.name(), EX_NAME);
+    assert!(person.try_set_name(String::new()).is_err());
+    assert_eq!(person.name(), EX_NAME);
+}
@@ -0,0 +1,423 @@
+use jemmy::*;
+use std::collections::VecDeque;
+
+const EX_STREET_LINE_1: &str = "2000 Pennsylvania Ave";
+const EX_STREET_LINE_2: &str = "Apt 24";
+const EX_STREET_LINE_3: &str = "Floor 3";
+const EX_UNIT_NUMBER_1: u32 = 24;
+const EX_UNIT_NUMBER_2: u32 = 202;
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Deques ❱ deque_push_front!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_deque_push_front_base_case() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_push_front!(pub street => String);
+    }
+
+    let mut address = Address {
+        street: VecDeque::from([EX_STREET_LINE_2.to_string()]),
+    };
+    address.street_push_front(EX_STREET_LINE_1.to_string());
+    assert_eq!(
+        address.street,
+        VecDeque::from([EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()])
+    );
+}
+
+#[test]
+fn test_deque_push_front_base_case_rename() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_push_front!(pub lines => street, String);
+    }
+
+    let mut address = Address {
+        street: VecDeque::new(),
+    };
+    address.lines_push_front(EX_STREET_LINE_1.to_string());
+    assert_eq!(
+        address.street,
+        VecDeque::from([EX_STREET_LINE_1.to_string()])
+    );
+}
+
+#[test]
+fn test_deque_push_front_into_case() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_push_front!(pub street => into String);
+    }
+
+    let mut address = Address {
+        street: VecDeque::new(),
+    };
+    address.street_push_front(EX_STREET_LINE_2);
+    address.street_push_front(EX_STREET_LINE_1);
+    assert_eq!(
+        address.street,
+        VecDeque::from([EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()])
+    );
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Deques ❱ deque_push_back!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_deque_push_back_base_case() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_push_back!(pub street => String);
+    }
+
+    let mut address = Address {
+        street: VecDeque::from([EX_STREET_LINE_1.to_string()]),
+    };
+    address.street_push_back(EX_STREET_LINE_2.to_string());
+    assert_eq!(
+        address.street,
+        VecDeque::from([EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()])
+    );
+}
+
+#[test]
+fn test_deque_push_back_base_case_rename() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_push_back!(pub lines => street, String);
+    }
+
+    let mut address = Address {
+        street: VecDeque::new(),
+    };
+    address.lines_push_back(EX_STREET_LINE_1.to_string());
+    assert_eq!(
+        address.street,
+        VecDeque::from([EX_STREET_LINE_1.to_string()])
+    );
+}
+
+#[test]
+fn test_deque_push_back_into_case() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_push_back!(pub street => into String);
+    }
+
+    let mut address = Address {
+        street: VecDeque::new(),
+    };
+    address.street_push_back(EX_STREET_LINE_1);
+    address.street_push_back(EX_STREET_LINE_2);
+    assert_eq!(
+        address.street,
+        VecDeque::from([EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()])
+    );
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Deques ❱ deque_pop_front!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_deque_pop_front_base_case() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_pop_front!(pub street => String);
+    }
+
+    let mut address = Address {
+        street: VecDeque::from([EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()]),
+    };
+    assert_eq!(
+        address.street_pop_front(),
+        Some(EX_STREET_LINE_1.to_string())
+    );
+    assert_eq!(
+        address.street_pop_front(),
+        Some(EX_STREET_LINE_2.to_string())
+    );
+    assert_eq!(address.street_pop_front(), None);
+}
+
+#[test]
+fn test_deque_pop_front_base_case_rename() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_pop_front!(pub lines => street, String);
+    }
+
+    let mut address = Address {
+        street: VecDeque::from([EX_STREET_LINE_1.to_string()]),
+    };
+    assert_eq!(
+        address.lines_pop_front(),
+        Some(EX_STREET_LINE_1.to_string())
+    );
+    assert!(address.street.is_empty());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Deques ❱ deque_pop_back!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_deque_pop_back_base_case() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_pop_back!(pub street => String);
+    }
+
+    let mut address = Address {
+        street: VecDeque::from([EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()]),
+    };
+    assert_eq!(
+        address.street_pop_back(),
+        Some(EX_STREET_LINE_2.to_string())
+    );
+    assert_eq!(
+        address.street_pop_back(),
+        Some(EX_STREET_LINE_1.to_string())
+    );
+    assert_eq!(address.street_pop_back(), None);
+}
+
+#[test]
+fn test_deque_pop_back_base_case_rename() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_pop_back!(pub lines => street, String);
+    }
+
+    let mut address = Address {
+        street: VecDeque::from([EX_STREET_LINE_1.to_string()]),
+    };
+    assert_eq!(address.lines_pop_back(), Some(EX_STREET_LINE_1.to_string()));
+    assert!(address.street.is_empty());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Deques ❱ deque_front!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_deque_front_base_case() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_front!(pub street => String);
+    }
+
+    let mut address = Address {
+        street: VecDeque::from([EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()]),
+    };
+    assert_eq!(address.street_front(), Some(&EX_STREET_LINE_1.to_string()));
+    address.street.clear();
+    assert_eq!(address.street_front(), None);
+}
+
+#[test]
+fn test_deque_front_base_case_rename() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_front!(pub first_line => street, String);
+    }
+
+    let address = Address {
+        street: VecDeque::from([EX_STREET_LINE_1.to_string()]),
+    };
+    assert_eq!(
+        address.first_line_front(),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+}
+
+#[test]
+fn test_deque_front_copy_case() {
+    pub struct Address {
+        units: VecDeque<u32>,
+    }
+    impl Address {
+        deque_front!(pub units => copy u32);
+    }
+
+    let address = Address {
+        units: VecDeque::from([EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2]),
+    };
+    assert_eq!(address.units_front(), Some(EX_UNIT_NUMBER_1));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Deques ❱ deque_back!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_deque_back_base_case() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_back!(pub street => String);
+    }
+
+    let mut address = Address {
+        street: VecDeque::from([EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()]),
+    };
+    assert_eq!(address.street_back(), Some(&EX_STREET_LINE_2.to_string()));
+    address.street.clear();
+    assert_eq!(address.street_back(), None);
+}
+
+#[test]
+fn test_deque_back_base_case_rename() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_back!(pub last_line => street, String);
+    }
+
+    let address = Address {
+        street: VecDeque::from([EX_STREET_LINE_1.to_string()]),
+    };
+    assert_eq!(
+        address.last_line_back(),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+}
+
+#[test]
+fn test_deque_back_copy_case() {
+    pub struct Address {
+        units: VecDeque<u32>,
+    }
+    impl Address {
+        deque_back!(pub units => copy u32);
+    }
+
+    let address = Address {
+        units: VecDeque::from([EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2]),
+    };
+    assert_eq!(address.units_back(), Some(EX_UNIT_NUMBER_2));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Deques ❱ deque_len!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_deque_len_base_case() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_len!(pub street);
+    }
+
+    let address = Address {
+        street: VecDeque::from([
+            EX_STREET_LINE_1.to_string(),
+            EX_STREET_LINE_2.to_string(),
+            EX_STREET_LINE_3.to_string(),
+        ]),
+    };
+    assert_eq!(address.street_len(), 3);
+}
+
+#[test]
+fn test_deque_len_base_case_rename() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_len!(pub lines => street);
+    }
+
+    let address = Address {
+        street: VecDeque::new(),
+    };
+    assert_eq!(address.lines_len(), 0);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Deques ❱ deque_iter!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_deque_iter_base_case() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_iter!(pub street => String);
+    }
+
+    let mut address = Address {
+        street: VecDeque::from([EX_STREET_LINE_2.to_string()]),
+    };
+    address.street.push_front(EX_STREET_LINE_1.to_string());
+    assert_eq!(
+        address.street_iter().collect::<Vec<_>>(),
+        vec![EX_STREET_LINE_1, EX_STREET_LINE_2]
+    );
+}
+
+#[test]
+fn test_deque_iter_base_case_rename() {
+    pub struct Address {
+        street: VecDeque<String>,
+    }
+    impl Address {
+        deque_iter!(pub lines => street, String);
+    }
+
+    let address = Address {
+        street: VecDeque::from([EX_STREET_LINE_1.to_string()]),
+    };
+    assert_eq!(address.lines_iter().count(), 1);
+}
+
+#[test]
+fn test_deque_iter_copy_case() {
+    pub struct Address {
+        units: VecDeque<u32>,
+    }
+    impl Address {
+        deque_iter!(pub units => copy u32);
+    }
+
+    let address = Address {
+        units: VecDeque::from([EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2]),
+    };
+    assert_eq!(
+        address.units_iter().sum::<u32>(),
+        EX_UNIT_NUMBER_1 + EX_UNIT_NUMBER_2
+    );
+}
@@ -1,9 +1,60 @@
+use jemmy::error::Validator;
 use jemmy::*;
 
 const EX_NUMBER_ON_STREET: u32 = 101;
 const EX_UNIT_NUMBER: u32 = 24;
 const EX_STREET_LINE_1: &str = "101 My Street";
 const EX_STREET_LINE_2: &str = "Unit 202";
+const EX_MAP_FILE: &str = "maps/my_street.svg";
+
+#[derive(Debug, PartialEq)]
+pub struct InvalidValue(&'static str);
+
+fn check_non_zero(value: &u32) -> Result<(), InvalidValue> {
+    if *value > 0 {
+        Ok(())
+    } else {
+        Err(InvalidValue("must be non-zero"))
+    }
+}
+
+fn check_not_blank(value: &str) -> Result<(), InvalidValue> {
+    if value.trim().is_empty() {
+        Err(InvalidValue("must not be blank"))
+    } else {
+        Ok(())
+    }
+}
+
+pub struct NonZero;
+
+impl Validator<u32> for NonZero {
+    type Error = InvalidValue;
+
+    fn validate(value: &u32) -> Result<(), Self::Error> {
+        check_non_zero(value)
+    }
+}
+
+pub struct NotBlank;
+
+impl Validator<String> for NotBlank {
+    type Error = InvalidValue;
+
+    fn validate(value: &String) -> Result<(), Self::Error> {
+        check_not_blank(value)
+    }
+}
+
+validator! {
+    fn check_positive(value: &u32) -> Result<(), InvalidValue> {
+        check_non_zero(value)
+    }
+
+    fn check_street(value: &str) -> Result<(), InvalidValue> {
+        check_not_blank(value)
+    }
+}
 
 // ------------------------------------------------------------------------------------------------
 // Test Cases ❱ Structures ❱ get!
@@ -127,6 +178,263 @@ fn test_get_optional_copy_case_rename() {
     assert_eq!(address.unit(), Some(EX_UNIT_NUMBER));
 }
 
+#[test]
+fn test_get_deref_case() {
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        get!(pub street_1 => deref String);
+    }
+
+    let address = Address {
+        street_1: EX_STREET_LINE_1.to_string(),
+    };
+    let street_1: &str = address.street_1();
+    assert_eq!(street_1, EX_STREET_LINE_1);
+}
+
+#[test]
+fn test_get_deref_case_rename() {
+    pub struct Address {
+        street_or_building: String,
+    }
+    impl Address {
+        get!(pub street_1 => street_or_building, deref String);
+    }
+
+    let address = Address {
+        street_or_building: EX_STREET_LINE_1.to_string(),
+    };
+    let street_1: &str = address.street_1();
+    assert_eq!(street_1, EX_STREET_LINE_1);
+}
+
+#[test]
+fn test_get_deref_vec_case() {
+    pub struct Address {
+        unit_numbers: Vec<u32>,
+    }
+    impl Address {
+        get!(pub unit_numbers => deref Vec<u32>);
+    }
+
+    let address = Address {
+        unit_numbers: vec![EX_UNIT_NUMBER, EX_NUMBER_ON_STREET],
+    };
+    let unit_numbers: &[u32] = address.unit_numbers();
+    assert_eq!(unit_numbers, &[EX_UNIT_NUMBER, EX_NUMBER_ON_STREET]);
+}
+
+#[test]
+fn test_get_deref_path_buf_case() {
+    use std::path::{Path, PathBuf};
+
+    pub struct Address {
+        map_file: PathBuf,
+    }
+    impl Address {
+        get!(pub map_file => deref PathBuf);
+    }
+
+    let address = Address {
+        map_file: PathBuf::from(EX_MAP_FILE),
+    };
+    let map_file: &Path = address.map_file();
+    assert_eq!(map_file, Path::new(EX_MAP_FILE));
+}
+
+#[test]
+fn test_get_optional_deref_case() {
+    pub struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        get!(pub street_2 => optional deref String);
+    }
+
+    let address = Address {
+        street_2: Some(EX_STREET_LINE_2.to_string()),
+    };
+    assert_eq!(address.street_2(), Some(EX_STREET_LINE_2));
+}
+
+#[test]
+fn test_get_optional_deref_case_rename() {
+    pub struct Address {
+        street_additional: Option<String>,
+    }
+    impl Address {
+        get!(pub street_2 => street_additional, optional deref String);
+    }
+
+    let address = Address {
+        street_additional: None,
+    };
+    assert_eq!(address.street_2(), None);
+}
+
+#[test]
+fn test_get_as_ref_case() {
+    use std::path::{Path, PathBuf};
+
+    pub struct Address {
+        map_file: PathBuf,
+    }
+    impl Address {
+        get!(pub map_file => as_ref Path);
+    }
+
+    let address = Address {
+        map_file: PathBuf::from(EX_MAP_FILE),
+    };
+    assert_eq!(address.map_file(), Path::new(EX_MAP_FILE));
+}
+
+#[test]
+fn test_get_as_ref_case_rename() {
+    use std::ffi::OsStr;
+    use std::path::PathBuf;
+
+    pub struct Address {
+        map_file_path: PathBuf,
+    }
+    impl Address {
+        get!(pub map_file => map_file_path, as_ref OsStr);
+    }
+
+    let address = Address {
+        map_file_path: PathBuf::from(EX_MAP_FILE),
+    };
+    assert_eq!(address.map_file(), OsStr::new(EX_MAP_FILE));
+}
+
+#[test]
+fn test_get_as_ref_trait_object_case() {
+    pub trait Label {
+        fn label(&self) -> String;
+    }
+    pub struct Street;
+    impl Label for Street {
+        fn label(&self) -> String {
+            EX_STREET_LINE_1.to_string()
+        }
+    }
+
+    pub struct Address {
+        street_1: Box<dyn Label>,
+    }
+    impl Address {
+        get!(pub street_1 => as_ref dyn Label);
+    }
+
+    let address = Address {
+        street_1: Box::new(Street),
+    };
+    assert_eq!(address.street_1().label(), EX_STREET_LINE_1);
+}
+
+#[test]
+fn test_get_optional_as_ref_case() {
+    use std::path::{Path, PathBuf};
+
+    pub struct Address {
+        map_file: Option<PathBuf>,
+    }
+    impl Address {
+        get!(pub map_file => optional as_ref Path);
+    }
+
+    let address = Address {
+        map_file: Some(PathBuf::from(EX_MAP_FILE)),
+    };
+    assert_eq!(address.map_file(), Some(Path::new(EX_MAP_FILE)));
+}
+
+#[test]
+fn test_get_optional_as_ref_case_rename() {
+    use std::path::{Path, PathBuf};
+
+    pub struct Address {
+        map_file_path: Option<PathBuf>,
+    }
+    impl Address {
+        get!(pub map_file => map_file_path, optional as_ref Path);
+    }
+
+    let address = Address {
+        map_file_path: None,
+    };
+    assert_eq!(address.map_file(), None::<&Path>);
+}
+
+#[test]
+fn test_get_clone_case() {
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        get!(pub street_1 => clone String);
+    }
+
+    let address = Address {
+        street_1: EX_STREET_LINE_1.to_string(),
+    };
+    let street_1: String = address.street_1();
+    assert_eq!(street_1, EX_STREET_LINE_1);
+}
+
+#[test]
+fn test_get_clone_case_rename() {
+    use std::rc::Rc;
+
+    pub struct Address {
+        street_or_building: Rc<str>,
+    }
+    impl Address {
+        get!(pub street_1 => street_or_building, clone Rc<str>);
+    }
+
+    let address = Address {
+        street_or_building: Rc::from(EX_STREET_LINE_1),
+    };
+    let street_1: Rc<str> = address.street_1();
+    assert_eq!(&*street_1, EX_STREET_LINE_1);
+    assert_eq!(Rc::strong_count(&street_1), 2);
+}
+
+#[test]
+fn test_get_optional_clone_case() {
+    pub struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        get!(pub street_2 => optional clone String);
+    }
+
+    let address = Address {
+        street_2: Some(EX_STREET_LINE_2.to_string()),
+    };
+    assert_eq!(address.street_2(), Some(EX_STREET_LINE_2.to_string()));
+}
+
+#[test]
+fn test_get_optional_clone_case_rename() {
+    use std::sync::Arc;
+
+    pub struct Address {
+        street_additional: Option<Arc<String>>,
+    }
+    impl Address {
+        get!(pub street_2 => street_additional, optional clone Arc<String>);
+    }
+
+    let address = Address {
+        street_additional: None,
+    };
+    assert_eq!(address.street_2(), None);
+}
+
 // ------------------------------------------------------------------------------------------------
 // Test Cases ❱ Structures ❱ get_mut!
 // ------------------------------------------------------------------------------------------------
@@ -167,6 +475,71 @@ fn test_get_mut_base_case_rename() {
     assert_eq!(line_1, "202 My Street");
 }
 
+#[test]
+fn test_get_mut_optional_case() {
+    pub struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        get_mut!(pub street_2 => optional String);
+    }
+
+    let mut address = Address { street_2: None };
+    assert_eq!(address.street_2_mut(), None);
+
+    address.street_2 = Some(EX_STREET_LINE_2.to_string());
+    address.street_2_mut().unwrap().push('!');
+    assert_eq!(address.street_2, Some(format!("{EX_STREET_LINE_2}!")));
+}
+
+#[test]
+fn test_get_mut_optional_case_rename() {
+    pub struct Address {
+        unit_number: Option<u32>,
+    }
+    impl Address {
+        get_mut!(pub unit => unit_number, optional u32);
+    }
+
+    let mut address = Address {
+        unit_number: Some(EX_UNIT_NUMBER),
+    };
+    if let Some(unit) = address.unit_mut() {
+        *unit += 1;
+    }
+    assert_eq!(address.unit_number, Some(EX_UNIT_NUMBER + 1));
+}
+
+#[test]
+fn test_get_mut_optional_or_default_case() {
+    pub struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        get_mut!(pub street_2 => optional or_default String);
+    }
+
+    let mut address = Address { street_2: None };
+    address.street_2_mut().push_str(EX_STREET_LINE_2);
+    assert_eq!(address.street_2, Some(EX_STREET_LINE_2.to_string()));
+    address.street_2_mut().push('!');
+    assert_eq!(address.street_2, Some(format!("{EX_STREET_LINE_2}!")));
+}
+
+#[test]
+fn test_get_mut_optional_or_default_case_rename() {
+    pub struct Address {
+        unit_number: Option<u32>,
+    }
+    impl Address {
+        get_mut!(pub unit => unit_number, optional or_default u32);
+    }
+
+    let mut address = Address { unit_number: None };
+    *address.unit_mut() += EX_UNIT_NUMBER;
+    assert_eq!(address.unit_number, Some(EX_UNIT_NUMBER));
+}
+
 // ------------------------------------------------------------------------------------------------
 // Test Cases ❱ Structures ❱ set!
 // ------------------------------------------------------------------------------------------------
@@ -299,37 +672,223 @@ fn test_set_optional_into_case_rename() {
     assert_eq!(&address.street_line_2, &Some(EX_STREET_LINE_2.to_string()));
 }
 
-// ------------------------------------------------------------------------------------------------
-// Test Cases ❱ Structures ❱ unset!
-// ------------------------------------------------------------------------------------------------
-
 #[test]
-fn test_unset_base_case() {
-    #[derive(Default)]
+fn test_set_validate_case() {
     pub struct Address {
-        street_2: Option<String>,
+        number_on_street: u32,
     }
     impl Address {
-        unset!(pub street_2);
+        set!(pub number_on_street => u32, validate = check_non_zero -> InvalidValue);
     }
 
     let mut address = Address {
-        street_2: Some(EX_STREET_LINE_2.to_string()),
+        number_on_street: EX_NUMBER_ON_STREET,
     };
-    assert_eq!(&address.street_2, &Some(EX_STREET_LINE_2.to_string()));
-    address.unset_street_2();
-    assert_eq!(&address.street_2, &None);
+    assert_eq!(
+        address.try_set_number_on_street(0),
+        Err(InvalidValue("must be non-zero"))
+    );
+    assert_eq!(address.number_on_street, EX_NUMBER_ON_STREET);
+    assert_eq!(address.try_set_number_on_street(EX_UNIT_NUMBER), Ok(()));
+    assert_eq!(address.number_on_street, EX_UNIT_NUMBER);
 }
 
 #[test]
-fn test_unset_base_case_rename() {
-    #[derive(Default)]
+fn test_set_validate_case_rename() {
     pub struct Address {
-        street_line_2: Option<String>,
+        number: u32,
     }
     impl Address {
-        unset!(pub street_2 => street_line_2);
-    }
+        fn check_number(value: &u32) -> Result<(), InvalidValue> {
+            check_non_zero(value)
+        }
+        set!(pub number_on_street => number, u32, validate = Self::check_number -> InvalidValue);
+    }
+
+    let mut address = Address { number: 1 };
+    assert!(address.try_set_number_on_street(0).is_err());
+    assert!(address
+        .try_set_number_on_street(EX_NUMBER_ON_STREET)
+        .is_ok());
+    assert_eq!(address.number, EX_NUMBER_ON_STREET);
+}
+
+#[test]
+fn test_set_validate_into_case() {
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        set!(pub street_1 => into String, validate = check_not_blank -> InvalidValue);
+    }
+
+    let mut address = Address {
+        street_1: EX_STREET_LINE_1.to_string(),
+    };
+    assert!(address.try_set_street_1("  ").is_err());
+    assert_eq!(address.street_1, EX_STREET_LINE_1);
+    assert!(address.try_set_street_1(EX_STREET_LINE_2).is_ok());
+    assert_eq!(address.street_1, EX_STREET_LINE_2);
+}
+
+#[test]
+fn test_set_validate_optional_case() {
+    pub struct Address {
+        unit: Option<u32>,
+    }
+    impl Address {
+        set!(pub unit => optional u32, validate = check_non_zero -> InvalidValue);
+    }
+
+    let mut address = Address { unit: None };
+    assert!(address.try_set_unit(0).is_err());
+    assert_eq!(address.unit, None);
+    assert!(address.try_set_unit(EX_UNIT_NUMBER).is_ok());
+    assert_eq!(address.unit, Some(EX_UNIT_NUMBER));
+}
+
+#[test]
+fn test_set_validate_optional_into_case_rename() {
+    pub struct Address {
+        street_line_2: Option<String>,
+    }
+    impl Address {
+        set!(pub street_2 => street_line_2, optional into String, validate = check_not_blank -> InvalidValue);
+    }
+
+    let mut address = Address {
+        street_line_2: None,
+    };
+    assert!(address.try_set_street_2("").is_err());
+    assert_eq!(address.street_line_2, None);
+    assert!(address.try_set_street_2(EX_STREET_LINE_2).is_ok());
+    assert_eq!(address.street_line_2, Some(EX_STREET_LINE_2.to_string()));
+}
+
+#[test]
+fn test_set_validator_case() {
+    pub struct Address {
+        number_on_street: u32,
+    }
+    impl Address {
+        set!(pub number_on_street => u32, validate = NonZero);
+    }
+
+    let mut address = Address {
+        number_on_street: EX_NUMBER_ON_STREET,
+    };
+    let result: Result<(), InvalidValue> = address.try_set_number_on_street(0);
+    assert_eq!(result, Err(InvalidValue("must be non-zero")));
+    assert_eq!(address.number_on_street, EX_NUMBER_ON_STREET);
+    assert_eq!(address.try_set_number_on_street(EX_UNIT_NUMBER), Ok(()));
+    assert_eq!(address.number_on_street, EX_UNIT_NUMBER);
+}
+
+#[test]
+fn test_set_validator_into_case_rename() {
+    pub struct Address {
+        street_or_building: String,
+    }
+    impl Address {
+        set!(pub street_1 => street_or_building, into String, validate = NotBlank);
+    }
+
+    let mut address = Address {
+        street_or_building: EX_STREET_LINE_1.to_string(),
+    };
+    assert!(address.try_set_street_1(" ").is_err());
+    assert_eq!(address.street_or_building, EX_STREET_LINE_1);
+    assert!(address.try_set_street_1(EX_STREET_LINE_2).is_ok());
+    assert_eq!(address.street_or_building, EX_STREET_LINE_2);
+}
+
+#[test]
+fn test_set_validator_optional_into_case() {
+    pub struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        set!(pub street_2 => optional into String, validate = NotBlank);
+    }
+
+    let mut address = Address { street_2: None };
+    assert!(address.try_set_street_2("").is_err());
+    assert_eq!(address.street_2, None);
+    assert!(address.try_set_street_2(EX_STREET_LINE_2).is_ok());
+    assert_eq!(address.street_2, Some(EX_STREET_LINE_2.to_string()));
+}
+
+#[test]
+fn test_set_validator_function_case() {
+    pub struct Address {
+        number_on_street: u32,
+    }
+    impl Address {
+        set!(pub number_on_street => u32, validate = check_positive);
+    }
+
+    let mut address = Address {
+        number_on_street: EX_NUMBER_ON_STREET,
+    };
+    let result: Result<(), InvalidValue> = address.try_set_number_on_street(0);
+    assert_eq!(result, check_positive(&0));
+    assert_eq!(address.number_on_street, EX_NUMBER_ON_STREET);
+    assert_eq!(address.try_set_number_on_street(EX_UNIT_NUMBER), Ok(()));
+    assert_eq!(address.number_on_street, EX_UNIT_NUMBER);
+}
+
+#[test]
+fn test_set_validator_function_optional_into_case_rename() {
+    pub struct Address {
+        street_line_2: Option<String>,
+    }
+    impl Address {
+        set!(pub street_2 => street_line_2, optional into String, validate = check_street);
+    }
+
+    let mut address = Address {
+        street_line_2: None,
+    };
+    assert_eq!(
+        address.try_set_street_2(" "),
+        Err(InvalidValue("must not be blank"))
+    );
+    assert_eq!(address.street_line_2, None);
+    assert!(address.try_set_street_2(EX_STREET_LINE_2).is_ok());
+    assert_eq!(address.street_line_2, Some(EX_STREET_LINE_2.to_string()));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ unset!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_unset_base_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        unset!(pub street_2);
+    }
+
+    let mut address = Address {
+        street_2: Some(EX_STREET_LINE_2.to_string()),
+    };
+    assert_eq!(&address.street_2, &Some(EX_STREET_LINE_2.to_string()));
+    address.unset_street_2();
+    assert_eq!(&address.street_2, &None);
+}
+
+#[test]
+fn test_unset_base_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        street_line_2: Option<String>,
+    }
+    impl Address {
+        unset!(pub street_2 => street_line_2);
+    }
 
     let mut address = Address {
         street_line_2: Some(EX_STREET_LINE_2.to_string()),
@@ -339,120 +898,499 @@ fn test_unset_base_case_rename() {
     assert_eq!(&address.street_line_2, &None);
 }
 
-// ------------------------------------------------------------------------------------------------
-// Test Cases ❱ Structures ❱ with!
-// ------------------------------------------------------------------------------------------------
-
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ with!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_with_base_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        with!(pub street_1 => String);
+    }
+
+    let address = Address::default().with_street_1(EX_STREET_LINE_1.to_string());
+    assert_eq!(&address.street_1, &EX_STREET_LINE_1.to_string());
+}
+
+#[test]
+fn test_with_base_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        street_line_1: String,
+    }
+    impl Address {
+        with!(pub street_1 => street_line_1, String);
+    }
+
+    let address = Address::default().with_street_1(EX_STREET_LINE_1.to_string());
+    assert_eq!(&address.street_line_1, &EX_STREET_LINE_1.to_string());
+}
+
+#[test]
+fn test_with_into_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        with!(pub street_1 => into String);
+    }
+
+    let address = Address::default().with_street_1(EX_STREET_LINE_1);
+    assert_eq!(&address.street_1, &EX_STREET_LINE_1.to_string());
+}
+
+#[test]
+fn test_with_into_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        street_line_1: String,
+    }
+    impl Address {
+        with!(pub street_1 => street_line_1, into String);
+    }
+
+    let address = Address::default().with_street_1(EX_STREET_LINE_1);
+    assert_eq!(&address.street_line_1, &EX_STREET_LINE_1.to_string());
+}
+
+#[test]
+fn test_with_optional_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        with!(pub street_2 => optional String);
+    }
+
+    let address = Address::default().with_street_2(EX_STREET_LINE_1.to_string());
+    assert_eq!(&address.street_2, &Some(EX_STREET_LINE_1.to_string()));
+}
+
+#[test]
+fn test_with_optional_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        street_line_2: Option<String>,
+    }
+    impl Address {
+        with!(pub street_2 => street_line_2, optional String);
+    }
+
+    let address = Address::default().with_street_2(EX_STREET_LINE_1.to_string());
+    assert_eq!(&address.street_line_2, &Some(EX_STREET_LINE_1.to_string()));
+}
+
+#[test]
+fn test_with_optional_into_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        with!(pub street_2 => optional into String);
+    }
+
+    let address = Address::default().with_street_2(EX_STREET_LINE_1);
+    assert_eq!(&address.street_2, &Some(EX_STREET_LINE_1.to_string()));
+}
+
+#[test]
+fn test_with_optional_into_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        street_line_2: Option<String>,
+    }
+    impl Address {
+        with!(pub street_2 => street_line_2, optional into String);
+    }
+
+    let address = Address::default().with_street_2(EX_STREET_LINE_1);
+    assert_eq!(&address.street_line_2, &Some(EX_STREET_LINE_1.to_string()));
+}
+
+#[test]
+fn test_with_validate_case() {
+    #[derive(Debug, Default)]
+    pub struct Address {
+        number_on_street: u32,
+    }
+    impl Address {
+        with!(pub number_on_street => u32, validate = check_non_zero -> InvalidValue);
+    }
+
+    assert_eq!(
+        Address::default().try_with_number_on_street(0).unwrap_err(),
+        InvalidValue("must be non-zero")
+    );
+    let address = Address::default()
+        .try_with_number_on_street(EX_NUMBER_ON_STREET)
+        .unwrap();
+    assert_eq!(address.number_on_street, EX_NUMBER_ON_STREET);
+}
+
+#[test]
+fn test_with_validate_into_case_rename() {
+    #[derive(Debug, Default)]
+    pub struct Address {
+        street_or_building: String,
+    }
+    impl Address {
+        with!(pub street_1 => street_or_building, into String, validate = check_not_blank -> InvalidValue);
+    }
+
+    assert!(Address::default().try_with_street_1("").is_err());
+    let address = Address::default()
+        .try_with_street_1(EX_STREET_LINE_1)
+        .unwrap();
+    assert_eq!(address.street_or_building, EX_STREET_LINE_1);
+}
+
+#[test]
+fn test_with_validate_optional_case() {
+    #[derive(Debug, Default)]
+    pub struct Address {
+        unit: Option<u32>,
+    }
+    impl Address {
+        with!(pub unit => optional u32, validate = check_non_zero -> InvalidValue);
+    }
+
+    assert!(Address::default().try_with_unit(0).is_err());
+    let address = Address::default().try_with_unit(EX_UNIT_NUMBER).unwrap();
+    assert_eq!(address.unit, Some(EX_UNIT_NUMBER));
+}
+
+#[test]
+fn test_with_validator_case() {
+    #[derive(Debug, Default)]
+    pub struct Address {
+        number_on_street: u32,
+    }
+    impl Address {
+        with!(pub number_on_street => u32, validate = NonZero);
+    }
+
+    let result: Result<Address, InvalidValue> = Address::default().try_with_number_on_street(0);
+    assert_eq!(result.unwrap_err(), InvalidValue("must be non-zero"));
+    let address = Address::default()
+        .try_with_number_on_street(EX_NUMBER_ON_STREET)
+        .unwrap();
+    assert_eq!(address.number_on_street, EX_NUMBER_ON_STREET);
+}
+
+#[test]
+fn test_with_validator_function_into_case() {
+    #[derive(Debug, Default)]
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        with!(pub street_1 => into String, validate = check_street);
+    }
+
+    let result: Result<Address, InvalidValue> = Address::default().try_with_street_1("");
+    assert_eq!(result.unwrap_err(), InvalidValue("must not be blank"));
+    let address = Address::default()
+        .try_with_street_1(EX_STREET_LINE_1)
+        .unwrap();
+    assert_eq!(address.street_1, EX_STREET_LINE_1);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ take!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_take_base_case() {
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        take!(pub street_1 => String);
+    }
+
+    let mut address = Address {
+        street_1: EX_STREET_LINE_1.to_string(),
+    };
+    assert_eq!(address.take_street_1(), EX_STREET_LINE_1);
+    assert_eq!(address.street_1, String::default());
+}
+
+#[test]
+fn test_take_base_case_rename() {
+    pub struct Address {
+        unit_numbers: Vec<u32>,
+    }
+    impl Address {
+        take!(pub units => unit_numbers, Vec<u32>);
+    }
+
+    let mut address = Address {
+        unit_numbers: vec![EX_UNIT_NUMBER],
+    };
+    assert_eq!(address.take_units(), vec![EX_UNIT_NUMBER]);
+    assert!(address.unit_numbers.is_empty());
+}
+
+#[test]
+fn test_take_optional_case() {
+    pub struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        take!(pub street_2 => optional String);
+    }
+
+    let mut address = Address {
+        street_2: Some(EX_STREET_LINE_2.to_string()),
+    };
+    assert_eq!(address.take_street_2(), Some(EX_STREET_LINE_2.to_string()));
+    assert_eq!(address.take_street_2(), None);
+}
+
+#[test]
+fn test_take_optional_case_rename() {
+    pub struct Address {
+        street_line_2: Option<String>,
+    }
+    impl Address {
+        take!(pub street_2 => street_line_2, optional String);
+    }
+
+    let mut address = Address {
+        street_line_2: Some(EX_STREET_LINE_2.to_string()),
+    };
+    assert_eq!(address.take_street_2(), Some(EX_STREET_LINE_2.to_string()));
+    assert!(address.street_line_2.is_none());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ replace!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_replace_base_case() {
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        replace!(pub street_1 => String);
+    }
+
+    let mut address = Address {
+        street_1: EX_STREET_LINE_1.to_string(),
+    };
+    assert_eq!(
+        address.replace_street_1(EX_STREET_LINE_2.to_string()),
+        EX_STREET_LINE_1
+    );
+    assert_eq!(address.street_1, EX_STREET_LINE_2);
+}
+
+#[test]
+fn test_replace_base_case_rename() {
+    pub struct Address {
+        number: u32,
+    }
+    impl Address {
+        replace!(pub number_on_street => number, u32);
+    }
+
+    let mut address = Address {
+        number: EX_NUMBER_ON_STREET,
+    };
+    assert_eq!(
+        address.replace_number_on_street(EX_UNIT_NUMBER),
+        EX_NUMBER_ON_STREET
+    );
+    assert_eq!(address.number, EX_UNIT_NUMBER);
+}
+
+#[test]
+fn test_replace_into_case() {
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        replace!(pub street_1 => into String);
+    }
+
+    let mut address = Address {
+        street_1: EX_STREET_LINE_1.to_string(),
+    };
+    assert_eq!(address.replace_street_1(EX_STREET_LINE_2), EX_STREET_LINE_1);
+    assert_eq!(address.street_1, EX_STREET_LINE_2);
+}
+
 #[test]
-fn test_with_base_case() {
-    #[derive(Default)]
+fn test_replace_optional_case() {
     pub struct Address {
-        street_1: String,
+        street_2: Option<String>,
     }
     impl Address {
-        with!(pub street_1 => String);
+        replace!(pub street_2 => optional String);
     }
 
-    let address = Address::default().with_street_1(EX_STREET_LINE_1.to_string());
-    assert_eq!(&address.street_1, &EX_STREET_LINE_1.to_string());
+    let mut address = Address { street_2: None };
+    assert_eq!(address.replace_street_2(EX_STREET_LINE_1.to_string()), None);
+    assert_eq!(
+        address.replace_street_2(EX_STREET_LINE_2.to_string()),
+        Some(EX_STREET_LINE_1.to_string())
+    );
+    assert_eq!(address.street_2, Some(EX_STREET_LINE_2.to_string()));
 }
 
 #[test]
-fn test_with_base_case_rename() {
-    #[derive(Default)]
+fn test_replace_optional_into_case_rename() {
     pub struct Address {
-        street_line_1: String,
+        street_line_2: Option<String>,
     }
     impl Address {
-        with!(pub street_1 => street_line_1, String);
+        replace!(pub street_2 => street_line_2, optional into String);
     }
 
-    let address = Address::default().with_street_1(EX_STREET_LINE_1.to_string());
-    assert_eq!(&address.street_line_1, &EX_STREET_LINE_1.to_string());
+    let mut address = Address {
+        street_line_2: Some(EX_STREET_LINE_1.to_string()),
+    };
+    assert_eq!(
+        address.replace_street_2(EX_STREET_LINE_2),
+        Some(EX_STREET_LINE_1.to_string())
+    );
+    assert_eq!(address.street_line_2, Some(EX_STREET_LINE_2.to_string()));
 }
 
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ swap!
+// ------------------------------------------------------------------------------------------------
+
 #[test]
-fn test_with_into_case() {
-    #[derive(Default)]
+fn test_swap_base_case() {
     pub struct Address {
         street_1: String,
     }
     impl Address {
-        with!(pub street_1 => into String);
+        swap!(pub street_1);
     }
 
-    let address = Address::default().with_street_1(EX_STREET_LINE_1);
-    assert_eq!(&address.street_1, &EX_STREET_LINE_1.to_string());
+    let mut address = Address {
+        street_1: EX_STREET_LINE_1.to_string(),
+    };
+    let mut other = Address {
+        street_1: EX_STREET_LINE_2.to_string(),
+    };
+    address.swap_street_1(&mut other);
+    assert_eq!(address.street_1, EX_STREET_LINE_2);
+    assert_eq!(other.street_1, EX_STREET_LINE_1);
 }
 
 #[test]
-fn test_with_into_case_rename() {
-    #[derive(Default)]
+fn test_swap_base_case_rename() {
     pub struct Address {
-        street_line_1: String,
+        street_line_2: Option<String>,
     }
     impl Address {
-        with!(pub street_1 => street_line_1, into String);
+        swap!(pub street_2 => street_line_2);
     }
 
-    let address = Address::default().with_street_1(EX_STREET_LINE_1);
-    assert_eq!(&address.street_line_1, &EX_STREET_LINE_1.to_string());
+    let mut address = Address {
+        street_line_2: Some(EX_STREET_LINE_2.to_string()),
+    };
+    let mut other = Address {
+        street_line_2: None,
+    };
+    address.swap_street_2(&mut other);
+    assert_eq!(address.street_line_2, None);
+    assert_eq!(other.street_line_2, Some(EX_STREET_LINE_2.to_string()));
 }
 
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ update!
+// ------------------------------------------------------------------------------------------------
+
 #[test]
-fn test_with_optional_case() {
-    #[derive(Default)]
+fn test_update_base_case() {
     pub struct Address {
-        street_2: Option<String>,
+        street_1: String,
     }
     impl Address {
-        with!(pub street_2 => optional String);
+        update!(pub street_1 => String);
     }
 
-    let address = Address::default().with_street_2(EX_STREET_LINE_1.to_string());
-    assert_eq!(&address.street_2, &Some(EX_STREET_LINE_1.to_string()));
+    let mut address = Address {
+        street_1: EX_STREET_LINE_1.to_string(),
+    };
+    address.update_street_1(|street_1| street_1.push_str(", Suite 1"));
+    assert_eq!(address.street_1, format!("{EX_STREET_LINE_1}, Suite 1"));
+
+    let address = address.map_street_1(|_| EX_STREET_LINE_2.to_string());
+    assert_eq!(address.street_1, EX_STREET_LINE_2);
 }
 
 #[test]
-fn test_with_optional_case_rename() {
-    #[derive(Default)]
+fn test_update_base_case_rename() {
     pub struct Address {
-        street_line_2: Option<String>,
+        number: u32,
     }
     impl Address {
-        with!(pub street_2 => street_line_2, optional String);
+        update!(pub number_on_street => number, u32);
     }
 
-    let address = Address::default().with_street_2(EX_STREET_LINE_1.to_string());
-    assert_eq!(&address.street_line_2, &Some(EX_STREET_LINE_1.to_string()));
+    let mut address = Address {
+        number: EX_NUMBER_ON_STREET,
+    };
+    address.update_number_on_street(|number| *number += 1);
+    assert_eq!(address.number, EX_NUMBER_ON_STREET + 1);
+
+    let address = address.map_number_on_street(|number| number * 2);
+    assert_eq!(address.number, (EX_NUMBER_ON_STREET + 1) * 2);
 }
 
 #[test]
-fn test_with_optional_into_case() {
-    #[derive(Default)]
+fn test_update_optional_case() {
     pub struct Address {
         street_2: Option<String>,
     }
     impl Address {
-        with!(pub street_2 => optional into String);
+        update!(pub street_2 => optional String);
     }
 
-    let address = Address::default().with_street_2(EX_STREET_LINE_1);
-    assert_eq!(&address.street_2, &Some(EX_STREET_LINE_1.to_string()));
+    let mut address = Address { street_2: None };
+    address.update_street_2_if_set(|street_2| street_2.push('!'));
+    assert_eq!(address.street_2, None);
+
+    address
+        .get_or_insert_street_2_with(|| EX_STREET_LINE_2.to_string())
+        .push('!');
+    assert_eq!(address.street_2, Some(format!("{EX_STREET_LINE_2}!")));
+
+    address.update_street_2_if_set(|street_2| street_2.truncate(EX_STREET_LINE_2.len()));
+    assert_eq!(address.street_2, Some(EX_STREET_LINE_2.to_string()));
 }
 
 #[test]
-fn test_with_optional_into_case_rename() {
-    #[derive(Default)]
+fn test_update_optional_case_rename() {
     pub struct Address {
-        street_line_2: Option<String>,
+        unit_number: Option<u32>,
     }
     impl Address {
-        with!(pub street_2 => street_line_2, optional into String);
+        update!(pub unit => unit_number, optional u32);
     }
 
-    let address = Address::default().with_street_2(EX_STREET_LINE_1);
-    assert_eq!(&address.street_line_2, &Some(EX_STREET_LINE_1.to_string()));
+    let address = Address { unit_number: None }.map_unit(|unit| unit + 1);
+    assert_eq!(address.unit_number, None);
+
+    let mut address = Address {
+        unit_number: Some(EX_UNIT_NUMBER),
+    }
+    .map_unit(|unit| unit + 1);
+    assert_eq!(address.unit_number, Some(EX_UNIT_NUMBER + 1));
+    assert_eq!(*address.get_or_insert_unit_with(|| 0), EX_UNIT_NUMBER + 1);
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -604,51 +1542,267 @@ fn test_get_and_set_optional_copy_case() {
 }
 
 #[test]
-fn test_get_and_set_optional_copy_case_rename() {
+fn test_get_and_set_optional_copy_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        unit_number: Option<u32>,
+    }
+    impl Address {
+        get_and_set!(pub unit => unit_number, optional copy u32);
+    }
+
+    let mut address = Address::default();
+    assert_eq!(address.unit(), None);
+    address.set_unit(24);
+    assert_eq!(address.unit(), Some(24));
+}
+
+#[test]
+fn test_get_and_set_optional_into_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        get_and_set!(pub street_2 => optional into String);
+    }
+
+    let mut address = Address::default();
+    assert_eq!(address.street_2(), None);
+    address.set_street_2(EX_STREET_LINE_2);
+    assert_eq!(address.street_2(), Some(&EX_STREET_LINE_2.to_string()));
+}
+
+#[test]
+fn test_get_and_set_optional_into_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        street_line_2: Option<String>,
+    }
+    impl Address {
+        get_and_set!(pub street_2 => street_line_2, optional into String);
+    }
+
+    let mut address = Address::default();
+    assert_eq!(address.street_2(), None);
+    address.set_street_2(EX_STREET_LINE_2);
+    assert_eq!(address.street_2(), Some(&EX_STREET_LINE_2.to_string()));
+}
+
+#[test]
+fn test_get_and_set_deref_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        get_and_set!(pub street_1 => deref String);
+    }
+
+    let mut address = Address::default();
+    assert_eq!(address.street_1(), "");
+    address.set_street_1(EX_STREET_LINE_1.to_string());
+    assert_eq!(address.street_1(), EX_STREET_LINE_1);
+}
+
+#[test]
+fn test_get_and_set_deref_into_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        street_or_building: String,
+    }
+    impl Address {
+        get_and_set!(pub street_1 => street_or_building, deref into String);
+    }
+
+    let mut address = Address::default();
+    address.set_street_1(EX_STREET_LINE_1);
+    assert_eq!(address.street_1(), EX_STREET_LINE_1);
+}
+
+#[test]
+fn test_get_and_set_optional_deref_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        get_and_set!(pub street_2 => optional deref String);
+    }
+
+    let mut address = Address::default();
+    assert_eq!(address.street_2(), None);
+    address.set_street_2(EX_STREET_LINE_2.to_string());
+    assert_eq!(address.street_2(), Some(EX_STREET_LINE_2));
+}
+
+#[test]
+fn test_get_and_set_optional_deref_into_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        get_and_set!(pub street_2 => optional deref into String);
+    }
+
+    let mut address = Address::default();
+    address.set_street_2(EX_STREET_LINE_2);
+    assert_eq!(address.street_2(), Some(EX_STREET_LINE_2));
+}
+
+#[test]
+fn test_get_and_set_as_ref_case() {
+    use std::path::{Path, PathBuf};
+
+    #[derive(Default)]
+    pub struct Address {
+        map_file: PathBuf,
+    }
+    impl Address {
+        get_and_set!(pub map_file => as_ref Path, PathBuf);
+    }
+
+    let mut address = Address::default();
+    address.set_map_file(PathBuf::from(EX_MAP_FILE));
+    assert_eq!(address.map_file(), Path::new(EX_MAP_FILE));
+}
+
+#[test]
+fn test_get_and_set_as_ref_into_case_rename() {
+    use std::path::{Path, PathBuf};
+
+    #[derive(Default)]
+    pub struct Address {
+        map_file_path: PathBuf,
+    }
+    impl Address {
+        get_and_set!(pub map_file => map_file_path, as_ref Path, into PathBuf);
+    }
+
+    let mut address = Address::default();
+    address.set_map_file(EX_MAP_FILE);
+    assert_eq!(address.map_file(), Path::new(EX_MAP_FILE));
+}
+
+#[test]
+fn test_get_and_set_optional_as_ref_into_case() {
+    use std::path::{Path, PathBuf};
+
+    #[derive(Default)]
+    pub struct Address {
+        map_file: Option<PathBuf>,
+    }
+    impl Address {
+        get_and_set!(pub map_file => optional as_ref Path, into PathBuf);
+    }
+
+    let mut address = Address::default();
+    assert_eq!(address.map_file(), None);
+    address.set_map_file(EX_MAP_FILE);
+    assert_eq!(address.map_file(), Some(Path::new(EX_MAP_FILE)));
+}
+
+#[test]
+fn test_get_and_set_clone_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        get_and_set!(pub street_1 => clone String);
+    }
+
+    let mut address = Address::default();
+    address.set_street_1(EX_STREET_LINE_1.to_string());
+    assert_eq!(address.street_1(), EX_STREET_LINE_1.to_string());
+}
+
+#[test]
+fn test_get_and_set_optional_clone_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        street_line_2: Option<String>,
+    }
+    impl Address {
+        get_and_set!(pub street_2 => street_line_2, optional clone String);
+    }
+
+    let mut address = Address::default();
+    assert_eq!(address.street_2(), None);
+    address.set_street_2(EX_STREET_LINE_2.to_string());
+    assert_eq!(address.street_2(), Some(EX_STREET_LINE_2.to_string()));
+}
+
+#[test]
+fn test_get_and_set_validate_copy_case() {
+    #[derive(Default)]
+    pub struct Address {
+        number_on_street: u32,
+    }
+    impl Address {
+        get_and_set!(pub number_on_street => copy u32, validate = check_non_zero -> InvalidValue);
+    }
+
+    let mut address = Address::default();
+    assert!(address.try_set_number_on_street(0).is_err());
+    assert!(address
+        .try_set_number_on_street(EX_NUMBER_ON_STREET)
+        .is_ok());
+    assert_eq!(address.number_on_street(), EX_NUMBER_ON_STREET);
+}
+
+#[test]
+fn test_get_and_set_validate_optional_into_case_rename() {
     #[derive(Default)]
     pub struct Address {
-        unit_number: Option<u32>,
+        street_line_2: Option<String>,
     }
     impl Address {
-        get_and_set!(pub unit => unit_number, optional copy u32);
+        get_and_set!(pub street_2 => street_line_2, optional into String, validate = check_not_blank -> InvalidValue);
     }
 
     let mut address = Address::default();
-    assert_eq!(address.unit(), None);
-    address.set_unit(24);
-    assert_eq!(address.unit(), Some(24));
+    assert!(address.try_set_street_2(" ").is_err());
+    assert_eq!(address.street_2(), None);
+    assert!(address.try_set_street_2(EX_STREET_LINE_2).is_ok());
+    assert_eq!(address.street_2(), Some(&EX_STREET_LINE_2.to_string()));
 }
 
 #[test]
-fn test_get_and_set_optional_into_case() {
+fn test_get_and_set_validator_copy_case() {
     #[derive(Default)]
     pub struct Address {
-        street_2: Option<String>,
+        number_on_street: u32,
     }
     impl Address {
-        get_and_set!(pub street_2 => optional into String);
+        get_and_set!(pub number_on_street => copy u32, validate = NonZero);
     }
 
     let mut address = Address::default();
-    assert_eq!(address.street_2(), None);
-    address.set_street_2(EX_STREET_LINE_2);
-    assert_eq!(address.street_2(), Some(&EX_STREET_LINE_2.to_string()));
+    assert!(address.try_set_number_on_street(0).is_err());
+    assert!(address
+        .try_set_number_on_street(EX_NUMBER_ON_STREET)
+        .is_ok());
+    assert_eq!(address.number_on_street(), EX_NUMBER_ON_STREET);
 }
 
 #[test]
-fn test_get_and_set_optional_into_case_rename() {
+fn test_get_and_set_validator_function_copy_case() {
     #[derive(Default)]
     pub struct Address {
-        street_line_2: Option<String>,
+        number_on_street: u32,
     }
     impl Address {
-        get_and_set!(pub street_2 => street_line_2, optional into String);
+        get_and_set!(pub number_on_street => copy u32, validate = check_positive);
     }
 
     let mut address = Address::default();
-    assert_eq!(address.street_2(), None);
-    address.set_street_2(EX_STREET_LINE_2);
-    assert_eq!(address.street_2(), Some(&EX_STREET_LINE_2.to_string()));
+    assert!(address.try_set_number_on_street(0).is_err());
+    assert!(address
+        .try_set_number_on_street(EX_NUMBER_ON_STREET)
+        .is_ok());
+    assert_eq!(address.number_on_street(), EX_NUMBER_ON_STREET);
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -847,6 +2001,171 @@ fn test_with_get_and_set_optional_into_case_rename() {
     assert_eq!(address.street_2(), Some(&EX_STREET_LINE_2.to_string()));
 }
 
+#[test]
+fn test_with_get_and_set_deref_into_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        with_get_and_set!(pub street_1 => deref into String);
+    }
+
+    let mut address = Address::default().with_street_1("--");
+    assert_eq!(address.street_1(), "--");
+    address.set_street_1(EX_STREET_LINE_1);
+    assert_eq!(address.street_1(), EX_STREET_LINE_1);
+}
+
+#[test]
+fn test_with_get_and_set_optional_deref_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        street_line_2: Option<String>,
+    }
+    impl Address {
+        with_get_and_set!(pub street_2 => street_line_2, optional deref String);
+    }
+
+    let mut address = Address::default().with_street_2("--".to_string());
+    assert_eq!(address.street_2(), Some("--"));
+    address.set_street_2(EX_STREET_LINE_2.to_string());
+    assert_eq!(address.street_2(), Some(EX_STREET_LINE_2));
+}
+
+#[test]
+fn test_with_get_and_set_as_ref_into_case() {
+    use std::path::{Path, PathBuf};
+
+    #[derive(Default)]
+    pub struct Address {
+        map_file: PathBuf,
+    }
+    impl Address {
+        with_get_and_set!(pub map_file => as_ref Path, into PathBuf);
+    }
+
+    let mut address = Address::default().with_map_file("--");
+    assert_eq!(address.map_file(), Path::new("--"));
+    address.set_map_file(EX_MAP_FILE);
+    assert_eq!(address.map_file(), Path::new(EX_MAP_FILE));
+}
+
+#[test]
+fn test_with_get_and_set_optional_as_ref_case() {
+    use std::path::{Path, PathBuf};
+
+    #[derive(Default)]
+    pub struct Address {
+        map_file: Option<PathBuf>,
+    }
+    impl Address {
+        with_get_and_set!(pub map_file => optional as_ref Path, PathBuf);
+    }
+
+    let address = Address::default().with_map_file(PathBuf::from(EX_MAP_FILE));
+    assert_eq!(address.map_file(), Some(Path::new(EX_MAP_FILE)));
+}
+
+#[test]
+fn test_with_get_and_set_clone_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        with_get_and_set!(pub street_1 => clone String);
+    }
+
+    let mut address = Address::default().with_street_1("--".to_string());
+    assert_eq!(address.street_1(), "--".to_string());
+    address.set_street_1(EX_STREET_LINE_1.to_string());
+    assert_eq!(address.street_1(), EX_STREET_LINE_1.to_string());
+}
+
+#[test]
+fn test_with_get_and_set_optional_clone_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        with_get_and_set!(pub street_2 => optional clone String);
+    }
+
+    let address = Address::default().with_street_2(EX_STREET_LINE_2.to_string());
+    assert_eq!(address.street_2(), Some(EX_STREET_LINE_2.to_string()));
+}
+
+#[test]
+fn test_with_get_and_set_validate_case() {
+    #[derive(Debug, Default)]
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        with_get_and_set!(pub street_1 => String, validate = check_not_blank -> InvalidValue);
+    }
+
+    assert!(Address::default().try_with_street_1(String::new()).is_err());
+    let mut address = Address::default()
+        .try_with_street_1(EX_STREET_LINE_1.to_string())
+        .unwrap();
+    assert_eq!(address.street_1(), EX_STREET_LINE_1);
+    assert!(address.try_set_street_1(String::new()).is_err());
+    assert_eq!(address.street_1(), EX_STREET_LINE_1);
+}
+
+#[test]
+fn test_with_get_and_set_validate_optional_copy_case() {
+    #[derive(Debug, Default)]
+    pub struct Address {
+        unit: Option<u32>,
+    }
+    impl Address {
+        with_get_and_set!(pub unit => optional copy u32, validate = check_non_zero -> InvalidValue);
+    }
+
+    let mut address = Address::default().try_with_unit(EX_UNIT_NUMBER).unwrap();
+    assert_eq!(address.unit(), Some(EX_UNIT_NUMBER));
+    assert!(address.try_set_unit(0).is_err());
+    assert_eq!(address.unit(), Some(EX_UNIT_NUMBER));
+}
+
+#[test]
+fn test_with_get_and_set_validator_optional_copy_case() {
+    #[derive(Debug, Default)]
+    pub struct Address {
+        unit: Option<u32>,
+    }
+    impl Address {
+        with_get_and_set!(pub unit => optional copy u32, validate = NonZero);
+    }
+
+    assert!(Address::default().try_with_unit(0).is_err());
+    let mut address = Address::default().try_with_unit(EX_UNIT_NUMBER).unwrap();
+    assert!(address.try_set_unit(0).is_err());
+    assert_eq!(address.unit(), Some(EX_UNIT_NUMBER));
+}
+
+#[test]
+fn test_with_get_and_set_validator_function_case() {
+    #[derive(Debug, Default)]
+    pub struct Address {
+        street_1: String,
+    }
+    impl Address {
+        with_get_and_set!(pub street_1 => String, validate = check_street);
+    }
+
+    assert!(Address::default().try_with_street_1(String::new()).is_err());
+    let mut address = Address::default()
+        .try_with_street_1(EX_STREET_LINE_1.to_string())
+        .unwrap();
+    assert!(address.try_set_street_1(" ".to_string()).is_err());
+    assert_eq!(address.street_1(), EX_STREET_LINE_1);
+}
+
 // ------------------------------------------------------------------------------------------------
 // Test Cases ❱ Structures ❱ Combinators ❱ get_set_and_unset!
 // ------------------------------------------------------------------------------------------------
@@ -887,6 +2206,120 @@ fn test_get_set_and_unset_base_case_rename() {
     assert_eq!(address.street_2(), None);
 }
 
+#[test]
+fn test_get_set_and_unset_deref_case() {
+    #[derive(Default)]
+    struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        get_set_and_unset!(pub street_2 => deref String);
+    }
+
+    let mut address = Address::default();
+    assert_eq!(address.street_2(), None);
+    address.set_street_2(EX_STREET_LINE_2.to_string());
+    assert_eq!(address.street_2(), Some(EX_STREET_LINE_2));
+    address.unset_street_2();
+    assert_eq!(address.street_2(), None);
+}
+
+#[test]
+fn test_get_set_and_unset_as_ref_case() {
+    use std::path::{Path, PathBuf};
+
+    #[derive(Default)]
+    struct Address {
+        map_file: Option<PathBuf>,
+    }
+    impl Address {
+        get_set_and_unset!(pub map_file => as_ref Path, PathBuf);
+    }
+
+    let mut address = Address::default();
+    address.set_map_file(PathBuf::from(EX_MAP_FILE));
+    assert_eq!(address.map_file(), Some(Path::new(EX_MAP_FILE)));
+    address.unset_map_file();
+    assert_eq!(address.map_file(), None);
+}
+
+#[test]
+fn test_get_set_and_unset_clone_case() {
+    #[derive(Default)]
+    struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        get_set_and_unset!(pub street_2 => clone String);
+    }
+
+    let mut address = Address::default();
+    address.set_street_2(EX_STREET_LINE_2.to_string());
+    assert_eq!(address.street_2(), Some(EX_STREET_LINE_2.to_string()));
+    address.unset_street_2();
+    assert_eq!(address.street_2(), None);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Combinators ❱ get_mut_set_and_unset!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_get_mut_set_and_unset_base_case() {
+    #[derive(Default)]
+    struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        get_mut_set_and_unset!(pub street_2 => String);
+    }
+
+    let mut address = Address::default();
+    assert_eq!(address.street_2_mut(), None);
+    address.set_street_2(EX_STREET_LINE_2.to_string());
+    address.street_2_mut().unwrap().push('!');
+    assert_eq!(address.street_2(), Some(&format!("{EX_STREET_LINE_2}!")));
+    address.unset_street_2();
+    assert_eq!(address.street_2(), None);
+}
+
+#[test]
+fn test_get_mut_set_and_unset_base_case_rename() {
+    #[derive(Default)]
+    struct Address {
+        street_line_2: Option<String>,
+    }
+    impl Address {
+        get_mut_set_and_unset!(pub street_2 => street_line_2, String);
+    }
+
+    let mut address = Address::default();
+    address.set_street_2(EX_STREET_LINE_2.to_string());
+    assert_eq!(
+        address.street_2_mut(),
+        Some(&mut EX_STREET_LINE_2.to_string())
+    );
+    address.unset_street_2();
+    assert_eq!(address.street_line_2, None);
+}
+
+#[test]
+fn test_get_mut_set_and_unset_or_default_case() {
+    #[derive(Default)]
+    struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        get_mut_set_and_unset!(pub street_2 => or_default String);
+    }
+
+    let mut address = Address::default();
+    address.street_2_mut().push_str(EX_STREET_LINE_2);
+    assert_eq!(address.street_2(), Some(&EX_STREET_LINE_2.to_string()));
+    address.unset_street_2();
+    assert_eq!(address.street_2(), None);
+}
+
 // ------------------------------------------------------------------------------------------------
 // Test Cases ❱ Structures ❱ Combinators ❱ with_get_set_and_unset!
 // ------------------------------------------------------------------------------------------------
@@ -926,3 +2359,55 @@ fn test_with_get_set_and_unset_base_case_rename() {
     address.unset_street_2();
     assert_eq!(address.street_2(), None);
 }
+
+#[test]
+fn test_with_get_set_and_unset_deref_case() {
+    #[derive(Default)]
+    struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        with_get_set_and_unset!(pub street_2 => deref String);
+    }
+
+    let mut address = Address::default().with_street_2("--".to_string());
+    assert_eq!(address.street_2(), Some("--"));
+    address.set_street_2(EX_STREET_LINE_2.to_string());
+    assert_eq!(address.street_2(), Some(EX_STREET_LINE_2));
+    address.unset_street_2();
+    assert_eq!(address.street_2(), None);
+}
+
+#[test]
+fn test_with_get_set_and_unset_as_ref_case() {
+    use std::path::{Path, PathBuf};
+
+    #[derive(Default)]
+    struct Address {
+        map_file: Option<PathBuf>,
+    }
+    impl Address {
+        with_get_set_and_unset!(pub map_file => as_ref Path, PathBuf);
+    }
+
+    let mut address = Address::default().with_map_file(PathBuf::from(EX_MAP_FILE));
+    assert_eq!(address.map_file(), Some(Path::new(EX_MAP_FILE)));
+    address.unset_map_file();
+    assert_eq!(address.map_file(), None);
+}
+
+#[test]
+fn test_with_get_set_and_unset_clone_case() {
+    #[derive(Default)]
+    struct Address {
+        street_2: Option<String>,
+    }
+    impl Address {
+        with_get_set_and_unset!(pub street_2 => clone String);
+    }
+
+    let mut address = Address::default().with_street_2("--".to_string());
+    assert_eq!(address.street_2(), Some("--".to_string()));
+    address.unset_street_2();
+    assert_eq!(address.street_2(), None);
+}
@@ -0,0 +1,171 @@
+use jemmy::*;
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Flags ❱ flag_is!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_flag_is_base_case() {
+    pub struct Account {
+        active: bool,
+    }
+    impl Account {
+        flag_is!(pub active);
+    }
+
+    assert!(Account { active: true }.is_active());
+    assert!(!Account { active: false }.is_active());
+}
+
+#[test]
+fn test_flag_is_base_case_rename() {
+    pub struct Account {
+        locked_flag: bool,
+    }
+    impl Account {
+        flag_is!(pub locked => locked_flag);
+    }
+
+    assert!(Account { locked_flag: true }.is_locked());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Flags ❱ flag_set!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_flag_set_base_case() {
+    pub struct Account {
+        active: bool,
+    }
+    impl Account {
+        flag_set!(pub active);
+    }
+
+    let mut account = Account { active: false };
+    account.set_active(true);
+    assert!(account.active);
+    account.set_active(false);
+    assert!(!account.active);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Flags ❱ flag_enable! & flag_disable!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_flag_enable_base_case() {
+    pub struct Account {
+        active: bool,
+    }
+    impl Account {
+        flag_enable!(pub active);
+    }
+
+    let mut account = Account { active: false };
+    account.enable_active();
+    assert!(account.active);
+    account.enable_active();
+    assert!(account.active);
+}
+
+#[test]
+fn test_flag_disable_base_case_rename() {
+    pub struct Account {
+        locked_flag: bool,
+    }
+    impl Account {
+        flag_disable!(pub locked => locked_flag);
+    }
+
+    let mut account = Account { locked_flag: true };
+    account.disable_locked();
+    assert!(!account.locked_flag);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Flags ❱ flag_toggle!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_flag_toggle_base_case() {
+    pub struct Account {
+        active: bool,
+    }
+    impl Account {
+        flag_toggle!(pub active);
+    }
+
+    let mut account = Account { active: false };
+    account.toggle_active();
+    assert!(account.active);
+    account.toggle_active();
+    assert!(!account.active);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Flags ❱ flag_with!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_flag_with_base_case() {
+    #[derive(Default)]
+    pub struct Account {
+        active: bool,
+        locked: bool,
+    }
+    impl Account {
+        flag_with!(pub active);
+        flag_with!(pub locked);
+    }
+
+    let account = Account::default().with_active();
+    assert!(account.active);
+    assert!(!account.locked);
+
+    let account = account.with_locked();
+    assert!(account.active);
+    assert!(account.locked);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Flags ❱ flag!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_flag_base_case() {
+    #[derive(Default)]
+    pub struct Account {
+        active: bool,
+    }
+    impl Account {
+        flag!(pub active);
+    }
+
+    let mut account = Account::default().with_active();
+    assert!(account.is_active());
+    account.disable_active();
+    assert!(!account.is_active());
+    account.enable_active();
+    assert!(account.is_active());
+    account.toggle_active();
+    assert!(!account.is_active());
+    account.set_active(true);
+    assert!(account.is_active());
+}
+
+#[test]
+fn test_flag_base_case_rename() {
+    #[derive(Default)]
+    pub struct Account {
+        is_locked: bool,
+    }
+    impl Account {
+        flag!(pub locked => is_locked);
+    }
+
+    let mut account = Account::default();
+    assert!(!account.is_locked());
+    account.toggle_locked();
+    assert!(account.is_locked);
+}
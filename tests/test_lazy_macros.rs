@@ -0,0 +1,242 @@
+use jemmy::*;
+use std::cell::{Cell, OnceCell};
+use std::sync::OnceLock;
+
+const EX_PAYLOAD: &[u8] = &[1, 2, 3, 4];
+const EX_PAYLOAD_SUM: u64 = 10;
+const EX_OTHER_PAYLOAD: &[u8] = &[5, 6];
+const EX_OTHER_PAYLOAD_SUM: u64 = 11;
+const EX_NAME: &str = "Jemmy Button";
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Lazy ❱ lazy_get!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_lazy_get_base_case() {
+    pub struct Packet {
+        payload: Vec<u8>,
+        checksum: OnceCell<u64>,
+        computed: Cell<u32>,
+    }
+    impl Packet {
+        lazy_get!(pub checksum => OnceCell<u64>, Self::compute_checksum);
+
+        fn compute_checksum(&self) -> u64 {
+            self.computed.set(self.computed.get() + 1);
+            self.payload.iter().map(|byte| *byte as u64).sum()
+        }
+    }
+
+    let mut packet = Packet {
+        payload: EX_PAYLOAD.to_vec(),
+        checksum: OnceCell::new(),
+        computed: Cell::new(0),
+    };
+    assert_eq!(*packet.checksum(), EX_PAYLOAD_SUM);
+    assert_eq!(*packet.checksum(), EX_PAYLOAD_SUM);
+    assert_eq!(packet.computed.get(), 1);
+
+    packet.invalidate_checksum();
+    assert_eq!(*packet.checksum(), EX_PAYLOAD_SUM);
+    assert_eq!(packet.computed.get(), 2);
+}
+
+#[test]
+fn test_lazy_get_base_case_rename() {
+    pub struct Packet {
+        payload: Vec<u8>,
+        cached_length: OnceCell<usize>,
+    }
+    impl Packet {
+        lazy_get!(pub length => cached_length, OnceCell<usize>, compute_length);
+    }
+    fn compute_length(packet: &Packet) -> usize {
+        packet.payload.len()
+    }
+
+    let packet = Packet {
+        payload: EX_PAYLOAD.to_vec(),
+        cached_length: OnceCell::new(),
+    };
+    assert_eq!(*packet.length(), EX_PAYLOAD.len());
+}
+
+#[test]
+fn test_lazy_get_once_lock_case() {
+    pub struct Person {
+        name: String,
+        greeting: OnceLock<String>,
+    }
+    impl Person {
+        lazy_get!(pub greeting => OnceLock<String>, Self::compute_greeting);
+
+        fn compute_greeting(&self) -> String {
+            format!("Hello, {}", self.name)
+        }
+    }
+
+    let mut person = Person {
+        name: EX_NAME.to_string(),
+        greeting: OnceLock::new(),
+    };
+    assert_eq!(person.greeting(), "Hello, Jemmy Button");
+    person.name = String::new();
+    person.invalidate_greeting();
+    assert_eq!(person.greeting(), "Hello, ");
+}
+
+#[test]
+fn test_lazy_get_cell_path_case() {
+    pub struct Packet {
+        payload: Vec<u8>,
+        checksum: std::cell::OnceCell<u64>,
+    }
+    impl Packet {
+        lazy_get!(pub checksum => std::cell::OnceCell<u64>, Self::compute_checksum);
+
+        fn compute_checksum(&self) -> u64 {
+            self.payload.iter().map(|byte| *byte as u64).sum()
+        }
+    }
+
+    let mut packet = Packet {
+        payload: EX_PAYLOAD.to_vec(),
+        checksum: std::cell::OnceCell::new(),
+    };
+    assert_eq!(*packet.checksum(), EX_PAYLOAD_SUM);
+    packet.payload = EX_OTHER_PAYLOAD.to_vec();
+    packet.invalidate_checksum();
+    assert_eq!(*packet.checksum(), EX_OTHER_PAYLOAD_SUM);
+}
+
+#[test]
+fn test_lazy_get_cell_alias_case_rename() {
+    type Cached<T> = OnceLock<T>;
+
+    pub struct Packet {
+        payload: Vec<u8>,
+        cached_length: Cached<usize>,
+    }
+    impl Packet {
+        lazy_get!(pub length => cached_length, Cached<usize>, compute_length);
+    }
+    fn compute_length(packet: &Packet) -> usize {
+        packet.payload.len()
+    }
+
+    let packet = Packet {
+        payload: EX_PAYLOAD.to_vec(),
+        cached_length: Cached::new(),
+    };
+    assert_eq!(*packet.length(), EX_PAYLOAD.len());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Lazy ❱ set!, with! invalidates
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_set_invalidates_case() {
+    pub struct Packet {
+        payload: Vec<u8>,
+        checksum: OnceCell<u64>,
+        length: OnceCell<usize>,
+    }
+    impl Packet {
+        lazy_get!(pub checksum => OnceCell<u64>, Self::compute_checksum);
+        lazy_get!(pub length => OnceCell<usize>, Self::compute_length);
+        set!(pub payload => Vec<u8>, invalidates checksum, length);
+
+        fn compute_checksum(&self) -> u64 {
+            self.payload.iter().map(|byte| *byte as u64).sum()
+        }
+
+        fn compute_length(&self) -> usize {
+            self.payload.len()
+        }
+    }
+
+    let mut packet = Packet {
+        payload: EX_PAYLOAD.to_vec(),
+        checksum: OnceCell::new(),
+        length: OnceCell::new(),
+    };
+    assert_eq!(*packet.checksum(), EX_PAYLOAD_SUM);
+    assert_eq!(*packet.length(), EX_PAYLOAD.len());
+
+    packet.set_payload(EX_OTHER_PAYLOAD.to_vec());
+    assert_eq!(*packet.checksum(), EX_OTHER_PAYLOAD_SUM);
+    assert_eq!(*packet.length(), EX_OTHER_PAYLOAD.len());
+}
+
+#[test]
+fn test_set_optional_into_invalidates_case_rename() {
+    pub struct Person {
+        nickname: Option<String>,
+        greeting: OnceCell<String>,
+    }
+    impl Person {
+        lazy_get!(pub greeting => OnceCell<String>, Self::compute_greeting);
+        set!(pub name => nickname, optional into String, invalidates greeting);
+
+        fn compute_greeting(&self) -> String {
+            format!("Hello, {}", self.nickname.as_deref().unwrap_or("stranger"))
+        }
+    }
+
+    let mut person = Person {
+        nickname: None,
+        greeting: OnceCell::new(),
+    };
+    assert_eq!(person.greeting(), "Hello, stranger");
+    person.set_name(EX_NAME);
+    assert_eq!(person.greeting(), "Hello, Jemmy Button");
+}
+
+#[test]
+fn test_with_invalidates_case() {
+    #[derive(Default)]
+    pub struct Packet {
+        payload: Vec<u8>,
+        checksum: OnceCell<u64>,
+    }
+    impl Packet {
+        lazy_get!(pub checksum => OnceCell<u64>, Self::compute_checksum);
+        with!(pub payload => into Vec<u8>, invalidates checksum);
+
+        fn compute_checksum(&self) -> u64 {
+            self.payload.iter().map(|byte| *byte as u64).sum()
+        }
+    }
+
+    let packet = Packet::default();
+    assert_eq!(*packet.checksum(), 0);
+    let packet = packet.with_payload(EX_PAYLOAD);
+    assert_eq!(*packet.checksum(), EX_PAYLOAD_SUM);
+}
+
+#[test]
+fn test_get_and_set_invalidates_case() {
+    pub struct Person {
+        name: String,
+        greeting: OnceCell<String>,
+    }
+    impl Person {
+        lazy_get!(pub greeting => OnceCell<String>, Self::compute_greeting);
+        get_and_set!(pub name => String, invalidates greeting);
+
+        fn compute_greeting(&self) -> String {
+            format!("Hello, {}", self.name)
+        }
+    }
+
+    let mut person = Person {
+        name: String::new(),
+        greeting: OnceCell::new(),
+    };
+    assert_eq!(person.greeting(), "Hello, ");
+    person.set_name(EX_NAME.to_string());
+    assert_eq!(person.name(), EX_NAME);
+    assert_eq!(person.greeting(), "Hello, Jemmy Button");
+}
@@ -0,0 +1,328 @@
+use jemmy::*;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+
+const EX_SESSION_1: u32 = 101;
+const EX_SESSION_2: u32 = 202;
+const EX_CONFIG: &str = "listen = 8080";
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Locks ❱ mutex_lock!, mutex_try_lock!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_mutex_lock_base_case() {
+    pub struct Inner {
+        sessions: Mutex<Vec<u32>>,
+    }
+    impl Inner {
+        mutex_lock!(pub sessions => Vec<u32>);
+    }
+
+    let inner = Inner {
+        sessions: Mutex::new(Vec::new()),
+    };
+    inner.lock_sessions().push(EX_SESSION_1);
+    assert_eq!(*inner.lock_sessions(), vec![EX_SESSION_1]);
+}
+
+#[test]
+fn test_mutex_lock_poison_case_rename() {
+    pub struct Inner {
+        session_ids: Mutex<Vec<u32>>,
+    }
+    impl Inner {
+        mutex_lock!(pub sessions => session_ids, poison Vec<u32>);
+    }
+
+    let inner = Inner {
+        session_ids: Mutex::new(vec![EX_SESSION_1]),
+    };
+    assert_eq!(*inner.lock_sessions().unwrap(), vec![EX_SESSION_1]);
+    poison(&inner.session_ids);
+    let poisoned = inner.lock_sessions().unwrap_err();
+    assert_eq!(*poisoned.into_inner(), vec![EX_SESSION_1]);
+}
+
+#[test]
+fn test_mutex_try_lock_base_case() {
+    pub struct Inner {
+        sessions: Mutex<Vec<u32>>,
+    }
+    impl Inner {
+        mutex_lock!(pub sessions => Vec<u32>);
+        mutex_try_lock!(pub sessions => Vec<u32>);
+    }
+
+    let inner = Inner {
+        sessions: Mutex::new(Vec::new()),
+    };
+    {
+        let _guard = inner.lock_sessions();
+        assert!(inner.try_lock_sessions().is_none());
+    }
+    assert!(inner.try_lock_sessions().is_some());
+}
+
+#[test]
+fn test_mutex_try_lock_poison_case() {
+    pub struct Inner {
+        sessions: Mutex<Vec<u32>>,
+    }
+    impl Inner {
+        mutex_try_lock!(pub sessions => poison Vec<u32>);
+    }
+
+    let inner = Inner {
+        sessions: Mutex::new(Vec::new()),
+    };
+    let guard = inner.try_lock_sessions().unwrap();
+    assert!(matches!(
+        inner.try_lock_sessions(),
+        Err(std::sync::TryLockError::WouldBlock)
+    ));
+    drop(guard);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Locks ❱ mutex_with_locked!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_mutex_with_locked_base_case() {
+    pub struct Inner {
+        sessions: Mutex<Vec<u32>>,
+    }
+    impl Inner {
+        mutex_with_locked!(pub sessions => Vec<u32>);
+    }
+
+    let inner = Arc::new(Inner {
+        sessions: Mutex::new(Vec::new()),
+    });
+    let handles: Vec<_> = [EX_SESSION_1, EX_SESSION_2]
+        .into_iter()
+        .map(|session| {
+            let inner = inner.clone();
+            thread::spawn(move || inner.with_sessions_locked(|sessions| sessions.push(session)))
+        })
+        .collect();
+    handles
+        .into_iter()
+        .for_each(|handle| handle.join().unwrap());
+
+    let length = inner.with_sessions_locked(|sessions| {
+        sessions.sort();
+        sessions.len()
+    });
+    assert_eq!(length, 2);
+    assert_eq!(
+        inner.with_sessions_locked(|sessions| sessions.clone()),
+        vec![EX_SESSION_1, EX_SESSION_2]
+    );
+}
+
+fn poison<T: Send>(lock: &Mutex<T>) {
+    let _ = thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                let _guard = lock.lock().unwrap();
+                panic!("poisoning the mutex");
+            })
+            .join()
+    });
+}
+
+fn poison_rwlock<T: Send + Sync>(lock: &RwLock<T>) {
+    let _ = thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                let _guard = lock.write().unwrap();
+                panic!("poisoning the lock");
+            })
+            .join()
+    });
+}
+
+#[test]
+#[should_panic(expected = "the mutex field `sessions` is poisoned")]
+fn test_mutex_with_locked_poisoned_panics() {
+    pub struct Inner {
+        sessions: Mutex<Vec<u32>>,
+    }
+    impl Inner {
+        mutex_with_locked!(pub sessions => Vec<u32>);
+    }
+
+    let inner = Inner {
+        sessions: Mutex::new(Vec::new()),
+    };
+    poison(&inner.sessions);
+    inner.with_sessions_locked(|sessions| sessions.len());
+}
+
+#[test]
+fn test_mutex_with_locked_poison_case() {
+    pub struct Inner {
+        sessions: Mutex<Vec<u32>>,
+    }
+    impl Inner {
+        mutex_with_locked!(pub sessions => poison Vec<u32>);
+    }
+
+    let inner = Inner {
+        sessions: Mutex::new(vec![EX_SESSION_1]),
+    };
+    assert_eq!(
+        inner.with_sessions_locked(|sessions| sessions.len()).ok(),
+        Some(1)
+    );
+    poison(&inner.sessions);
+    let poisoned = inner
+        .with_sessions_locked(|sessions| sessions.len())
+        .unwrap_err();
+    assert_eq!(poisoned.into_inner(), 1);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Locks ❱ rwlock_read!, rwlock_write!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_rwlock_read_write_base_case() {
+    pub struct Inner {
+        config: RwLock<String>,
+    }
+    impl Inner {
+        rwlock_read!(pub config => String);
+        rwlock_write!(pub config => String);
+    }
+
+    let inner = Inner {
+        config: RwLock::new(String::new()),
+    };
+    inner.write_config().push_str(EX_CONFIG);
+    let first = inner.read_config();
+    let second = inner.read_config();
+    assert_eq!(*first, EX_CONFIG);
+    assert_eq!(*second, EX_CONFIG);
+}
+
+#[test]
+fn test_rwlock_read_write_poison_case_rename() {
+    pub struct Inner {
+        settings: RwLock<String>,
+    }
+    impl Inner {
+        rwlock_read!(pub config => settings, poison String);
+        rwlock_write!(pub config => settings, poison String);
+    }
+
+    let inner = Inner {
+        settings: RwLock::new(String::new()),
+    };
+    inner.write_config().unwrap().push_str(EX_CONFIG);
+    assert_eq!(*inner.read_config().unwrap(), EX_CONFIG);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Locks ❱ rwlock_with_read!, rwlock_with_write!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_rwlock_with_read_write_base_case() {
+    pub struct Inner {
+        config: RwLock<String>,
+    }
+    impl Inner {
+        rwlock_with_read!(pub config => String);
+        rwlock_with_write!(pub config => String);
+    }
+
+    let inner = Inner {
+        config: RwLock::new(String::new()),
+    };
+    inner.with_config_write(|config| config.push_str(EX_CONFIG));
+    assert_eq!(
+        inner.with_config_read(|config| config.len()),
+        EX_CONFIG.len()
+    );
+}
+
+#[test]
+fn test_rwlock_with_read_write_poison_case() {
+    pub struct Inner {
+        config: RwLock<String>,
+    }
+    impl Inner {
+        rwlock_with_read!(pub config => poison String);
+        rwlock_with_write!(pub config => poison String);
+    }
+
+    let inner = Inner {
+        config: RwLock::new(String::new()),
+    };
+    assert!(inner
+        .with_config_write(|config| config.push_str(EX_CONFIG))
+        .is_ok());
+    assert_eq!(
+        inner.with_config_read(|config| config.clone()).ok(),
+        Some(EX_CONFIG.to_string())
+    );
+    poison_rwlock(&inner.config);
+    let poisoned = inner.with_config_read(|config| config.len()).unwrap_err();
+    assert_eq!(poisoned.into_inner(), EX_CONFIG.len());
+    let poisoned = inner
+        .with_config_write(|config| config.clear())
+        .unwrap_err();
+    poisoned.into_inner();
+    assert!(inner
+        .with_config_read(|config| config.is_empty())
+        .unwrap_err()
+        .into_inner());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Locks ❱ Combinators
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_mutex_accessors_case() {
+    pub struct Inner {
+        sessions: Mutex<Vec<u32>>,
+    }
+    impl Inner {
+        mutex_accessors!(pub sessions => Vec<u32>);
+    }
+
+    let inner = Inner {
+        sessions: Mutex::new(Vec::new()),
+    };
+    inner.lock_sessions().push(EX_SESSION_1);
+    inner.with_sessions_locked(|sessions| sessions.push(EX_SESSION_2));
+    assert_eq!(
+        *inner.try_lock_sessions().unwrap(),
+        vec![EX_SESSION_1, EX_SESSION_2]
+    );
+}
+
+#[test]
+fn test_rwlock_accessors_poison_case_rename() {
+    pub struct Inner {
+        settings: RwLock<String>,
+    }
+    impl Inner {
+        rwlock_accessors!(pub config => settings, poison String);
+    }
+
+    let inner = Inner {
+        settings: RwLock::new(String::new()),
+    };
+    inner.write_config().unwrap().push_str(EX_CONFIG);
+    assert_eq!(
+        inner.with_config_read(|config| config.len()).ok(),
+        Some(EX_CONFIG.len())
+    );
+    assert!(inner.with_config_write(|config| config.clear()).is_ok());
+    assert!(inner.read_config().unwrap().is_empty());
+}
@@ -0,0 +1,574 @@
+use jemmy::*;
+use std::collections::{BTreeMap, HashMap};
+
+const EX_HOME: &str = "home";
+const EX_WORK: &str = "work";
+const EX_STREET_LINE_1: &str = "101 My Street";
+const EX_STREET_LINE_2: &str = "202 Your Street";
+const EX_UNIT_NUMBER_1: u32 = 24;
+const EX_UNIT_NUMBER_2: u32 = 202;
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Maps ❱ map_get!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_map_get_base_case() {
+    pub struct AddressBook {
+        addresses: HashMap<String, String>,
+    }
+    impl AddressBook {
+        map_get!(pub addresses => String, String);
+    }
+
+    let book = AddressBook {
+        addresses: HashMap::from([(EX_HOME.to_string(), EX_STREET_LINE_1.to_string())]),
+    };
+    assert_eq!(
+        book.addresses_get(&EX_HOME.to_string()),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+    assert_eq!(book.addresses_get(&EX_WORK.to_string()), None);
+}
+
+#[test]
+fn test_map_get_base_case_rename() {
+    pub struct AddressBook {
+        entries: BTreeMap<String, String>,
+    }
+    impl AddressBook {
+        map_get!(pub addresses => entries, String, String);
+    }
+
+    let book = AddressBook {
+        entries: BTreeMap::from([(EX_HOME.to_string(), EX_STREET_LINE_1.to_string())]),
+    };
+    assert_eq!(
+        book.addresses_get(&EX_HOME.to_string()),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+}
+
+#[test]
+fn test_map_get_copy_case() {
+    pub struct AddressBook {
+        unit_counts: BTreeMap<u32, u32>,
+    }
+    impl AddressBook {
+        map_get!(pub unit_counts => u32, copy u32);
+    }
+
+    let book = AddressBook {
+        unit_counts: BTreeMap::from([(EX_UNIT_NUMBER_1, 2)]),
+    };
+    assert_eq!(book.unit_counts_get(&EX_UNIT_NUMBER_1), Some(2));
+    assert_eq!(book.unit_counts_get(&EX_UNIT_NUMBER_2), None);
+}
+
+#[test]
+fn test_map_get_copy_case_rename() {
+    pub struct AddressBook {
+        counts: HashMap<u32, u32>,
+    }
+    impl AddressBook {
+        map_get!(pub unit_counts => counts, u32, copy u32);
+    }
+
+    let book = AddressBook {
+        counts: HashMap::from([(EX_UNIT_NUMBER_1, 2)]),
+    };
+    assert_eq!(book.unit_counts_get(&EX_UNIT_NUMBER_1), Some(2));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Maps ❱ map_get_mut!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_map_get_mut_base_case() {
+    pub struct AddressBook {
+        addresses: HashMap<String, String>,
+    }
+    impl AddressBook {
+        map_get_mut!(pub addresses => String, String);
+    }
+
+    let mut book = AddressBook {
+        addresses: HashMap::from([(EX_HOME.to_string(), EX_STREET_LINE_1.to_string())]),
+    };
+    book.addresses_get_mut(&EX_HOME.to_string())
+        .unwrap()
+        .replace_range(0.., EX_STREET_LINE_2);
+    assert_eq!(
+        book.addresses.get(EX_HOME),
+        Some(&EX_STREET_LINE_2.to_string())
+    );
+    assert_eq!(book.addresses_get_mut(&EX_WORK.to_string()), None);
+}
+
+#[test]
+fn test_map_get_mut_base_case_rename() {
+    pub struct AddressBook {
+        counts: BTreeMap<u32, u32>,
+    }
+    impl AddressBook {
+        map_get_mut!(pub unit_counts => counts, u32, u32);
+    }
+
+    let mut book = AddressBook {
+        counts: BTreeMap::from([(EX_UNIT_NUMBER_1, 2)]),
+    };
+    *book.unit_counts_get_mut(&EX_UNIT_NUMBER_1).unwrap() += 1;
+    assert_eq!(book.counts.get(&EX_UNIT_NUMBER_1), Some(&3));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Maps ❱ map_insert!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_map_insert_base_case() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        addresses: HashMap<String, String>,
+    }
+    impl AddressBook {
+        map_insert!(pub addresses => String, String);
+    }
+
+    let mut book = AddressBook::default();
+    assert_eq!(
+        book.addresses_insert(EX_HOME.to_string(), EX_STREET_LINE_1.to_string()),
+        None
+    );
+    assert_eq!(
+        book.addresses_insert(EX_HOME.to_string(), EX_STREET_LINE_2.to_string()),
+        Some(EX_STREET_LINE_1.to_string())
+    );
+    assert_eq!(
+        book.addresses.get(EX_HOME),
+        Some(&EX_STREET_LINE_2.to_string())
+    );
+}
+
+#[test]
+fn test_map_insert_base_case_rename() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        counts: BTreeMap<u32, u32>,
+    }
+    impl AddressBook {
+        map_insert!(pub unit_counts => counts, u32, u32);
+    }
+
+    let mut book = AddressBook::default();
+    assert_eq!(book.unit_counts_insert(EX_UNIT_NUMBER_1, 1), None);
+    assert_eq!(book.counts.get(&EX_UNIT_NUMBER_1), Some(&1));
+}
+
+#[test]
+fn test_map_insert_into_key_case() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        addresses: HashMap<String, String>,
+    }
+    impl AddressBook {
+        map_insert!(pub addresses => into String, String);
+    }
+
+    let mut book = AddressBook::default();
+    let _ = book.addresses_insert(EX_HOME, EX_STREET_LINE_1.to_string());
+    assert_eq!(
+        book.addresses.get(EX_HOME),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+}
+
+#[test]
+fn test_map_insert_into_value_case() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        addresses: BTreeMap<u32, String>,
+    }
+    impl AddressBook {
+        map_insert!(pub addresses => u32, into String);
+    }
+
+    let mut book = AddressBook::default();
+    let _ = book.addresses_insert(EX_UNIT_NUMBER_1, EX_STREET_LINE_1);
+    assert_eq!(
+        book.addresses.get(&EX_UNIT_NUMBER_1),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+}
+
+#[test]
+fn test_map_insert_into_key_and_value_case() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        addresses: HashMap<String, String>,
+    }
+    impl AddressBook {
+        map_insert!(pub addresses => into String, into String);
+    }
+
+    let mut book = AddressBook::default();
+    let _ = book.addresses_insert(EX_HOME, EX_STREET_LINE_1);
+    assert_eq!(
+        book.addresses.get(EX_HOME),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+}
+
+#[test]
+fn test_map_insert_into_key_and_value_case_rename() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        entries: HashMap<String, String>,
+    }
+    impl AddressBook {
+        map_insert!(pub addresses => entries, into String, into String);
+    }
+
+    let mut book = AddressBook::default();
+    let _ = book.addresses_insert(EX_HOME, EX_STREET_LINE_1);
+    assert_eq!(
+        book.entries.get(EX_HOME),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Maps ❱ map_remove!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_map_remove_base_case() {
+    pub struct AddressBook {
+        addresses: HashMap<String, String>,
+    }
+    impl AddressBook {
+        map_remove!(pub addresses => String, String);
+    }
+
+    let mut book = AddressBook {
+        addresses: HashMap::from([(EX_HOME.to_string(), EX_STREET_LINE_1.to_string())]),
+    };
+    assert_eq!(
+        book.addresses_remove(&EX_HOME.to_string()),
+        Some(EX_STREET_LINE_1.to_string())
+    );
+    assert_eq!(book.addresses_remove(&EX_HOME.to_string()), None);
+}
+
+#[test]
+fn test_map_remove_base_case_rename() {
+    pub struct AddressBook {
+        counts: BTreeMap<u32, u32>,
+    }
+    impl AddressBook {
+        map_remove!(pub unit_counts => counts, u32, u32);
+    }
+
+    let mut book = AddressBook {
+        counts: BTreeMap::from([(EX_UNIT_NUMBER_1, 2)]),
+    };
+    assert_eq!(book.unit_counts_remove(&EX_UNIT_NUMBER_1), Some(2));
+    assert!(book.counts.is_empty());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Maps ❱ map_contains_key!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_map_contains_key_base_case() {
+    pub struct AddressBook {
+        addresses: HashMap<String, String>,
+    }
+    impl AddressBook {
+        map_contains_key!(pub addresses => String);
+    }
+
+    let book = AddressBook {
+        addresses: HashMap::from([(EX_HOME.to_string(), EX_STREET_LINE_1.to_string())]),
+    };
+    assert!(book.contains_addresses_key(&EX_HOME.to_string()));
+    assert!(!book.contains_addresses_key(&EX_WORK.to_string()));
+}
+
+#[test]
+fn test_map_contains_key_base_case_rename() {
+    pub struct AddressBook {
+        counts: BTreeMap<u32, u32>,
+    }
+    impl AddressBook {
+        map_contains_key!(pub unit_counts => counts, u32);
+    }
+
+    let book = AddressBook {
+        counts: BTreeMap::from([(EX_UNIT_NUMBER_1, 2)]),
+    };
+    assert!(book.contains_unit_counts_key(&EX_UNIT_NUMBER_1));
+    assert!(!book.contains_unit_counts_key(&EX_UNIT_NUMBER_2));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Maps ❱ map_keys!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_map_keys_base_case() {
+    pub struct AddressBook {
+        addresses: BTreeMap<String, String>,
+    }
+    impl AddressBook {
+        map_keys!(pub addresses => String);
+    }
+
+    let book = AddressBook {
+        addresses: BTreeMap::from([
+            (EX_HOME.to_string(), EX_STREET_LINE_1.to_string()),
+            (EX_WORK.to_string(), EX_STREET_LINE_2.to_string()),
+        ]),
+    };
+    let keys: Vec<&String> = book.addresses_keys().collect();
+    assert_eq!(keys, vec![&EX_HOME.to_string(), &EX_WORK.to_string()]);
+}
+
+#[test]
+fn test_map_keys_copy_case() {
+    pub struct AddressBook {
+        unit_counts: BTreeMap<u32, u32>,
+    }
+    impl AddressBook {
+        map_keys!(pub unit_counts => copy u32);
+    }
+
+    let book = AddressBook {
+        unit_counts: BTreeMap::from([(EX_UNIT_NUMBER_1, 2), (EX_UNIT_NUMBER_2, 1)]),
+    };
+    let keys: Vec<u32> = book.unit_counts_keys().collect();
+    assert_eq!(keys, vec![EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2]);
+}
+
+#[test]
+fn test_map_keys_copy_case_rename() {
+    pub struct AddressBook {
+        counts: BTreeMap<u32, u32>,
+    }
+    impl AddressBook {
+        map_keys!(pub unit_counts => counts, copy u32);
+    }
+
+    let book = AddressBook {
+        counts: BTreeMap::from([(EX_UNIT_NUMBER_1, 2)]),
+    };
+    assert_eq!(book.unit_counts_keys().sum::<u32>(), EX_UNIT_NUMBER_1);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Maps ❱ map_values!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_map_values_base_case() {
+    pub struct AddressBook {
+        addresses: BTreeMap<String, String>,
+    }
+    impl AddressBook {
+        map_values!(pub addresses => String);
+    }
+
+    let book = AddressBook {
+        addresses: BTreeMap::from([
+            (EX_HOME.to_string(), EX_STREET_LINE_1.to_string()),
+            (EX_WORK.to_string(), EX_STREET_LINE_2.to_string()),
+        ]),
+    };
+    let values: Vec<&String> = book.addresses_values().collect();
+    assert_eq!(
+        values,
+        vec![&EX_STREET_LINE_1.to_string(), &EX_STREET_LINE_2.to_string()]
+    );
+}
+
+#[test]
+fn test_map_values_copy_case() {
+    pub struct AddressBook {
+        unit_counts: HashMap<u32, u32>,
+    }
+    impl AddressBook {
+        map_values!(pub unit_counts => copy u32);
+    }
+
+    let book = AddressBook {
+        unit_counts: HashMap::from([(EX_UNIT_NUMBER_1, 2), (EX_UNIT_NUMBER_2, 1)]),
+    };
+    assert_eq!(book.unit_counts_values().sum::<u32>(), 3);
+}
+
+#[test]
+fn test_map_values_copy_case_rename() {
+    pub struct AddressBook {
+        counts: HashMap<u32, u32>,
+    }
+    impl AddressBook {
+        map_values!(pub unit_counts => counts, copy u32);
+    }
+
+    let book = AddressBook {
+        counts: HashMap::from([(EX_UNIT_NUMBER_1, 2)]),
+    };
+    assert_eq!(book.unit_counts_values().sum::<u32>(), 2);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Maps ❱ map_len! & map_is_empty!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_map_len_and_is_empty_base_case() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        addresses: HashMap<String, String>,
+    }
+    impl AddressBook {
+        map_len!(pub addresses);
+        map_is_empty!(pub addresses);
+    }
+
+    let mut book = AddressBook::default();
+    assert!(book.is_addresses_empty());
+    assert_eq!(book.addresses_len(), 0);
+    let _ = book
+        .addresses
+        .insert(EX_HOME.to_string(), EX_STREET_LINE_1.to_string());
+    assert!(!book.is_addresses_empty());
+    assert_eq!(book.addresses_len(), 1);
+}
+
+#[test]
+fn test_map_len_and_is_empty_base_case_rename() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        counts: BTreeMap<u32, u32>,
+    }
+    impl AddressBook {
+        map_len!(pub unit_counts => counts);
+        map_is_empty!(pub unit_counts => counts);
+    }
+
+    let mut book = AddressBook::default();
+    assert!(book.is_unit_counts_empty());
+    let _ = book.counts.insert(EX_UNIT_NUMBER_1, 1);
+    let _ = book.counts.insert(EX_UNIT_NUMBER_2, 1);
+    assert!(!book.is_unit_counts_empty());
+    assert_eq!(book.unit_counts_len(), 2);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Maps ❱ map_with_entry!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_map_with_entry_base_case() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        addresses: HashMap<String, String>,
+    }
+    impl AddressBook {
+        map_with_entry!(pub addresses => String, String);
+    }
+
+    let book = AddressBook::default()
+        .with_addresses_entry(EX_HOME.to_string(), EX_STREET_LINE_1.to_string())
+        .with_addresses_entry(EX_WORK.to_string(), EX_STREET_LINE_2.to_string());
+    assert_eq!(book.addresses.len(), 2);
+    assert_eq!(
+        book.addresses.get(EX_WORK),
+        Some(&EX_STREET_LINE_2.to_string())
+    );
+}
+
+#[test]
+fn test_map_with_entry_base_case_rename() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        counts: BTreeMap<u32, u32>,
+    }
+    impl AddressBook {
+        map_with_entry!(pub unit_counts => counts, u32, u32);
+    }
+
+    let book = AddressBook::default().with_unit_counts_entry(EX_UNIT_NUMBER_1, 2);
+    assert_eq!(book.counts.get(&EX_UNIT_NUMBER_1), Some(&2));
+}
+
+#[test]
+fn test_map_with_entry_into_key_case() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        addresses: HashMap<String, String>,
+    }
+    impl AddressBook {
+        map_with_entry!(pub addresses => into String, String);
+    }
+
+    let book = AddressBook::default().with_addresses_entry(EX_HOME, EX_STREET_LINE_1.to_string());
+    assert_eq!(
+        book.addresses.get(EX_HOME),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+}
+
+#[test]
+fn test_map_with_entry_into_value_case() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        addresses: BTreeMap<u32, String>,
+    }
+    impl AddressBook {
+        map_with_entry!(pub addresses => u32, into String);
+    }
+
+    let book = AddressBook::default().with_addresses_entry(EX_UNIT_NUMBER_1, EX_STREET_LINE_1);
+    assert_eq!(
+        book.addresses.get(&EX_UNIT_NUMBER_1),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+}
+
+#[test]
+fn test_map_with_entry_into_key_and_value_case() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        addresses: HashMap<String, String>,
+    }
+    impl AddressBook {
+        map_with_entry!(pub addresses => into String, into String);
+    }
+
+    let book = AddressBook::default().with_addresses_entry(EX_HOME, EX_STREET_LINE_1);
+    assert_eq!(
+        book.addresses.get(EX_HOME),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+}
+
+#[test]
+fn test_map_with_entry_into_key_and_value_case_rename() {
+    #[derive(Default)]
+    pub struct AddressBook {
+        entries: HashMap<String, String>,
+    }
+    impl AddressBook {
+        map_with_entry!(pub addresses => entries, into String, into String);
+    }
+
+    let book = AddressBook::default().with_addresses_entry(EX_HOME, EX_STREET_LINE_1);
+    assert_eq!(
+        book.entries.get(EX_HOME),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+}
@@ -0,0 +1,357 @@
+use jemmy::*;
+
+const EX_BYTES_SENT: u64 = 1024;
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Numerics ❱ num_increment!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_num_increment_base_case() {
+    pub struct Statistics {
+        requests: u64,
+    }
+    impl Statistics {
+        num_increment!(pub requests);
+    }
+
+    let mut stats = Statistics { requests: 0 };
+    stats.increment_requests();
+    stats.increment_requests();
+    assert_eq!(stats.requests, 2);
+}
+
+#[test]
+fn test_num_increment_base_case_rename() {
+    pub struct Statistics {
+        request_count: u32,
+    }
+    impl Statistics {
+        num_increment!(pub requests => request_count);
+    }
+
+    let mut stats = Statistics { request_count: 9 };
+    stats.increment_requests();
+    assert_eq!(stats.request_count, 10);
+}
+
+#[test]
+fn test_num_increment_returning_case() {
+    pub struct Statistics {
+        requests: u64,
+    }
+    impl Statistics {
+        num_increment!(pub requests => returning u64);
+    }
+
+    let mut stats = Statistics { requests: 0 };
+    assert_eq!(stats.increment_requests(), 1);
+    assert_eq!(stats.increment_requests(), 2);
+}
+
+#[test]
+fn test_num_increment_returning_case_rename() {
+    pub struct Statistics {
+        request_count: u8,
+    }
+    impl Statistics {
+        num_increment!(pub requests => request_count, returning u8);
+    }
+
+    let mut stats = Statistics { request_count: 41 };
+    assert_eq!(stats.increment_requests(), 42);
+}
+
+#[test]
+#[should_panic(expected = "attempt to increment the field `requests` with overflow")]
+fn test_num_increment_base_case_overflow() {
+    pub struct Statistics {
+        requests: u8,
+    }
+    impl Statistics {
+        num_increment!(pub requests);
+    }
+
+    let mut stats = Statistics { requests: u8::MAX };
+    stats.increment_requests();
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Numerics ❱ num_decrement!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_num_decrement_base_case() {
+    pub struct Statistics {
+        in_flight: i32,
+    }
+    impl Statistics {
+        num_decrement!(pub in_flight);
+    }
+
+    let mut stats = Statistics { in_flight: 0 };
+    stats.decrement_in_flight();
+    assert_eq!(stats.in_flight, -1);
+}
+
+#[test]
+fn test_num_decrement_returning_case() {
+    pub struct Statistics {
+        in_flight: usize,
+    }
+    impl Statistics {
+        num_decrement!(pub in_flight => returning usize);
+    }
+
+    let mut stats = Statistics { in_flight: 2 };
+    assert_eq!(stats.decrement_in_flight(), 1);
+    assert_eq!(stats.decrement_in_flight(), 0);
+}
+
+#[test]
+#[should_panic(expected = "attempt to decrement the field `in_flight` with overflow")]
+fn test_num_decrement_returning_case_overflow() {
+    pub struct Statistics {
+        in_flight: usize,
+    }
+    impl Statistics {
+        num_decrement!(pub in_flight => returning usize);
+    }
+
+    let mut stats = Statistics { in_flight: 0 };
+    stats.decrement_in_flight();
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Numerics ❱ num_add_to!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_num_add_to_base_case() {
+    pub struct Statistics {
+        bytes_sent: u64,
+    }
+    impl Statistics {
+        num_add_to!(pub bytes_sent => u64);
+    }
+
+    let mut stats = Statistics { bytes_sent: 0 };
+    stats.add_to_bytes_sent(EX_BYTES_SENT);
+    stats.add_to_bytes_sent(EX_BYTES_SENT);
+    assert_eq!(stats.bytes_sent, 2 * EX_BYTES_SENT);
+}
+
+#[test]
+fn test_num_add_to_into_case() {
+    pub struct Statistics {
+        bytes_sent: u64,
+    }
+    impl Statistics {
+        num_add_to!(pub bytes_sent => into u64);
+    }
+
+    let mut stats = Statistics { bytes_sent: 0 };
+    stats.add_to_bytes_sent(16_u8);
+    stats.add_to_bytes_sent(1_u32);
+    assert_eq!(stats.bytes_sent, 17);
+}
+
+#[test]
+fn test_num_add_to_returning_case_rename() {
+    pub struct Statistics {
+        sent: u64,
+    }
+    impl Statistics {
+        num_add_to!(pub bytes_sent => sent, returning u64);
+    }
+
+    let mut stats = Statistics { sent: 1 };
+    assert_eq!(stats.add_to_bytes_sent(EX_BYTES_SENT), EX_BYTES_SENT + 1);
+}
+
+#[test]
+fn test_num_add_to_returning_into_case() {
+    pub struct Statistics {
+        bytes_sent: u64,
+    }
+    impl Statistics {
+        num_add_to!(pub bytes_sent => returning into u64);
+    }
+
+    let mut stats = Statistics { bytes_sent: 0 };
+    assert_eq!(stats.add_to_bytes_sent(8_u16), 8);
+}
+
+#[test]
+#[should_panic(expected = "attempt to add to the field `sent` with overflow")]
+fn test_num_add_to_into_case_rename_overflow() {
+    pub struct Statistics {
+        sent: u64,
+    }
+    impl Statistics {
+        num_add_to!(pub bytes_sent => sent, into u64);
+    }
+
+    let mut stats = Statistics { sent: u64::MAX };
+    stats.add_to_bytes_sent(1_u8);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Numerics ❱ num_checked_add_to!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_num_checked_add_to_base_case() {
+    pub struct Statistics {
+        retries: u8,
+    }
+    impl Statistics {
+        num_checked_add_to!(pub retries => u8);
+    }
+
+    let mut stats = Statistics { retries: 250 };
+    assert_eq!(stats.checked_add_to_retries(5), Some(()));
+    assert_eq!(stats.retries, 255);
+    assert_eq!(stats.checked_add_to_retries(1), None);
+    assert_eq!(stats.retries, 255);
+}
+
+#[test]
+fn test_num_checked_add_to_into_case_rename() {
+    pub struct Statistics {
+        retry_count: u16,
+    }
+    impl Statistics {
+        num_checked_add_to!(pub retries => retry_count, into u16);
+    }
+
+    let mut stats = Statistics { retry_count: 0 };
+    assert_eq!(stats.checked_add_to_retries(200_u8), Some(()));
+    assert_eq!(stats.retry_count, 200);
+}
+
+#[test]
+fn test_num_checked_add_to_returning_case() {
+    pub struct Statistics {
+        retries: u8,
+    }
+    impl Statistics {
+        num_checked_add_to!(pub retries => returning u8);
+    }
+
+    let mut stats = Statistics { retries: 250 };
+    assert_eq!(stats.checked_add_to_retries(5), Some(255));
+    assert_eq!(stats.checked_add_to_retries(1), None);
+    assert_eq!(stats.retries, 255);
+}
+
+#[test]
+fn test_num_checked_add_to_returning_into_case() {
+    pub struct Statistics {
+        bytes_sent: u64,
+    }
+    impl Statistics {
+        num_checked_add_to!(pub bytes_sent => returning into u64);
+    }
+
+    let mut stats = Statistics {
+        bytes_sent: u64::MAX - 1,
+    };
+    assert_eq!(stats.checked_add_to_bytes_sent(1_u8), Some(u64::MAX));
+    assert_eq!(stats.checked_add_to_bytes_sent(1_u8), None);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Numerics ❱ num_saturating_sub_from!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_num_saturating_sub_from_base_case() {
+    pub struct Statistics {
+        credits: u32,
+    }
+    impl Statistics {
+        num_saturating_sub_from!(pub credits => u32);
+    }
+
+    let mut stats = Statistics { credits: 10 };
+    stats.saturating_sub_from_credits(4);
+    assert_eq!(stats.credits, 6);
+    stats.saturating_sub_from_credits(100);
+    assert_eq!(stats.credits, 0);
+}
+
+#[test]
+fn test_num_saturating_sub_from_into_case() {
+    pub struct Statistics {
+        credits: i64,
+    }
+    impl Statistics {
+        num_saturating_sub_from!(pub credits => into i64);
+    }
+
+    let mut stats = Statistics {
+        credits: i64::MIN + 1,
+    };
+    stats.saturating_sub_from_credits(2_i8);
+    assert_eq!(stats.credits, i64::MIN);
+}
+
+#[test]
+fn test_num_saturating_sub_from_returning_case_rename() {
+    pub struct Statistics {
+        credit_balance: u32,
+    }
+    impl Statistics {
+        num_saturating_sub_from!(pub credits => credit_balance, returning u32);
+    }
+
+    let mut stats = Statistics { credit_balance: 3 };
+    assert_eq!(stats.saturating_sub_from_credits(2), 1);
+    assert_eq!(stats.saturating_sub_from_credits(2), 0);
+}
+
+#[test]
+fn test_num_saturating_sub_from_returning_into_case() {
+    pub struct Statistics {
+        credits: u32,
+    }
+    impl Statistics {
+        num_saturating_sub_from!(pub credits => returning into u32);
+    }
+
+    let mut stats = Statistics { credits: 3 };
+    assert_eq!(stats.saturating_sub_from_credits(1_u8), 2);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Numerics ❱ num_reset!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_num_reset_base_case() {
+    pub struct Statistics {
+        requests: u64,
+    }
+    impl Statistics {
+        num_reset!(pub requests);
+    }
+
+    let mut stats = Statistics { requests: 42 };
+    stats.reset_requests();
+    assert_eq!(stats.requests, 0);
+}
+
+#[test]
+fn test_num_reset_base_case_rename() {
+    pub struct Statistics {
+        request_count: i16,
+    }
+    impl Statistics {
+        num_reset!(pub requests => request_count);
+    }
+
+    let mut stats = Statistics { request_count: -7 };
+    stats.reset_requests();
+    assert_eq!(stats.request_count, 0);
+}
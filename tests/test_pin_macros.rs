@@ -0,0 +1,207 @@
+use jemmy::*;
+use std::future::Future;
+use std::marker::PhantomPinned;
+use std::pin::{pin, Pin};
+use std::task::{Context, Poll, Waker};
+
+const EX_VALUE: u32 = 42;
+const EX_POLLS: usize = 3;
+
+pub struct Countdown {
+    remaining: usize,
+    _pinned: PhantomPinned,
+}
+
+impl Countdown {
+    fn new(remaining: usize) -> Self {
+        Self {
+            remaining,
+            _pinned: PhantomPinned,
+        }
+    }
+}
+
+impl Future for Countdown {
+    type Output = u32;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: the field `remaining` is not structurally pinned.
+        let remaining = unsafe { &mut self.get_unchecked_mut().remaining };
+        if *remaining == 0 {
+            Poll::Ready(EX_VALUE)
+        } else {
+            *remaining -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Pin ❱ pin_get!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_pin_get_base_case() {
+    pub struct Counted<F> {
+        inner: F,
+        polls: usize,
+    }
+    impl<F> Counted<F> {
+        pin_get!(unsafe pub inner => pinned F);
+        pin_get!(unsafe pub polls => unpinned usize);
+    }
+    impl<F: Future> Future for Counted<F> {
+        type Output = (F::Output, usize);
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            *self.as_mut().polls_unpinned() += 1;
+            match self.as_mut().inner_pinned().poll(cx) {
+                Poll::Ready(value) => Poll::Ready((value, *self.polls_unpinned())),
+                Poll::Pending => Poll::Pending,
+            }
+        }
+    }
+
+    let mut future = pin!(Counted {
+        inner: Countdown::new(EX_POLLS),
+        polls: 0,
+    });
+    let mut cx = Context::from_waker(Waker::noop());
+    for _ in 0..EX_POLLS {
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+    }
+    assert_eq!(
+        future.as_mut().poll(&mut cx),
+        Poll::Ready((EX_VALUE, EX_POLLS + 1))
+    );
+}
+
+#[test]
+fn test_pin_get_base_case_rename() {
+    pub struct Wrapper {
+        countdown: Countdown,
+        last: Option<u32>,
+    }
+    impl Wrapper {
+        pin_get!(unsafe pub inner => countdown, pinned Countdown);
+        pin_get!(unsafe pub result => last, unpinned Option<u32>);
+    }
+
+    let mut wrapper = pin!(Wrapper {
+        countdown: Countdown::new(0),
+        last: None,
+    });
+    let mut cx = Context::from_waker(Waker::noop());
+    if let Poll::Ready(value) = wrapper.as_mut().inner_pinned().poll(&mut cx) {
+        *wrapper.as_mut().result_unpinned() = Some(value);
+    }
+    assert_eq!(*wrapper.as_mut().result_unpinned(), Some(EX_VALUE));
+}
+
+#[test]
+fn test_pin_get_unpinned_case_replace() {
+    pub struct Buffered {
+        buffer: Vec<u32>,
+    }
+    impl Buffered {
+        pin_get!(unsafe buffer => unpinned Vec<u32>);
+    }
+
+    let mut buffered = Buffered {
+        buffer: vec![EX_VALUE],
+    };
+    let taken = std::mem::take(Pin::new(&mut buffered).buffer_unpinned());
+    assert_eq!(taken, vec![EX_VALUE]);
+    assert!(buffered.buffer.is_empty());
+}
+
+#[test]
+fn test_pin_get_pinned_case_unpin_field() {
+    pub struct Wrapper<F> {
+        inner: F,
+    }
+    impl<F> Wrapper<F> {
+        pin_get!(unsafe pub inner => pinned F);
+    }
+
+    // the wrapper is `Unpin` as its pinned field is, so it may be pinned with `Pin::new`.
+    let mut wrapper = Wrapper {
+        inner: std::future::ready(EX_VALUE),
+    };
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(
+        Pin::new(&mut wrapper).inner_pinned().poll(&mut cx),
+        Poll::Ready(EX_VALUE)
+    );
+}
+
+#[test]
+fn test_pin_get_pinned_case_multiple_fields() {
+    pub struct Both<A, B> {
+        first: A,
+        second: B,
+        done: (bool, bool),
+    }
+    impl<A: Future, B: Future> Both<A, B> {
+        pin_get!(unsafe first => pinned A);
+        pin_get!(unsafe second => pinned B);
+        pin_get!(unsafe done => unpinned (bool, bool));
+    }
+    impl<A: Future, B: Future> Future for Both<A, B> {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            if !self.as_mut().done_unpinned().0 {
+                let ready = self.as_mut().first_pinned().poll(cx).is_ready();
+                self.as_mut().done_unpinned().0 = ready;
+            }
+            if !self.as_mut().done_unpinned().1 {
+                let ready = self.as_mut().second_pinned().poll(cx).is_ready();
+                self.as_mut().done_unpinned().1 = ready;
+            }
+            if *self.done_unpinned() == (true, true) {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        }
+    }
+
+    let mut future = pin!(Both {
+        first: Countdown::new(1),
+        second: Countdown::new(EX_POLLS),
+        done: (false, false),
+    });
+    let mut cx = Context::from_waker(Waker::noop());
+    for _ in 0..EX_POLLS {
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+    }
+    assert_eq!(*future.as_mut().done_unpinned(), (true, false));
+    assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(()));
+}
+
+#[test]
+fn test_pin_get_pinned_case_stable_address() {
+    pub struct Wrapper {
+        countdown: Countdown,
+    }
+    impl Wrapper {
+        pin_get!(unsafe pub countdown => pinned Countdown);
+    }
+
+    let mut wrapper = pin!(Wrapper {
+        countdown: Countdown::new(EX_POLLS),
+    });
+    let mut cx = Context::from_waker(Waker::noop());
+    let address: *const Countdown = &*wrapper.as_mut().countdown_pinned();
+    while wrapper
+        .as_mut()
+        .countdown_pinned()
+        .poll(&mut cx)
+        .is_pending()
+    {
+        let current: *const Countdown = &*wrapper.as_mut().countdown_pinned();
+        assert_eq!(current, address);
+    }
+}
@@ -0,0 +1,387 @@
+use jemmy::*;
+use std::rc::Rc;
+use std::sync::Arc;
+
+const EX_HANDLER: &str = "index.html";
+const EX_FALLBACK: &str = "404.html";
+const EX_PORT: u16 = 8080;
+const EX_OTHER_PORT: u16 = 9090;
+const EX_ROOT: &str = "root";
+const EX_CHILD: &str = "child";
+const EX_TOKEN: &str = "token";
+const EX_BYTES: &[u8] = &[0x01, 0x02, 0x03];
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Handler {
+    path: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    port: u16,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Pointers ❱ boxed
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_get_and_set_boxed_case() {
+    pub struct Server {
+        handler: Box<Handler>,
+    }
+    impl Server {
+        get_and_set!(pub handler => boxed Handler);
+        get_mut!(pub handler => boxed Handler);
+    }
+
+    let mut server = Server {
+        handler: Box::default(),
+    };
+    server.set_handler(Handler {
+        path: EX_HANDLER.to_string(),
+    });
+    assert_eq!(server.handler().path, EX_HANDLER);
+    server.handler_mut().path = EX_FALLBACK.to_string();
+    assert_eq!(server.handler().path, EX_FALLBACK);
+}
+
+#[test]
+fn test_get_and_set_boxed_case_rename() {
+    pub struct Server {
+        boxed_handler: Box<Handler>,
+    }
+    impl Server {
+        get_and_set!(pub handler => boxed_handler, boxed Handler);
+    }
+
+    let mut server = Server {
+        boxed_handler: Box::default(),
+    };
+    server.set_handler(Handler {
+        path: EX_HANDLER.to_string(),
+    });
+    assert_eq!(server.handler().path, EX_HANDLER);
+}
+
+#[test]
+fn test_with_boxed_case() {
+    #[derive(Default)]
+    pub struct Server {
+        handler: Box<Handler>,
+    }
+    impl Server {
+        with!(pub handler => boxed Handler);
+        get!(pub handler => boxed Handler);
+    }
+
+    let server = Server::default().with_handler(Handler {
+        path: EX_HANDLER.to_string(),
+    });
+    assert_eq!(server.handler().path, EX_HANDLER);
+}
+
+#[test]
+fn test_get_mut_set_and_unset_boxed_case() {
+    #[derive(Default)]
+    pub struct Server {
+        fallback: Option<Box<Handler>>,
+    }
+    impl Server {
+        get_mut_set_and_unset!(pub fallback => boxed Handler);
+    }
+
+    let mut server = Server::default();
+    assert_eq!(server.fallback(), None);
+    server.set_fallback(Handler {
+        path: EX_FALLBACK.to_string(),
+    });
+    assert_eq!(
+        server.fallback().map(|handler| handler.path.as_str()),
+        Some(EX_FALLBACK)
+    );
+    server.fallback_mut().unwrap().path.clear();
+    assert_eq!(
+        server.fallback().map(|handler| handler.path.as_str()),
+        Some("")
+    );
+    server.unset_fallback();
+    assert_eq!(server.fallback(), None);
+}
+
+#[test]
+fn test_with_get_set_and_unset_boxed_case() {
+    #[derive(Default)]
+    pub struct Server {
+        fallback: Option<Box<Handler>>,
+    }
+    impl Server {
+        with_get_set_and_unset!(pub fallback => boxed Handler);
+    }
+
+    let server = Server::default().with_fallback(Handler {
+        path: EX_FALLBACK.to_string(),
+    });
+    assert_eq!(
+        server.fallback().map(|handler| handler.path.as_str()),
+        Some(EX_FALLBACK)
+    );
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Pointers ❱ shared
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_get_shared_arc_case() {
+    pub struct Server {
+        config: Arc<Config>,
+    }
+    impl Server {
+        get!(pub config => shared Arc<Config>);
+    }
+
+    let server = Server {
+        config: Arc::new(Config { port: EX_PORT }),
+    };
+    assert_eq!(server.config().port, EX_PORT);
+    let handle: Arc<Config> = server.config_arc();
+    assert!(Arc::ptr_eq(&handle, &server.config));
+}
+
+#[test]
+fn test_get_mut_shared_arc_case_copy_on_write() {
+    pub struct Server {
+        config: Arc<Config>,
+    }
+    impl Server {
+        get!(pub config => shared Arc<Config>);
+        get_mut!(pub config => shared Arc<Config>);
+    }
+
+    let mut server = Server {
+        config: Arc::new(Config { port: EX_PORT }),
+    };
+    let handle = server.config_arc();
+    server.config_make_mut().port = EX_OTHER_PORT;
+    assert_eq!(server.config().port, EX_OTHER_PORT);
+    assert_eq!(handle.port, EX_PORT);
+    assert!(!Arc::ptr_eq(&handle, &server.config));
+}
+
+#[test]
+fn test_get_and_set_shared_rc_case_rename() {
+    pub struct Server {
+        shared_config: Rc<Config>,
+    }
+    impl Server {
+        get_and_set!(pub config => shared_config, shared Rc<Config>);
+    }
+
+    let mut server = Server {
+        shared_config: Rc::new(Config { port: EX_PORT }),
+    };
+    let handle: Rc<Config> = server.config_arc();
+    server.set_config(Config {
+        port: EX_OTHER_PORT,
+    });
+    assert_eq!(server.config().port, EX_OTHER_PORT);
+    assert_eq!(handle.port, EX_PORT);
+}
+
+#[test]
+fn test_with_get_and_set_shared_case() {
+    pub struct Server {
+        config: Arc<Config>,
+    }
+    impl Server {
+        with_get_and_set!(pub config => shared Arc<Config>);
+    }
+
+    let server = Server {
+        config: Arc::new(Config { port: EX_PORT }),
+    }
+    .with_config(Config {
+        port: EX_OTHER_PORT,
+    });
+    assert_eq!(server.config().port, EX_OTHER_PORT);
+}
+
+#[test]
+fn test_get_mut_set_and_unset_shared_case() {
+    #[derive(Default)]
+    pub struct Server {
+        config: Option<Arc<Config>>,
+    }
+    impl Server {
+        get_mut_set_and_unset!(pub config => shared Arc<Config>);
+    }
+
+    let mut server = Server::default();
+    assert_eq!(server.config(), None);
+    assert_eq!(server.config_make_mut(), None);
+    server.set_config(Config { port: EX_PORT });
+    let handle = server.config_arc().unwrap();
+    server.config_make_mut().unwrap().port = EX_OTHER_PORT;
+    assert_eq!(
+        server.config().map(|config| config.port),
+        Some(EX_OTHER_PORT)
+    );
+    assert_eq!(handle.port, EX_PORT);
+    server.unset_config();
+    assert_eq!(server.config_arc(), None);
+}
+
+#[test]
+fn test_with_get_set_and_unset_shared_case() {
+    #[derive(Default)]
+    pub struct Server {
+        config: Option<Rc<Config>>,
+    }
+    impl Server {
+        with_get_set_and_unset!(pub config => shared Rc<Config>);
+    }
+
+    let server = Server::default().with_config(Config { port: EX_PORT });
+    assert_eq!(server.config(), Some(&Config { port: EX_PORT }));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Pointers ❱ cow
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_get_and_set_cow_case() {
+    use std::borrow::Cow;
+
+    pub struct Token<'a> {
+        text: Cow<'a, str>,
+    }
+    impl<'a> Token<'a> {
+        get_and_set!(pub text => cow 'a str);
+        get_mut!(pub text => cow 'a str);
+    }
+
+    let source = String::from(EX_TOKEN);
+    let mut token = Token {
+        text: Cow::Borrowed(""),
+    };
+    token.set_text(source.as_str());
+    assert!(matches!(token.text, Cow::Borrowed(_)));
+    assert_eq!(token.text(), EX_TOKEN);
+
+    token.text_to_mut().push_str(EX_CHILD);
+    assert!(matches!(token.text, Cow::Owned(_)));
+    assert_eq!(token.text(), format!("{EX_TOKEN}{EX_CHILD}"));
+
+    token.set_text(EX_ROOT.to_string());
+    let owned: String = token.into_owned_text();
+    assert_eq!(owned, EX_ROOT);
+}
+
+#[test]
+fn test_with_get_and_set_cow_slice_case_rename() {
+    use std::borrow::Cow;
+
+    #[derive(Default)]
+    pub struct Frame<'a> {
+        raw_payload: Cow<'a, [u8]>,
+    }
+    impl<'a> Frame<'a> {
+        with_get_and_set!(pub payload => raw_payload, cow 'a [u8]);
+        get_mut!(pub payload => raw_payload, cow 'a [u8]);
+    }
+
+    let frame = Frame::default().with_payload(EX_BYTES);
+    assert!(matches!(frame.raw_payload, Cow::Borrowed(_)));
+    assert_eq!(frame.payload(), EX_BYTES);
+
+    let mut frame = frame;
+    frame.payload_to_mut().push(0x04);
+    assert_eq!(frame.payload(), &[0x01, 0x02, 0x03, 0x04]);
+
+    frame.set_payload(vec![0xFF]);
+    assert_eq!(frame.into_owned_payload(), vec![0xFF]);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Pointers ❱ weak!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_weak_base_case() {
+    use std::rc::Weak;
+
+    pub struct Node {
+        label: &'static str,
+        parent: Weak<Node>,
+    }
+    impl Node {
+        weak!(pub parent => Node);
+    }
+
+    let root = Rc::new(Node {
+        label: EX_ROOT,
+        parent: Weak::new(),
+    });
+    let mut child = Node {
+        label: EX_CHILD,
+        parent: Weak::new(),
+    };
+    assert!(!child.has_parent());
+    assert!(child.parent().is_none());
+
+    child.set_parent(&root);
+    assert!(child.has_parent());
+    assert_eq!(child.parent().unwrap().label, EX_ROOT);
+    assert_eq!(Rc::strong_count(&root), 1);
+
+    child.unset_parent();
+    assert!(!child.has_parent());
+    assert_eq!(child.label, EX_CHILD);
+}
+
+#[test]
+fn test_weak_base_case_dropped() {
+    use std::rc::Weak;
+
+    pub struct Node {
+        parent: Weak<Config>,
+    }
+    impl Node {
+        weak!(pub parent => Config);
+    }
+
+    let mut node = Node {
+        parent: Weak::new(),
+    };
+    let config = Rc::new(Config { port: EX_PORT });
+    node.set_parent(&config);
+    assert_eq!(node.parent().map(|config| config.port), Some(EX_PORT));
+
+    drop(config);
+    assert!(!node.has_parent());
+    assert!(node.parent().is_none());
+}
+
+#[test]
+fn test_weak_sync_case_rename() {
+    use std::sync::Weak;
+
+    pub struct Worker {
+        config_ref: Weak<Config>,
+    }
+    impl Worker {
+        weak!(pub config => config_ref, sync Config);
+    }
+
+    let config = Arc::new(Config { port: EX_PORT });
+    let mut worker = Worker {
+        config_ref: Weak::new(),
+    };
+    worker.set_config(&config);
+    let upgraded: Arc<Config> = worker.config().unwrap();
+    assert!(Arc::ptr_eq(&upgraded, &config));
+    worker.unset_config();
+    assert!(!worker.has_config());
+}
@@ -0,0 +1,446 @@
+use jemmy::*;
+use std::collections::{BTreeSet, HashSet};
+
+const EX_TAG_HOME: &str = "home";
+const EX_TAG_WORK: &str = "work";
+const EX_TAG_BILLING: &str = "billing";
+const EX_UNIT_NUMBER_1: u32 = 24;
+const EX_UNIT_NUMBER_2: u32 = 202;
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Sets ❱ collection_set_contains!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_set_contains_base_case() {
+    pub struct Address {
+        tags: HashSet<String>,
+    }
+    impl Address {
+        collection_set_contains!(pub tags => String);
+    }
+
+    let address = Address {
+        tags: HashSet::from([EX_TAG_HOME.to_string()]),
+    };
+    assert!(address.tags_contains(&EX_TAG_HOME.to_string()));
+    assert!(!address.tags_contains(&EX_TAG_WORK.to_string()));
+}
+
+#[test]
+fn test_set_contains_base_case_rename() {
+    pub struct Address {
+        units: BTreeSet<u32>,
+    }
+    impl Address {
+        collection_set_contains!(pub unit_numbers => units, u32);
+    }
+
+    let address = Address {
+        units: BTreeSet::from([EX_UNIT_NUMBER_1]),
+    };
+    assert!(address.unit_numbers_contains(&EX_UNIT_NUMBER_1));
+    assert!(!address.unit_numbers_contains(&EX_UNIT_NUMBER_2));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Sets ❱ collection_set_insert!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_set_insert_base_case() {
+    #[derive(Default)]
+    pub struct Address {
+        tags: HashSet<String>,
+    }
+    impl Address {
+        collection_set_insert!(pub tags => String);
+    }
+
+    let mut address = Address::default();
+    assert!(address.tags_insert(EX_TAG_HOME.to_string()));
+    assert!(!address.tags_insert(EX_TAG_HOME.to_string()));
+    assert_eq!(address.tags.len(), 1);
+}
+
+#[test]
+fn test_set_insert_base_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        units: BTreeSet<u32>,
+    }
+    impl Address {
+        collection_set_insert!(pub unit_numbers => units, u32);
+    }
+
+    let mut address = Address::default();
+    assert!(address.unit_numbers_insert(EX_UNIT_NUMBER_1));
+    assert!(address.units.contains(&EX_UNIT_NUMBER_1));
+}
+
+#[test]
+fn test_set_insert_into_case() {
+    #[derive(Default)]
+    pub struct Address {
+        tags: BTreeSet<String>,
+    }
+    impl Address {
+        collection_set_insert!(pub tags => into String);
+    }
+
+    let mut address = Address::default();
+    assert!(address.tags_insert(EX_TAG_HOME));
+    assert!(!address.tags_insert(EX_TAG_HOME));
+    assert!(address.tags.contains(EX_TAG_HOME));
+}
+
+#[test]
+fn test_set_insert_into_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        labels: HashSet<String>,
+    }
+    impl Address {
+        collection_set_insert!(pub tags => labels, into String);
+    }
+
+    let mut address = Address::default();
+    assert!(address.tags_insert(EX_TAG_WORK));
+    assert!(address.labels.contains(EX_TAG_WORK));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Sets ❱ collection_set_remove!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_set_remove_base_case() {
+    pub struct Address {
+        tags: HashSet<String>,
+    }
+    impl Address {
+        collection_set_remove!(pub tags => String);
+    }
+
+    let mut address = Address {
+        tags: HashSet::from([EX_TAG_HOME.to_string()]),
+    };
+    assert!(address.tags_remove(&EX_TAG_HOME.to_string()));
+    assert!(!address.tags_remove(&EX_TAG_HOME.to_string()));
+    assert!(address.tags.is_empty());
+}
+
+#[test]
+fn test_set_remove_base_case_rename() {
+    pub struct Address {
+        units: BTreeSet<u32>,
+    }
+    impl Address {
+        collection_set_remove!(pub unit_numbers => units, u32);
+    }
+
+    let mut address = Address {
+        units: BTreeSet::from([EX_UNIT_NUMBER_1]),
+    };
+    assert!(address.unit_numbers_remove(&EX_UNIT_NUMBER_1));
+    assert!(address.units.is_empty());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Sets ❱ collection_set_iter!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_set_iter_base_case() {
+    pub struct Address {
+        tags: BTreeSet<String>,
+    }
+    impl Address {
+        collection_set_iter!(pub tags => String);
+    }
+
+    let address = Address {
+        tags: BTreeSet::from([EX_TAG_WORK.to_string(), EX_TAG_HOME.to_string()]),
+    };
+    let tags: Vec<&String> = address.tags_iter().collect();
+    assert_eq!(
+        tags,
+        vec![&EX_TAG_HOME.to_string(), &EX_TAG_WORK.to_string()]
+    );
+}
+
+#[test]
+fn test_set_iter_copy_case() {
+    pub struct Address {
+        unit_numbers: BTreeSet<u32>,
+    }
+    impl Address {
+        collection_set_iter!(pub unit_numbers => copy u32);
+    }
+
+    let address = Address {
+        unit_numbers: BTreeSet::from([EX_UNIT_NUMBER_2, EX_UNIT_NUMBER_1]),
+    };
+    let units: Vec<u32> = address.unit_numbers_iter().collect();
+    assert_eq!(units, vec![EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2]);
+}
+
+#[test]
+fn test_set_iter_copy_case_rename() {
+    pub struct Address {
+        units: HashSet<u32>,
+    }
+    impl Address {
+        collection_set_iter!(pub unit_numbers => units, copy u32);
+    }
+
+    let address = Address {
+        units: HashSet::from([EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2]),
+    };
+    assert_eq!(
+        address.unit_numbers_iter().sum::<u32>(),
+        EX_UNIT_NUMBER_1 + EX_UNIT_NUMBER_2
+    );
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Sets ❱ collection_set_len!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_set_len_base_case() {
+    pub struct Address {
+        tags: HashSet<String>,
+    }
+    impl Address {
+        collection_set_len!(pub tags);
+    }
+
+    let address = Address {
+        tags: HashSet::from([EX_TAG_HOME.to_string(), EX_TAG_WORK.to_string()]),
+    };
+    assert_eq!(address.tags_len(), 2);
+}
+
+#[test]
+fn test_set_len_base_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        labels: BTreeSet<String>,
+    }
+    impl Address {
+        collection_set_len!(pub tags => labels);
+    }
+
+    let address = Address::default();
+    assert_eq!(address.tags_len(), 0);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Sets ❱ collection_set_extend!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_set_extend_base_case() {
+    pub struct Address {
+        tags: HashSet<String>,
+    }
+    impl Address {
+        collection_set_extend!(pub tags => String);
+    }
+
+    let mut address = Address {
+        tags: HashSet::from([EX_TAG_HOME.to_string()]),
+    };
+    address.tags_extend(vec![EX_TAG_HOME.to_string(), EX_TAG_WORK.to_string()]);
+    assert_eq!(address.tags.len(), 2);
+}
+
+#[test]
+fn test_set_extend_into_case() {
+    #[derive(Default)]
+    pub struct Address {
+        tags: BTreeSet<String>,
+    }
+    impl Address {
+        collection_set_extend!(pub tags => into String);
+    }
+
+    let mut address = Address::default();
+    address.tags_extend([EX_TAG_HOME, EX_TAG_WORK]);
+    assert!(address.tags.contains(EX_TAG_HOME));
+    assert!(address.tags.contains(EX_TAG_WORK));
+}
+
+#[test]
+fn test_set_extend_into_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        labels: BTreeSet<String>,
+    }
+    impl Address {
+        collection_set_extend!(pub tags => labels, into String);
+    }
+
+    let mut address = Address::default();
+    address.tags_extend([EX_TAG_HOME]);
+    assert!(address.labels.contains(EX_TAG_HOME));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Sets ❱ collection_set_union!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_set_union_base_case() {
+    pub struct Address {
+        tags: BTreeSet<String>,
+    }
+    impl Address {
+        collection_set_union!(pub tags => String);
+    }
+
+    let address = Address {
+        tags: BTreeSet::from([EX_TAG_HOME.to_string(), EX_TAG_WORK.to_string()]),
+    };
+    let other = HashSet::from([EX_TAG_WORK.to_string(), EX_TAG_BILLING.to_string()]);
+    let union: Vec<&String> = address.tags_union(&other).collect();
+    assert_eq!(
+        union,
+        vec![
+            &EX_TAG_HOME.to_string(),
+            &EX_TAG_WORK.to_string(),
+            &EX_TAG_BILLING.to_string()
+        ]
+    );
+}
+
+#[test]
+fn test_set_union_base_case_rename() {
+    pub struct Address {
+        units: HashSet<u32>,
+    }
+    impl Address {
+        collection_set_union!(pub unit_numbers => units, u32);
+    }
+
+    let address = Address {
+        units: HashSet::from([EX_UNIT_NUMBER_1]),
+    };
+    let other = BTreeSet::from([EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2]);
+    assert_eq!(
+        address.unit_numbers_union(&other).sum::<u32>(),
+        EX_UNIT_NUMBER_1 + EX_UNIT_NUMBER_2
+    );
+}
+
+#[test]
+fn test_set_union_repeated_other_case() {
+    pub struct Address {
+        tags: BTreeSet<String>,
+    }
+    impl Address {
+        collection_set_union!(pub tags => String);
+    }
+
+    let address = Address {
+        tags: BTreeSet::from([EX_TAG_HOME.to_string()]),
+    };
+    let other = vec![
+        EX_TAG_WORK.to_string(),
+        EX_TAG_HOME.to_string(),
+        EX_TAG_WORK.to_string(),
+        EX_TAG_BILLING.to_string(),
+        EX_TAG_BILLING.to_string(),
+    ];
+    let union: Vec<&String> = address.tags_union(&other).collect();
+    assert_eq!(
+        union,
+        vec![
+            &EX_TAG_HOME.to_string(),
+            &EX_TAG_WORK.to_string(),
+            &EX_TAG_BILLING.to_string()
+        ]
+    );
+}
+
+#[test]
+fn test_set_union_repeated_other_case_rename() {
+    pub struct Address {
+        units: HashSet<u32>,
+    }
+    impl Address {
+        collection_set_union!(pub unit_numbers => units, u32);
+    }
+
+    let address = Address {
+        units: HashSet::new(),
+    };
+    let other = [EX_UNIT_NUMBER_2, EX_UNIT_NUMBER_2, EX_UNIT_NUMBER_1];
+    let union: Vec<&u32> = address.unit_numbers_union(&other).collect();
+    assert_eq!(union, vec![&EX_UNIT_NUMBER_2, &EX_UNIT_NUMBER_1]);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Sets ❱ collection_set_with_member!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_set_with_member_base_case() {
+    #[derive(Default)]
+    pub struct Address {
+        tags: HashSet<String>,
+    }
+    impl Address {
+        collection_set_with_member!(pub tags => String);
+    }
+
+    let address = Address::default()
+        .with_tags_member(EX_TAG_HOME.to_string())
+        .with_tags_member(EX_TAG_WORK.to_string());
+    assert_eq!(address.tags.len(), 2);
+}
+
+#[test]
+fn test_set_with_member_base_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        units: BTreeSet<u32>,
+    }
+    impl Address {
+        collection_set_with_member!(pub unit_numbers => units, u32);
+    }
+
+    let address = Address::default().with_unit_numbers_member(EX_UNIT_NUMBER_1);
+    assert!(address.units.contains(&EX_UNIT_NUMBER_1));
+}
+
+#[test]
+fn test_set_with_member_into_case() {
+    #[derive(Default)]
+    pub struct Address {
+        tags: HashSet<String>,
+    }
+    impl Address {
+        collection_set_with_member!(pub tags => into String);
+    }
+
+    let address = Address::default()
+        .with_tags_member(EX_TAG_HOME)
+        .with_tags_member(EX_TAG_HOME);
+    assert_eq!(address.tags.len(), 1);
+}
+
+#[test]
+fn test_set_with_member_into_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        labels: BTreeSet<String>,
+    }
+    impl Address {
+        collection_set_with_member!(pub tags => labels, into String);
+    }
+
+    let address = Address::default().with_tags_member(EX_TAG_WORK);
+    assert!(address.labels.contains(EX_TAG_WORK));
+}
@@ -0,0 +1,886 @@
+use jemmy::*;
+
+const EX_STREET_LINE_1: &str = "101 My Street";
+const EX_STREET_LINE_2: &str = "Unit 202";
+const EX_STREET_LINE_3: &str = "Building C";
+const EX_UNIT_NUMBER_1: u32 = 24;
+const EX_UNIT_NUMBER_2: u32 = 202;
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ vec_is_empty!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_is_empty_base_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_is_empty!(pub street_lines);
+    }
+
+    let mut address = Address::default();
+    assert!(address.is_street_lines_empty());
+    address.street_lines.push(EX_STREET_LINE_1.to_string());
+    assert!(!address.is_street_lines_empty());
+}
+
+#[test]
+fn test_vec_is_empty_base_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_is_empty!(pub street_lines => lines);
+    }
+
+    let mut address = Address::default();
+    assert!(address.is_street_lines_empty());
+    address.lines.push(EX_STREET_LINE_1.to_string());
+    assert!(!address.is_street_lines_empty());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ vec_length!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_length_base_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_length!(pub street_lines);
+    }
+
+    let mut address = Address::default();
+    assert_eq!(address.street_lines_length(), 0);
+    address.street_lines.push(EX_STREET_LINE_1.to_string());
+    address.street_lines.push(EX_STREET_LINE_2.to_string());
+    assert_eq!(address.street_lines_length(), 2);
+}
+
+#[test]
+fn test_vec_length_base_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_length!(pub street_lines => lines);
+    }
+
+    let mut address = Address::default();
+    assert_eq!(address.street_lines_length(), 0);
+    address.lines.push(EX_STREET_LINE_1.to_string());
+    assert_eq!(address.street_lines_length(), 1);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ vec_contains!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_contains_base_case() {
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_contains!(pub street_lines => String);
+    }
+
+    let address = Address {
+        street_lines: vec![EX_STREET_LINE_1.to_string()],
+    };
+    assert!(address.street_lines_contains(&EX_STREET_LINE_1.to_string()));
+    assert!(!address.street_lines_contains(&EX_STREET_LINE_2.to_string()));
+}
+
+#[test]
+fn test_vec_contains_base_case_rename() {
+    pub struct Address {
+        units: Vec<u32>,
+    }
+    impl Address {
+        vec_contains!(pub unit_numbers => units, u32);
+    }
+
+    let address = Address {
+        units: vec![EX_UNIT_NUMBER_1],
+    };
+    assert!(address.unit_numbers_contains(&EX_UNIT_NUMBER_1));
+    assert!(!address.unit_numbers_contains(&EX_UNIT_NUMBER_2));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ vec_get!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_get_base_case() {
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_get!(pub street_lines => String);
+    }
+
+    let address = Address {
+        street_lines: vec![EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()],
+    };
+    assert_eq!(
+        address.street_lines_get(1),
+        Some(&EX_STREET_LINE_2.to_string())
+    );
+    assert_eq!(address.street_lines_get(2), None);
+}
+
+#[test]
+fn test_vec_get_base_case_rename() {
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_get!(pub street_lines => lines, String);
+    }
+
+    let address = Address {
+        lines: vec![EX_STREET_LINE_1.to_string()],
+    };
+    assert_eq!(
+        address.street_lines_get(0),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+    assert_eq!(address.street_lines_get(1), None);
+}
+
+#[test]
+fn test_vec_get_copy_case() {
+    pub struct Address {
+        unit_numbers: Vec<u32>,
+    }
+    impl Address {
+        vec_get!(pub unit_numbers => copy u32);
+    }
+
+    let address = Address {
+        unit_numbers: vec![EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2],
+    };
+    assert_eq!(address.unit_numbers_get(0), Some(EX_UNIT_NUMBER_1));
+    assert_eq!(address.unit_numbers_get(2), None);
+}
+
+#[test]
+fn test_vec_get_copy_case_rename() {
+    pub struct Address {
+        units: Vec<u32>,
+    }
+    impl Address {
+        vec_get!(pub unit_numbers => units, copy u32);
+    }
+
+    let address = Address {
+        units: vec![EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2],
+    };
+    assert_eq!(address.unit_numbers_get(1), Some(EX_UNIT_NUMBER_2));
+    assert_eq!(address.unit_numbers_get(2), None);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ vec_get_mut!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_get_mut_base_case() {
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_get_mut!(pub street_lines => String);
+    }
+
+    let mut address = Address {
+        street_lines: vec![EX_STREET_LINE_1.to_string()],
+    };
+    let line_1 = address.street_lines_get_mut(0).unwrap();
+    line_1.replace_range(0.., "202 My Street");
+    assert_eq!(address.street_lines[0].as_str(), "202 My Street");
+    assert_eq!(address.street_lines_get_mut(1), None);
+}
+
+#[test]
+fn test_vec_get_mut_base_case_rename() {
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_get_mut!(pub street_lines => lines, String);
+    }
+
+    let mut address = Address {
+        lines: vec![EX_STREET_LINE_1.to_string()],
+    };
+    let line_1 = address.street_lines_get_mut(0).unwrap();
+    line_1.replace_range(0.., "202 My Street");
+    assert_eq!(address.lines[0].as_str(), "202 My Street");
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ vec_remove!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_remove_base_case() {
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_remove!(pub street_lines => String);
+    }
+
+    let mut address = Address {
+        street_lines: vec![EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()],
+    };
+    assert_eq!(address.street_lines_remove(0), EX_STREET_LINE_1.to_string());
+    assert_eq!(address.street_lines, vec![EX_STREET_LINE_2.to_string()]);
+}
+
+#[test]
+fn test_vec_remove_base_case_rename() {
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_remove!(pub street_lines => lines, String);
+    }
+
+    let mut address = Address {
+        lines: vec![EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()],
+    };
+    assert_eq!(address.street_lines_remove(1), EX_STREET_LINE_2.to_string());
+    assert_eq!(address.lines, vec![EX_STREET_LINE_1.to_string()]);
+}
+
+#[test]
+#[should_panic]
+fn test_vec_remove_out_of_bounds() {
+    #[derive(Default)]
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_remove!(pub street_lines => String);
+    }
+
+    let mut address = Address::default();
+    let _ = address.street_lines_remove(0);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ vec_push!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_push_base_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_push!(pub street_lines => String);
+    }
+
+    let mut address = Address::default();
+    address.street_lines_push(EX_STREET_LINE_1.to_string());
+    address.street_lines_push(EX_STREET_LINE_2.to_string());
+    assert_eq!(
+        address.street_lines,
+        vec![EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()]
+    );
+}
+
+#[test]
+fn test_vec_push_base_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_push!(pub street_lines => lines, String);
+    }
+
+    let mut address = Address::default();
+    address.street_lines_push(EX_STREET_LINE_1.to_string());
+    assert_eq!(address.lines, vec![EX_STREET_LINE_1.to_string()]);
+}
+
+#[test]
+fn test_vec_push_into_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_push!(pub street_lines => into String);
+    }
+
+    let mut address = Address::default();
+    address.street_lines_push(EX_STREET_LINE_1);
+    assert_eq!(address.street_lines, vec![EX_STREET_LINE_1.to_string()]);
+}
+
+#[test]
+fn test_vec_push_into_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_push!(pub street_lines => lines, into String);
+    }
+
+    let mut address = Address::default();
+    address.street_lines_push(EX_STREET_LINE_1);
+    assert_eq!(address.lines, vec![EX_STREET_LINE_1.to_string()]);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ vec_pop!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_pop_base_case() {
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_pop!(pub street_lines => String);
+    }
+
+    let mut address = Address {
+        street_lines: vec![EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()],
+    };
+    assert_eq!(
+        address.street_lines_pop(),
+        Some(EX_STREET_LINE_2.to_string())
+    );
+    assert_eq!(
+        address.street_lines_pop(),
+        Some(EX_STREET_LINE_1.to_string())
+    );
+    assert_eq!(address.street_lines_pop(), None);
+}
+
+#[test]
+fn test_vec_pop_base_case_rename() {
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_pop!(pub street_lines => lines, String);
+    }
+
+    let mut address = Address {
+        lines: vec![EX_STREET_LINE_1.to_string()],
+    };
+    assert_eq!(
+        address.street_lines_pop(),
+        Some(EX_STREET_LINE_1.to_string())
+    );
+    assert_eq!(address.street_lines_pop(), None);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ vec_extend!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_extend_base_case() {
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_extend!(pub street_lines => String);
+    }
+
+    let mut address = Address {
+        street_lines: vec![EX_STREET_LINE_1.to_string()],
+    };
+    address.street_lines_extend(vec![
+        EX_STREET_LINE_2.to_string(),
+        EX_STREET_LINE_3.to_string(),
+    ]);
+    assert_eq!(
+        address.street_lines,
+        vec![
+            EX_STREET_LINE_1.to_string(),
+            EX_STREET_LINE_2.to_string(),
+            EX_STREET_LINE_3.to_string()
+        ]
+    );
+}
+
+#[test]
+fn test_vec_extend_base_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_extend!(pub street_lines => lines, String);
+    }
+
+    let mut address = Address::default();
+    address.street_lines_extend(Some(EX_STREET_LINE_1.to_string()));
+    assert_eq!(address.lines, vec![EX_STREET_LINE_1.to_string()]);
+}
+
+#[test]
+fn test_vec_extend_into_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_extend!(pub street_lines => into String);
+    }
+
+    let mut address = Address::default();
+    address.street_lines_extend([EX_STREET_LINE_1, EX_STREET_LINE_2]);
+    assert_eq!(
+        address.street_lines,
+        vec![EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()]
+    );
+}
+
+#[test]
+fn test_vec_extend_into_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_extend!(pub street_lines => lines, into String);
+    }
+
+    let mut address = Address::default();
+    address.street_lines_extend([EX_STREET_LINE_1]);
+    assert_eq!(address.lines, vec![EX_STREET_LINE_1.to_string()]);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ vec_clear!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_clear_base_case() {
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_clear!(pub street_lines);
+    }
+
+    let mut address = Address {
+        street_lines: vec![EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()],
+    };
+    address.street_lines_clear();
+    assert!(address.street_lines.is_empty());
+}
+
+#[test]
+fn test_vec_clear_base_case_rename() {
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_clear!(pub street_lines => lines);
+    }
+
+    let mut address = Address {
+        lines: vec![EX_STREET_LINE_1.to_string()],
+    };
+    address.street_lines_clear();
+    assert!(address.lines.is_empty());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ vec_iter!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_iter_base_case() {
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_iter!(pub street_lines => String);
+    }
+
+    let address = Address {
+        street_lines: vec![EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()],
+    };
+    let lines: Vec<&String> = address.street_lines_iter().collect();
+    assert_eq!(
+        lines,
+        vec![&EX_STREET_LINE_1.to_string(), &EX_STREET_LINE_2.to_string()]
+    );
+}
+
+#[test]
+fn test_vec_iter_base_case_rename() {
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_iter!(pub street_lines => lines, String);
+    }
+
+    let address = Address {
+        lines: vec![EX_STREET_LINE_1.to_string()],
+    };
+    let lines: Vec<&String> = address.street_lines_iter().collect();
+    assert_eq!(lines, vec![&EX_STREET_LINE_1.to_string()]);
+}
+
+#[test]
+fn test_vec_iter_copy_case() {
+    pub struct Address {
+        unit_numbers: Vec<u32>,
+    }
+    impl Address {
+        vec_iter!(pub unit_numbers => copy u32);
+    }
+
+    let address = Address {
+        unit_numbers: vec![EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2],
+    };
+    assert_eq!(
+        address.unit_numbers_iter().sum::<u32>(),
+        EX_UNIT_NUMBER_1 + EX_UNIT_NUMBER_2
+    );
+}
+
+#[test]
+fn test_vec_iter_copy_case_rename() {
+    pub struct Address {
+        units: Vec<u32>,
+    }
+    impl Address {
+        vec_iter!(pub unit_numbers => units, copy u32);
+    }
+
+    let address = Address {
+        units: vec![EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2],
+    };
+    let units: Vec<u32> = address.unit_numbers_iter().collect();
+    assert_eq!(units, vec![EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2]);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ vec_iter_mut!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_iter_mut_base_case() {
+    pub struct Address {
+        unit_numbers: Vec<u32>,
+    }
+    impl Address {
+        vec_iter_mut!(pub unit_numbers => u32);
+    }
+
+    let mut address = Address {
+        unit_numbers: vec![EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2],
+    };
+    address.unit_numbers_iter_mut().for_each(|unit| *unit += 1);
+    assert_eq!(
+        address.unit_numbers,
+        vec![EX_UNIT_NUMBER_1 + 1, EX_UNIT_NUMBER_2 + 1]
+    );
+}
+
+#[test]
+fn test_vec_iter_mut_base_case_rename() {
+    pub struct Address {
+        units: Vec<u32>,
+    }
+    impl Address {
+        vec_iter_mut!(pub unit_numbers => units, u32);
+    }
+
+    let mut address = Address {
+        units: vec![EX_UNIT_NUMBER_1],
+    };
+    address.unit_numbers_iter_mut().for_each(|unit| *unit = 0);
+    assert_eq!(address.units, vec![0]);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ vec_with_from_iter!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_with_from_iter_base_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_with_from_iter!(pub street_lines => String);
+    }
+
+    let address = Address::default().with_street_lines_from_iter(vec![
+        EX_STREET_LINE_1.to_string(),
+        EX_STREET_LINE_2.to_string(),
+    ]);
+    assert_eq!(
+        address.street_lines,
+        vec![EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()]
+    );
+}
+
+#[test]
+fn test_vec_with_from_iter_base_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_with_from_iter!(pub street_lines => lines, String);
+    }
+
+    let address = Address {
+        lines: vec![EX_STREET_LINE_3.to_string()],
+    }
+    .with_street_lines_from_iter(vec![EX_STREET_LINE_1.to_string()]);
+    assert_eq!(address.lines, vec![EX_STREET_LINE_1.to_string()]);
+}
+
+#[test]
+fn test_vec_with_from_iter_into_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_with_from_iter!(pub street_lines => into String);
+    }
+
+    let address =
+        Address::default().with_street_lines_from_iter([EX_STREET_LINE_1, EX_STREET_LINE_2]);
+    assert_eq!(
+        address.street_lines,
+        vec![EX_STREET_LINE_1.to_string(), EX_STREET_LINE_2.to_string()]
+    );
+}
+
+#[test]
+fn test_vec_with_from_iter_into_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_with_from_iter!(pub street_lines => lines, into String);
+    }
+
+    let address = Address::default().with_street_lines_from_iter([EX_STREET_LINE_1]);
+    assert_eq!(address.lines, vec![EX_STREET_LINE_1.to_string()]);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ Combinators ❱ vec_accessors!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_accessors_base_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_accessors!(pub street_lines => String);
+    }
+
+    let mut address = Address::default();
+    assert!(address.is_street_lines_empty());
+    address.street_lines.push(EX_STREET_LINE_1.to_string());
+    address.street_lines.push(EX_STREET_LINE_2.to_string());
+    assert!(!address.is_street_lines_empty());
+    assert_eq!(address.street_lines_length(), 2);
+    assert_eq!(
+        address.street_lines_get(0),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+    assert!(address.street_lines_contains(&EX_STREET_LINE_2.to_string()));
+    assert_eq!(address.street_lines_iter().count(), 2);
+}
+
+#[test]
+fn test_vec_accessors_base_case_rename() {
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_accessors!(pub street_lines => lines, String);
+    }
+
+    let address = Address {
+        lines: vec![EX_STREET_LINE_1.to_string()],
+    };
+    assert!(!address.is_street_lines_empty());
+    assert_eq!(address.street_lines_length(), 1);
+    assert_eq!(
+        address.street_lines_get(0),
+        Some(&EX_STREET_LINE_1.to_string())
+    );
+    assert!(!address.street_lines_contains(&EX_STREET_LINE_2.to_string()));
+    assert_eq!(address.street_lines_iter().count(), 1);
+}
+
+#[test]
+fn test_vec_accessors_copy_case() {
+    pub struct Address {
+        unit_numbers: Vec<u32>,
+    }
+    impl Address {
+        vec_accessors!(pub unit_numbers => copy u32);
+    }
+
+    let address = Address {
+        unit_numbers: vec![EX_UNIT_NUMBER_1, EX_UNIT_NUMBER_2],
+    };
+    assert_eq!(address.unit_numbers_get(1), Some(EX_UNIT_NUMBER_2));
+    assert_eq!(
+        address.unit_numbers_iter().sum::<u32>(),
+        EX_UNIT_NUMBER_1 + EX_UNIT_NUMBER_2
+    );
+    assert!(address.unit_numbers_contains(&EX_UNIT_NUMBER_1));
+}
+
+#[test]
+fn test_vec_accessors_copy_case_rename() {
+    pub struct Address {
+        units: Vec<u32>,
+    }
+    impl Address {
+        vec_accessors!(pub unit_numbers => units, copy u32);
+    }
+
+    let address = Address {
+        units: vec![EX_UNIT_NUMBER_1],
+    };
+    assert_eq!(address.unit_numbers_length(), 1);
+    assert_eq!(address.unit_numbers_get(0), Some(EX_UNIT_NUMBER_1));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test Cases ❱ Structures ❱ Vectors ❱ Combinators ❱ vec_accessors_mut!
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_vec_accessors_mut_base_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_accessors_mut!(pub street_lines => String);
+    }
+
+    let mut address = Address::default();
+    address.street_lines_push(EX_STREET_LINE_1.to_string());
+    address.street_lines_extend(vec![
+        EX_STREET_LINE_2.to_string(),
+        EX_STREET_LINE_3.to_string(),
+    ]);
+    assert_eq!(address.street_lines_length(), 3);
+    assert_eq!(
+        address.street_lines_pop(),
+        Some(EX_STREET_LINE_3.to_string())
+    );
+    assert_eq!(address.street_lines_remove(0), EX_STREET_LINE_1.to_string());
+    address
+        .street_lines_iter_mut()
+        .for_each(|line| line.push_str(", Rear"));
+    assert_eq!(
+        address.street_lines_get(0),
+        Some(&format!("{EX_STREET_LINE_2}, Rear"))
+    );
+    address.street_lines_clear();
+    assert!(address.is_street_lines_empty());
+}
+
+#[test]
+fn test_vec_accessors_mut_base_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_accessors_mut!(pub street_lines => lines, String);
+    }
+
+    let mut address = Address::default();
+    address.street_lines_push(EX_STREET_LINE_1.to_string());
+    assert_eq!(address.lines, vec![EX_STREET_LINE_1.to_string()]);
+    address.street_lines_clear();
+    assert!(address.is_street_lines_empty());
+}
+
+#[test]
+fn test_vec_accessors_mut_copy_case() {
+    #[derive(Default)]
+    pub struct Address {
+        unit_numbers: Vec<u32>,
+    }
+    impl Address {
+        vec_accessors_mut!(pub unit_numbers => copy u32);
+    }
+
+    let mut address = Address::default();
+    address.unit_numbers_push(EX_UNIT_NUMBER_1);
+    address.unit_numbers_extend([EX_UNIT_NUMBER_2]);
+    address.unit_numbers_iter_mut().for_each(|unit| *unit += 1);
+    assert_eq!(address.unit_numbers_get(0), Some(EX_UNIT_NUMBER_1 + 1));
+    assert_eq!(address.unit_numbers_pop(), Some(EX_UNIT_NUMBER_2 + 1));
+}
+
+#[test]
+fn test_vec_accessors_mut_into_case() {
+    #[derive(Default)]
+    pub struct Address {
+        street_lines: Vec<String>,
+    }
+    impl Address {
+        vec_accessors_mut!(pub street_lines => into String);
+    }
+
+    let mut address = Address::default();
+    address.street_lines_push(EX_STREET_LINE_1);
+    address.street_lines_extend([EX_STREET_LINE_2, EX_STREET_LINE_3]);
+    assert_eq!(address.street_lines_length(), 3);
+    assert_eq!(
+        address.street_lines_get(2),
+        Some(&EX_STREET_LINE_3.to_string())
+    );
+}
+
+#[test]
+fn test_vec_accessors_mut_into_case_rename() {
+    #[derive(Default)]
+    pub struct Address {
+        lines: Vec<String>,
+    }
+    impl Address {
+        vec_accessors_mut!(pub street_lines => lines, into String);
+    }
+
+    let mut address = Address::default();
+    address.street_lines_push(EX_STREET_LINE_1);
+    assert_eq!(address.lines, vec![EX_STREET_LINE_1.to_string()]);
+}